@@ -46,6 +46,10 @@ fn doc_parser(c: &mut Criterion) {
     b.to_async(FuturesExecutor)
       .iter_with_large_drop(parse_with_reexports)
   });
+  let doc_nodes = futures::executor::block_on(parse_with_reexports());
+  c.bench_function("clone large", |b| {
+    b.iter_with_large_drop(|| doc_nodes.clone())
+  });
 }
 
 criterion_group!(benches, doc_parser);
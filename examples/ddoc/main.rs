@@ -3,6 +3,7 @@
 use clap::App;
 use clap::Arg;
 use deno_doc::find_nodes_by_name_recursively;
+use deno_doc::merge_param_docs;
 use deno_doc::DocNodeKind;
 use deno_doc::DocParser;
 use deno_doc::DocPrinter;
@@ -48,10 +49,14 @@ fn main() {
   let matches = App::new("ddoc")
     .arg(Arg::with_name("source_file").required(true))
     .arg(Arg::with_name("filter"))
+    .arg(Arg::with_name("strip_internal").long("strip-internal"))
+    .arg(Arg::with_name("loose_js_doc").long("loose-js-doc"))
     .get_matches();
 
   let source_file = matches.value_of("source_file").unwrap();
   let maybe_filter = matches.value_of("filter");
+  let strip_internal = matches.is_present("strip_internal");
+  let loose_js_doc = matches.is_present("loose_js_doc");
   let source_file =
     ModuleSpecifier::from_directory_path(current_dir().unwrap())
       .unwrap()
@@ -72,7 +77,9 @@ fn main() {
       None,
     )
     .await;
-    let parser = DocParser::new(graph, false, &source_parser);
+    let mut parser = DocParser::new(graph, false, &source_parser);
+    parser.strip_internal = strip_internal;
+    parser.loose_js_doc = loose_js_doc;
     let parse_result = parser.parse_with_reexports(&source_file);
 
     let mut doc_nodes = match parse_result {
@@ -84,6 +91,15 @@ fn main() {
     };
 
     doc_nodes.retain(|doc_node| doc_node.kind != DocNodeKind::Import);
+    for unknown_param in merge_param_docs(&mut doc_nodes) {
+      eprintln!(
+        "warning: @param {} does not name a parameter ({}:{}:{})",
+        unknown_param.name,
+        unknown_param.location.filename,
+        unknown_param.location.line,
+        unknown_param.location.col
+      );
+    }
     if let Some(filter) = maybe_filter {
       doc_nodes = find_nodes_by_name_recursively(doc_nodes, filter.to_string());
     }
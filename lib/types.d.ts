@@ -82,6 +82,38 @@ export interface DocNodeImport extends DocNodeBase {
 
 export type Accessibility = "public" | "protected" | "private";
 
+/** A getter and/or setter of a class or interface, merged into a single
+ * property-like entry. A getter without a setter is `readonly`, a setter
+ * without a getter is `writeOnly`. */
+export interface AccessorDef {
+  name: string;
+  location: Location;
+  jsDoc?: JsDoc;
+  tsType?: TsTypeDef;
+  readonly: boolean;
+  writeOnly: boolean;
+  optional: boolean;
+  /** The most permissive accessibility of the getter and the setter. */
+  accessibility?: Accessibility;
+  /** The accessibility of the getter, if it differs from the one of the
+   * setter. */
+  getterAccessibility?: Accessibility;
+  /** The accessibility of the setter, if it differs from the one of the
+   * getter. */
+  setterAccessibility?: Accessibility;
+  isAbstract?: boolean;
+  isStatic?: boolean;
+  declaredAs: MethodKind[];
+}
+
+/** A group of doc nodes sharing a category, as returned by
+ * `groupByCategory()`. The doc nodes without a category are grouped in a
+ * category without a name. */
+export interface Category {
+  name?: string;
+  docNodes: DocNode[];
+}
+
 export interface ClassDef {
   isAbstract: boolean;
   constructors: ClassConstructorDef[];
@@ -93,8 +125,20 @@ export interface ClassDef {
   typeParams: TsTypeParamDef[];
   superTypeParams: TsTypeDef[];
   decorators?: DecoratorDef[];
+  accessors?: AccessorDef[];
+  inherited?: ClassAncestorDef[];
+}
+
+export interface ClassAncestorDef {
+  name: string;
+  location: Location;
+  properties: InheritedMemberDef<ClassPropertyDef>[];
+  methods: InheritedMemberDef<ClassMethodDef>[];
+  accessors?: InheritedMemberDef<AccessorDef>[];
 }
 
+export type InheritedMemberDef<T> = T & { overridden?: boolean };
+
 export interface ClassConstructorDef {
   jsDoc?: JsDoc;
   accessibility?: Accessibility;
@@ -157,8 +201,13 @@ export interface FunctionDef {
   isGenerator: boolean;
   typeParams: TsTypeParamDef[];
   decorators?: DecoratorDef[];
+  /** The description of the return value from the `@returns` tag. */
+  returnDoc?: string;
 }
 
+/** The JSDoc tag doc nodes are grouped by. */
+export type GroupBy = "category" | "group" | "tags";
+
 export interface ImportDef {
   src: string;
   imported?: string;
@@ -171,6 +220,16 @@ export interface InterfaceDef {
   callSignatures: InterfaceCallSignatureDef[];
   indexSignatures: InterfaceIndexSignatureDef[];
   typeParams: TsTypeParamDef[];
+  accessors?: AccessorDef[];
+  inherited?: InterfaceAncestorDef[];
+}
+
+export interface InterfaceAncestorDef {
+  name: string;
+  location: Location;
+  properties: InheritedMemberDef<InterfacePropertyDef>[];
+  methods: InheritedMemberDef<InterfaceMethodDef>[];
+  accessors?: InheritedMemberDef<AccessorDef>[];
 }
 
 export interface InterfaceCallSignatureDef {
@@ -179,6 +238,8 @@ export interface InterfaceCallSignatureDef {
   params: ParamDef[];
   tsType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
+  /** The description of the return value from the `@returns` tag. */
+  returnDoc?: string;
 }
 
 export interface InterfaceIndexSignatureDef {
@@ -197,6 +258,8 @@ export interface InterfaceMethodDef {
   params: ParamDef[];
   returnType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
+  /** The description of the return value from the `@returns` tag. */
+  returnDoc?: string;
 }
 
 export interface InterfacePropertyDef {
@@ -213,17 +276,196 @@ export interface InterfacePropertyDef {
 
 export interface JsDoc {
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
   tags?: JsDocTag[];
+  /** The symbol or member the docs are inherited from, e.g. `Base.method`. */
+  inheritedFrom?: string;
+  /** The comment the docs were parsed from, with the `jsDocComments`
+   * option. */
+  comment?: JsDocComment;
+  /** The deprecation of the symbol or member, by its own `@deprecated` tag or
+   * by the one of the namespace, class, interface or enum it is in, with the
+   * `resolveDeprecations` option. */
+  deprecation?: Deprecation;
+}
+
+export type MarkdownBlock =
+  | MarkdownBlockParagraph
+  | MarkdownBlockHeading
+  | MarkdownBlockList
+  | MarkdownBlockCode
+  | MarkdownBlockQuote
+  | MarkdownBlockThematicBreak;
+
+export interface MarkdownBlockParagraph {
+  kind: "paragraph";
+  children: MarkdownInline[];
+}
+
+export interface MarkdownBlockHeading {
+  kind: "heading";
+  /** From 1 to 6. */
+  level: number;
+  children: MarkdownInline[];
+}
+
+/** A bullet list, or an ordered list if it has a `start`. */
+export interface MarkdownBlockList {
+  kind: "list";
+  start?: number;
+  /** The blocks of each item of the list. */
+  items: MarkdownBlock[][];
+}
+
+/** A fenced or indented code block. */
+export interface MarkdownBlockCode {
+  kind: "codeBlock";
+  /** The first word of the info string of a fenced code block. */
+  lang?: string;
+  code: string;
+}
+
+export interface MarkdownBlockQuote {
+  kind: "blockQuote";
+  children: MarkdownBlock[];
+}
+
+export interface MarkdownBlockThematicBreak {
+  kind: "thematicBreak";
+}
+
+export type MarkdownInline =
+  | MarkdownInlineText
+  | MarkdownInlineCode
+  | MarkdownInlineEmphasis
+  | MarkdownInlineLink
+  | MarkdownInlineJsDocLink
+  | MarkdownInlineLineBreak;
+
+/** Text, in which line breaks within the paragraph are kept as `\n`s. */
+export interface MarkdownInlineText {
+  kind: "text";
+  value: string;
+}
+
+export interface MarkdownInlineCode {
+  kind: "code";
+  value: string;
+}
+
+export interface MarkdownInlineEmphasis {
+  kind: "emphasis" | "strong";
+  children: MarkdownInline[];
+}
+
+/** `[text](url)` or `<url>`. */
+export interface MarkdownInlineLink {
+  kind: "link";
+  url: string;
+  children: MarkdownInline[];
+}
+
+/** An inline link tag, as in `JsDocSegmentLink`. */
+export interface MarkdownInlineJsDocLink {
+  kind: "jsDocLink";
+  target: string;
+  label?: string;
+  style: "link" | "code" | "plain";
+  resolution?: TypeRefResolution;
+}
+
+/** A hard line break. */
+export interface MarkdownInlineLineBreak {
+  kind: "lineBreak";
+}
+
+/** Why a symbol or member is deprecated, and which `@deprecated` tag
+ * deprecates it. */
+export interface Deprecation {
+  reason?: string;
+  /** The qualified name of the symbol or member the tag is written on, e.g.
+   * `ns.Client.connect`. */
+  source: string;
+}
+
+/** A deprecated symbol or member, as listed by `deprecations()`. */
+export interface DeprecatedSymbol {
+  /** The qualified name of the symbol or member. */
+  name: string;
+  location: Location;
+  deprecation: Deprecation;
+}
+
+/** A JSDoc comment as written in the source. */
+export interface JsDocComment {
+  /** The location of the `/**` of the comment. */
+  location: Location;
+  /** The byte offset of the `/**` of the comment in the source. */
+  start: number;
+  /** The byte offset just past the `*\/` of the comment in the source. */
+  end: number;
+  /** The text of the comment, from its `/**` to its `*\/`. */
+  text: string;
+  /** The lines of the tags parsed from the comment. */
+  tagLines: JsDocTagLine[];
+}
+
+/** The line of a tag parsed from a JSDoc comment. */
+export interface JsDocTagLine {
+  /** The index of the tag in the `tags` of the docs parsed from the
+   * comment. */
+  tag: number;
+  /** The line of the tag, counted from the line of the `/**`. */
+  line: number;
+}
+
+export type JsDocSegment =
+  | JsDocSegmentText
+  | JsDocSegmentLink
+  | JsDocSegmentInheritDoc;
+
+export interface JsDocSegmentText {
+  kind: "text";
+  value: string;
+}
+
+/** `{@link target}`, `{@link target | label}` or `{@link target label}`, and
+ * the same for `{@linkcode}` (style `"code"`) and `{@linkplain}` (style
+ * `"plain"`). */
+export interface JsDocSegmentLink {
+  kind: "link";
+  target: string;
+  label?: string;
+  style: "link" | "code" | "plain";
+  resolution?: TypeRefResolution;
+}
+
+export interface JsDocSegmentInheritDoc {
+  kind: "inheritDoc";
+  target?: string;
 }
 
 export type JsDocTagKind =
+  | "async"
   | "callback"
+  | "category"
   | "constructor"
+  | "custom"
+  | "default"
   | "deprecated"
   | "enum"
   | "example"
+  | "experimental"
   | "extends"
+  | "group"
+  | "ignore"
+  | "inheritdoc"
+  | "internal"
   | "module"
+  | "override"
   | "param"
   | "public"
   | "private"
@@ -231,8 +473,12 @@ export type JsDocTagKind =
   | "protected"
   | "readonly"
   | "return"
+  | "see"
+  | "since"
+  | "tags"
   | "template"
   | "this"
+  | "throws"
   | "typedef"
   | "type"
   | "unsupported";
@@ -240,11 +486,20 @@ export type JsDocTagKind =
 export type JsDocTag =
   | JsDocTagOnly
   | JsDocTagDoc
+  | JsDocTagExample
   | JsDocTagNamed
   | JsDocTagTyped
   | JsDocTagNamedTyped
   | JsDocTagParam
   | JsDocTagReturn
+  | JsDocTagThrows
+  | JsDocTagValue
+  | JsDocTagSee
+  | JsDocTagSince
+  | JsDocTagCategory
+  | JsDocTagTags
+  | JsDocTagInheritDoc
+  | JsDocTagCustom
   | JsDocTagUnsupported;
 
 export interface JsDocTagBase {
@@ -253,8 +508,12 @@ export interface JsDocTagBase {
 
 export interface JsDocTagOnly extends JsDocTagBase {
   kind:
+    | "async"
     | "constructor"
+    | "ignore"
+    | "internal"
     | "module"
+    | "override"
     | "public"
     | "private"
     | "protected"
@@ -262,40 +521,158 @@ export interface JsDocTagOnly extends JsDocTagBase {
 }
 
 export interface JsDocTagDoc extends JsDocTagBase {
-  kind: "deprecated" | "example";
+  kind: "deprecated" | "experimental";
+  doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
+}
+
+export interface JsDocTagExample extends JsDocTagBase {
+  kind: "example";
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
+  /** The caption or title of the example. */
+  caption?: string;
+  /** `doc` without the caption, split into prose and code blocks. */
+  blocks?: ExampleBlock[];
+}
+
+export type ExampleBlock = ExampleBlockProse | ExampleBlockCode;
+
+export interface ExampleBlockProse {
+  kind: "prose";
+  doc: string;
+}
+
+/** A code block of an example, either fenced or the whole example. */
+export interface ExampleBlockCode {
+  kind: "code";
+  /** The language of the code fence, `"ts"` if there is none. */
+  lang: string;
+  code: string;
 }
 
 export interface JsDocTagNamed extends JsDocTagBase {
   kind: "callback" | "template";
   name: string;
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
 }
 
 export interface JsDocTagTyped extends JsDocTagBase {
   kind: "enum" | "extends" | "this" | "type";
   type: string;
+  /** `type` parsed into a type, with the `parseJsDocTypes` option. */
+  tsType?: TsTypeDef;
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
 }
 
 export interface JsDocTagNamedTyped extends JsDocTagBase {
   kind: "property" | "typedef";
   name: string;
   type: string;
+  /** `type` parsed into a type, with the `parseJsDocTypes` option. */
+  tsType?: TsTypeDef;
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
 }
 
 export interface JsDocTagParam extends JsDocTagBase {
   kind: "param";
   name: string;
+  /** Set when the parameter is optional, as written `[name]`. */
+  optional?: boolean;
+  /** The default value of an optional parameter, as written
+   * `[name=default]`. */
+  default?: string;
   type?: string;
+  /** `type` parsed into a type, with the `parseJsDocTypes` option. */
+  tsType?: TsTypeDef;
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
 }
 
 export interface JsDocTagReturn extends JsDocTagBase {
   kind: "return";
   type?: string;
+  /** `type` parsed into a type, with the `parseJsDocTypes` option. */
+  tsType?: TsTypeDef;
+  doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
+}
+
+export interface JsDocTagThrows extends JsDocTagBase {
+  kind: "throws";
+  type?: string;
   doc?: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
+}
+
+export interface JsDocTagValue extends JsDocTagBase {
+  kind: "default";
+  value: string;
+}
+
+export interface JsDocTagSee extends JsDocTagBase {
+  kind: "see";
+  doc: string;
+  /** `doc` split into text and inline tags, if it has any inline tags. */
+  segments?: JsDocSegment[];
+  /** `doc` parsed as Markdown, with the `markdown` option. */
+  markdown?: MarkdownBlock[];
+}
+
+export interface JsDocTagSince extends JsDocTagBase {
+  kind: "since";
+  version: string;
+}
+
+export interface JsDocTagCategory extends JsDocTagBase {
+  kind: "category" | "group";
+  name: string;
+}
+
+export interface JsDocTagInheritDoc extends JsDocTagBase {
+  kind: "inheritdoc";
+  target?: string;
+}
+
+/** A tag parsed according to one of the `customTags` of the options. */
+export interface JsDocTagCustom extends JsDocTagBase {
+  kind: "custom";
+  name: string;
+  /** The text of the tag parsed according to its shape, e.g.
+   * `{ type?: string; name?: string; doc?: string }`. */
+  value: unknown;
+}
+
+export interface JsDocTagTags extends JsDocTagBase {
+  kind: "tags";
+  tags: string[];
 }
 
 export interface JsDocTagUnsupported extends JsDocTagBase {
@@ -376,6 +753,9 @@ export interface LiteralPropertyDef {
   optional: boolean;
   tsType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
+  /** The description of the property from the `@param` tag of the parameter
+   * it belongs to. */
+  doc?: string;
 }
 
 export interface Location {
@@ -399,6 +779,10 @@ export interface ObjectPatPropAssignDef {
   kind: "assign";
   key: string;
   value?: string;
+  /** The type inferred from the default value, if any. */
+  tsType?: TsTypeDef;
+  /** The description of the property from its `@param` tag. */
+  doc?: string;
 }
 
 export interface ObjectPatPropKeyValueDef {
@@ -425,6 +809,8 @@ export interface ParamArrayDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  /** The description of the parameter from its `@param` tag. */
+  doc?: string;
 }
 
 export interface ParamAssignDef {
@@ -433,6 +819,8 @@ export interface ParamAssignDef {
   right: string;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  /** The description of the parameter from its `@param` tag. */
+  doc?: string;
 }
 
 export interface ParamIdentifierDef {
@@ -441,6 +829,8 @@ export interface ParamIdentifierDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  /** The description of the parameter from its `@param` tag. */
+  doc?: string;
 }
 
 export interface ParamObjectDef {
@@ -449,6 +839,8 @@ export interface ParamObjectDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  /** The description of the parameter from its `@param` tag. */
+  doc?: string;
 }
 
 export interface ParamRestDef {
@@ -456,6 +848,8 @@ export interface ParamRestDef {
   arg: ParamDef;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  /** The description of the parameter from its `@param` tag. */
+  doc?: string;
 }
 
 export type TruePlusMinus = true | "+" | "-";
@@ -472,6 +866,8 @@ export interface TsFnOrConstructorDef {
   tsType: TsTypeDef;
   params: ParamDef[];
   typeParams: TsTypeParamDef[];
+  /** The description of the return value from the `@returns` tag. */
+  returnDoc?: string;
 }
 
 export interface TsImportTypeDef {
@@ -498,6 +894,13 @@ export interface TsMappedTypeDef {
   tsType?: TsTypeDef;
 }
 
+/** An element of a tuple type. Optional and rest elements have a `tsType` of
+ * kind `"optional"` or `"rest"`. */
+export interface TsTupleElementDef {
+  label?: string;
+  tsType: TsTypeDef;
+}
+
 export interface TsTypeLiteralDef {
   methods: LiteralMethodDef[];
   properties: LiteralPropertyDef[];
@@ -545,9 +948,17 @@ export type TsTypeDef =
   | TsTypeTypeLiteralDef
   | TsTypeTypePredicateDef;
 
+/** With the `taggedJson` option, the payload of a type definition is found
+ * under `value` rather than under the property named after its kind below,
+ * and a `"this"` type has no payload. */
 interface TsTypeDefBase {
   repr: string;
   kind: TsTypeDefKind;
+  /** The JSDoc of a union or intersection member or a tuple element. */
+  jsDoc?: JsDoc;
+  /** Set when the type was inferred rather than declared, e.g. the return
+   * type of a function without a return type annotation. */
+  inferred?: boolean;
 }
 
 export interface TsTypeKeywordDef extends TsTypeDefBase {
@@ -582,7 +993,7 @@ export interface TsTypeArrayDef extends TsTypeDefBase {
 
 export interface TsTypeTupleDef extends TsTypeDefBase {
   kind: "tuple";
-  tuple: TsTypeDef[];
+  tuple: TsTupleElementDef[];
 }
 
 export interface TsTypeTypeOperatorDef extends TsTypeDefBase {
@@ -681,6 +1092,29 @@ export type TsTypeDefKind =
 export interface TsTypeRefDef {
   typeParams?: TsTypeDef[];
   typeName: string;
+  resolution?: TypeRefResolution;
+}
+
+export type TypeRefResolution =
+  | TypeRefResolutionSymbol
+  | TypeRefResolutionTypeParam
+  | TypeRefResolutionUnresolved;
+
+export interface TypeRefResolutionSymbol {
+  kind: "symbol";
+  specifier: string;
+  symbolPath: string[];
+  /** Whether the symbol, or a namespace enclosing it, is hidden from the docs
+   * by `@ignore`, or by `@internal` if internal symbols are stripped. */
+  hidden?: boolean;
+}
+
+export interface TypeRefResolutionTypeParam {
+  kind: "typeParam";
+}
+
+export interface TypeRefResolutionUnresolved {
+  kind: "unresolved";
 }
 
 export interface TypeAliasDef {
@@ -694,3 +1128,62 @@ export interface VariableDef {
   tsType?: TsTypeDef;
   kind: VariableDeclKind;
 }
+
+/** A project-specific tag, parsed according to the shape of its text:
+ * `"name"` for `@tag name comment`, `"type name"` for
+ * `@tag {type} name comment` and `"text"` for `@tag comment`. */
+export interface CustomTag {
+  /** The name of the tag, without the `@`. */
+  name: string;
+  shape: "name" | "type name" | "text";
+}
+
+/** The options of parsing the documentation of a module. */
+export interface ParseOptions {
+  /** If `true` include all documentation nodes in the output, included private
+   * (non-exported) nodes. The default is `false`.  Use the `declarationKind`
+   * of the `DocNode` to determine if the doc node is private, exported,
+   * imported, or declared. */
+  includeAll?: boolean;
+  /** If `true` hide symbols and members tagged with `@internal` from the
+   * output, as is always done for the ones tagged with `@ignore`. References
+   * to hidden symbols are flagged with `hidden` in their resolution. The
+   * default is `false`. */
+  stripInternal?: boolean;
+  /** If `true` resolve the type references and the targets of inline links
+   * to the symbols they refer to, in their `resolution`. Stripping internal
+   * symbols resolves them as well. The default is `false`. */
+  resolveTypeRefs?: boolean;
+  /** If `true` parse the `{type}` expressions of JSDoc tags, written in
+   * TypeScript or in the Closure syntax of JSDoc, into the `tsType` of the
+   * tags. The default is `false`. */
+  parseJsDocTypes?: boolean;
+  /** If `true` the last JSDoc comment before a declaration is attached to it
+   * even when a blank line separates them, or when it is the module doc. The
+   * default is `false`. */
+  looseJsDoc?: boolean;
+  /** If `true` the comments which docs are parsed from are kept in the
+   * `comment` of the docs, with their spans and the lines of their tags. The
+   * default is `false`. */
+  jsDocComments?: boolean;
+  /** If `true` fill in the `deprecation` of the docs of every symbol and
+   * member, which is deprecated by its own `@deprecated` tag or by the one of
+   * the namespace, class, interface or enum it is in. The default is
+   * `false`. */
+  resolveDeprecations?: boolean;
+  /** If `true` parse the documentation of docs and of their tags as Markdown
+   * into their `markdown`, with the inline link tags of the documentation
+   * kept as `"jsDocLink"` inlines. The default is `false`. */
+  markdown?: boolean;
+  /** If `true` type definitions have the payload of their kind under `value`,
+   * e.g. `{ repr: "string", kind: "keyword", value: "string" }`, rather than
+   * under a property named after the kind, e.g. `keyword: "string"`. The
+   * default is `false`. */
+  taggedJson?: boolean;
+  /** Project-specific tags to parse into `"custom"` tags, rather than leaving
+   * them `"unsupported"`, each by its name and the shape of its text. For
+   * example `{ name: "permission", shape: "name" }` parses
+   * `@permission net Reads the network` into the value
+   * `{ name: "net", doc: "Reads the network" }`. The default is `[]`. */
+  customTags?: CustomTag[];
+}
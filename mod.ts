@@ -1,18 +1,23 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
-import { doc as jsDoc } from "./lib/deno_doc.generated.js";
-import type { DocNode } from "./lib/types.d.ts";
+import {
+  deprecations as jsDeprecations,
+  doc as jsDoc,
+  group_by_category as jsGroupByCategory,
+} from "./lib/deno_doc.generated.js";
+import type {
+  Category,
+  DeprecatedSymbol,
+  DocNode,
+  GroupBy,
+  ParseOptions,
+} from "./lib/types.d.ts";
 import { load as defaultLoad } from "https://deno.land/x/deno_graph@0.23.0/lib/loader.ts";
 import type { LoadResponse } from "https://deno.land/x/deno_graph@0.23.0/mod.ts";
 
 export type { LoadResponse } from "https://deno.land/x/deno_graph@0.23.0/mod.ts";
 
-export interface DocOptions {
-  /** If `true` include all documentation nodes in the output, included private
-   * (non-exported) nodes. The default is `false`.  Use the `declarationKind`
-   * of the `DocNode` to determine if the doc node is private, exported,
-   * imported, or declared. */
-  includeAll?: boolean;
+export interface DocOptions extends ParseOptions {
   /**
    * An optional callback that is called with the URL string of the resource to
    * be loaded and a flag indicating if the module was required dynamically. The
@@ -65,6 +70,37 @@ export function doc(
   specifier: string,
   options: DocOptions = {},
 ): Promise<Array<DocNode>> {
-  const { load = defaultLoad, includeAll = false, resolve } = options;
-  return jsDoc(specifier, includeAll, load, resolve);
+  const { load = defaultLoad, resolve, ...parseOptions } = options;
+  return jsDoc(specifier, parseOptions, load, resolve);
+}
+
+/**
+ * Group documentation nodes by their `@category` tags, or by their `@group` or
+ * `@tags` tags, where a node with several of them is in each of their
+ * categories. The categories are sorted by name, followed by the category of
+ * the nodes without one, which has no name.
+ *
+ * @param entries The documentation nodes to group, as returned by `doc()`
+ * @param groupBy The tag to group the documentation nodes by
+ * @returns The categories, each with its documentation nodes
+ */
+export function groupByCategory(
+  entries: Array<DocNode>,
+  groupBy: GroupBy = "category",
+): Array<Category> {
+  return jsGroupByCategory(entries, groupBy);
+}
+
+/**
+ * List the deprecated public symbols and members of documentation nodes,
+ * whether deprecated by their own `@deprecated` tag or by the one of the
+ * namespace, class, interface or enum they are in.
+ *
+ * @param entries The documentation nodes, as returned by `doc()`
+ * @returns The deprecated symbols and members, with their deprecation
+ */
+export function deprecations(
+  entries: Array<DocNode>,
+): Array<DeprecatedSymbol> {
+  return jsDeprecations(entries);
 }
@@ -0,0 +1,233 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::js_doc::JsDoc;
+use crate::swc_util::is_false;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::Location;
+
+cfg_if! {
+  if #[cfg(feature = "rust")] {
+    use crate::colors;
+    use crate::display::display_abstract;
+    use crate::display::display_accessibility;
+    use crate::display::display_optional;
+    use crate::display::display_readonly;
+    use crate::display::display_static;
+    use crate::display::display_write_only;
+
+    use std::fmt::Display;
+    use std::fmt::Formatter;
+    use std::fmt::Result as FmtResult;
+  }
+}
+
+/// A property-like view of a `get`/`set` accessor pair.
+///
+/// ```ts
+/// class A {
+///   get size(): number;
+///   set size(value: number | string);
+/// }
+/// ```
+///
+/// is represented as a single `size` accessor with the type
+/// `number | string`. A getter without a setter is `readonly`, and a setter
+/// without a getter is `write_only`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessorDef {
+  pub name: String,
+  pub location: Location,
+  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  pub js_doc: JsDoc,
+  pub ts_type: Option<TsTypeDef>,
+  pub readonly: bool,
+  pub write_only: bool,
+  pub optional: bool,
+  /// The most permissive accessibility of the getter and the setter.
+  pub accessibility: Option<Accessibility>,
+  /// The accessibility of the getter, if it differs from the one of the
+  /// setter.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub getter_accessibility: Option<Accessibility>,
+  /// The accessibility of the setter, if it differs from the one of the
+  /// getter, e.g. for `get x()` along with `private set x(value)`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub setter_accessibility: Option<Accessibility>,
+  #[serde(skip_serializing_if = "is_false")]
+  pub is_abstract: bool,
+  #[serde(skip_serializing_if = "is_false")]
+  pub is_static: bool,
+  /// The accessor kinds the property was declared with, in source order.
+  pub declared_as: Vec<MethodKind>,
+}
+
+impl AccessorDef {
+  fn merge(&mut self, part: AccessorPart) {
+    if !self.declared_as.contains(&part.kind) {
+      self.declared_as.push(part.kind);
+    }
+    self.readonly = !self.declared_as.contains(&MethodKind::Setter);
+    self.write_only = !self.declared_as.contains(&MethodKind::Getter);
+    self.optional |= part.optional;
+    if permissiveness(part.accessibility) != permissiveness(self.accessibility)
+    {
+      let (getter, setter) = if part.kind == MethodKind::Setter {
+        (self.accessibility, part.accessibility)
+      } else {
+        (part.accessibility, self.accessibility)
+      };
+      self.getter_accessibility = Some(getter.unwrap_or(Accessibility::Public));
+      self.setter_accessibility = Some(setter.unwrap_or(Accessibility::Public));
+      if permissiveness(part.accessibility) > permissiveness(self.accessibility)
+      {
+        self.accessibility = part.accessibility;
+      }
+    }
+    self.is_abstract |= part.is_abstract;
+    self.ts_type = merge_ts_types(self.ts_type.take(), part.ts_type.cloned());
+    self.js_doc = merge_js_docs(&self.js_doc, part.js_doc);
+  }
+}
+
+impl From<AccessorPart<'_>> for AccessorDef {
+  fn from(part: AccessorPart) -> Self {
+    Self {
+      name: part.name.to_string(),
+      location: part.location.clone(),
+      js_doc: part.js_doc.clone(),
+      ts_type: part.ts_type.cloned(),
+      readonly: part.kind == MethodKind::Getter,
+      write_only: part.kind == MethodKind::Setter,
+      optional: part.optional,
+      accessibility: part.accessibility,
+      getter_accessibility: None,
+      setter_accessibility: None,
+      is_abstract: part.is_abstract,
+      is_static: part.is_static,
+      declared_as: vec![part.kind],
+    }
+  }
+}
+
+#[cfg(feature = "rust")]
+impl Display for AccessorDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}{}{}{}{}{}{}",
+      display_abstract(self.is_abstract),
+      display_accessibility(self.accessibility),
+      display_static(self.is_static),
+      display_readonly(self.readonly),
+      display_write_only(self.write_only),
+      colors::bold(&self.name),
+      display_optional(self.optional),
+    )?;
+    if let Some(ts_type) = &self.ts_type {
+      write!(f, ": {}", ts_type)?;
+    }
+    Ok(())
+  }
+}
+
+/// One `get` or `set` declaration of a class or interface, borrowed from its
+/// method definition.
+pub(crate) struct AccessorPart<'a> {
+  pub kind: MethodKind,
+  pub name: &'a str,
+  pub location: &'a Location,
+  pub js_doc: &'a JsDoc,
+  /// The return type of a getter or the parameter type of a setter.
+  pub ts_type: Option<&'a TsTypeDef>,
+  pub optional: bool,
+  pub accessibility: Option<Accessibility>,
+  pub is_abstract: bool,
+  pub is_static: bool,
+}
+
+/// Merges getters and setters with the same name (and staticness) into
+/// accessor definitions, keeping the order in which the names first appear.
+/// Parts which are not getters or setters are ignored.
+pub(crate) fn merge_accessors(parts: Vec<AccessorPart>) -> Vec<AccessorDef> {
+  let mut accessors: Vec<AccessorDef> = Vec::new();
+
+  for part in parts {
+    if !matches!(part.kind, MethodKind::Getter | MethodKind::Setter) {
+      continue;
+    }
+
+    if let Some(accessor) = accessors
+      .iter_mut()
+      .find(|a| a.name == part.name && a.is_static == part.is_static)
+    {
+      accessor.merge(part);
+    } else {
+      accessors.push(part.into());
+    }
+  }
+
+  accessors
+}
+
+/// Orders accessibilities from private to public, where no accessibility is
+/// public.
+fn permissiveness(accessibility: Option<Accessibility>) -> u8 {
+  match accessibility {
+    Some(Accessibility::Private) => 0,
+    Some(Accessibility::Protected) => 1,
+    Some(Accessibility::Public) | None => 2,
+  }
+}
+
+fn merge_ts_types(
+  a: Option<TsTypeDef>,
+  b: Option<TsTypeDef>,
+) -> Option<TsTypeDef> {
+  match (a, b) {
+    (Some(a), Some(b)) => {
+      if a == b {
+        return Some(a);
+      }
+      let mut types = vec![];
+      for ts_type in [a, b] {
+        let members = match ts_type {
+          TsTypeDef {
+            kind: TsTypeDefKind::Union(members),
+            ..
+          } => members,
+          ts_type => vec![ts_type],
+        };
+        for member in members {
+          if !types.contains(&member) {
+            types.push(member);
+          }
+        }
+      }
+      Some(TsTypeDefKind::Union(types).into())
+    }
+    (a, b) => a.or(b),
+  }
+}
+
+fn merge_js_docs(a: &JsDoc, b: &JsDoc) -> JsDoc {
+  if b.is_empty() {
+    return a.clone();
+  }
+  if a.is_empty() {
+    return b.clone();
+  }
+  let doc = match (&a.doc, &b.doc) {
+    (Some(a), Some(b)) => Some(format!("{}\n\n{}", a, b)),
+    (a, b) => a.clone().or_else(|| b.clone()),
+  };
+  let mut tags = a.tags.clone();
+  tags.extend(b.tags.iter().cloned());
+  JsDoc::new(doc, tags)
+}
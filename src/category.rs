@@ -0,0 +1,92 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The JSDoc tag doc nodes are grouped by.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum GroupBy {
+  /// `@category name`, which puts a doc node with several of them in each
+  /// of their categories
+  Category,
+  /// `@group name`, which puts a doc node with several of them in each of
+  /// their groups
+  Group,
+  /// `@tags tag, tag`, which puts a doc node in the group of each of its tags
+  Tags,
+}
+
+/// A group of doc nodes sharing a category.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+  /// The name of the category, or `None` for the doc nodes without one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  pub doc_nodes: Vec<DocNode>,
+}
+
+/// The names of the categories of a doc node documented by `js_doc`, from
+/// each of its `group_by` tags, without duplicates.
+fn category_names(js_doc: &JsDoc, group_by: GroupBy) -> Vec<String> {
+  let mut names: Vec<String> = Vec::new();
+  for tag in &js_doc.tags {
+    let tag_names = match (group_by, tag) {
+      (GroupBy::Category, JsDocTag::Category { name })
+      | (GroupBy::Group, JsDocTag::Group { name }) => {
+        std::slice::from_ref(name)
+      }
+      (GroupBy::Tags, JsDocTag::Tags { tags }) => tags.as_slice(),
+      _ => continue,
+    };
+    for name in tag_names {
+      if !names.contains(name) {
+        names.push(name.clone());
+      }
+    }
+  }
+  names
+}
+
+/// Groups doc nodes by the JSDoc tag `group_by`. The categories are sorted by
+/// name, followed by the category of the doc nodes without one, if any. The
+/// doc nodes of each category keep their order.
+pub fn group_by_category(
+  doc_nodes: &[DocNode],
+  group_by: GroupBy,
+) -> Vec<Category> {
+  let mut categories: Vec<Category> = Vec::new();
+  let mut uncategorized = Vec::new();
+  for doc_node in doc_nodes {
+    let names = category_names(&doc_node.js_doc, group_by);
+    if names.is_empty() {
+      uncategorized.push(doc_node.clone());
+    }
+    for name in names {
+      match categories
+        .binary_search_by(|category| category.name.as_ref().cmp(&Some(&name)))
+      {
+        Ok(i) => categories[i].doc_nodes.push(doc_node.clone()),
+        Err(i) => categories.insert(
+          i,
+          Category {
+            name: Some(name),
+            doc_nodes: vec![doc_node.clone()],
+          },
+        ),
+      }
+    }
+  }
+  if !uncategorized.is_empty() {
+    categories.push(Category {
+      name: None,
+      doc_nodes: uncategorized,
+    });
+  }
+  categories
+}
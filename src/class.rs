@@ -5,10 +5,14 @@ use deno_ast::ParsedSource;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::accessor::merge_accessors;
+use crate::accessor::AccessorDef;
+use crate::accessor::AccessorPart;
 use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
 use crate::function::function_to_function_def;
 use crate::function::FunctionDef;
+use crate::inheritance::InheritedMemberDef;
 use crate::js_doc::JsDoc;
 use crate::node::DeclarationKind;
 use crate::params::assign_pat_to_param_def;
@@ -18,6 +22,7 @@ use crate::params::prop_name_to_string;
 use crate::params::ts_fn_param_to_param_def;
 use crate::swc_util::get_location;
 use crate::swc_util::js_doc_for_span;
+use crate::swc_util::JsDocOptions;
 use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::maybe_type_param_instantiation_to_type_defs;
 use crate::ts_type::ts_type_ann_to_def;
@@ -213,28 +218,90 @@ pub struct ClassDef {
   pub super_type_params: Vec<TsTypeDef>,
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub decorators: Vec<DecoratorDef>,
+  /// The getters and setters of `methods`, merged into properties.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub accessors: Vec<AccessorDef>,
+  /// The members inherited from the ancestors of the class, closest ancestor
+  /// first, as filled in by `DocParser::resolve_inherited_members`.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub inherited: Vec<ClassAncestorDef>,
+}
+
+/// An ancestor of a class, with the members inherited from it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassAncestorDef {
+  /// The name of the ancestor, as referenced by the `extends` clause.
+  pub name: String,
+  pub location: Location,
+  pub properties: Vec<InheritedMemberDef<ClassPropertyDef>>,
+  pub methods: Vec<InheritedMemberDef<ClassMethodDef>>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub accessors: Vec<InheritedMemberDef<AccessorDef>>,
+}
+
+pub(crate) fn class_accessors_to_defs(
+  methods: &[ClassMethodDef],
+) -> Vec<AccessorDef> {
+  merge_accessors(
+    methods
+      .iter()
+      .map(|method| AccessorPart {
+        kind: method.kind,
+        name: &method.name,
+        location: &method.location,
+        js_doc: &method.js_doc,
+        ts_type: match method.kind {
+          deno_ast::swc::ast::MethodKind::Setter => method
+            .function_def
+            .params
+            .first()
+            .and_then(|param| param.ts_type()),
+          _ => method.function_def.return_type.as_ref(),
+        },
+        optional: method.optional,
+        accessibility: method.accessibility,
+        is_abstract: method.is_abstract,
+        is_static: method.is_static,
+      })
+      .collect(),
+  )
+}
+
+/// The name of the class in the `extends` clause of a class, for an
+/// identifier or a chain of property accesses on one, e.g. `ns.Base`.
+fn super_class_name(expr: &deno_ast::swc::ast::Expr) -> Option<String> {
+  use deno_ast::swc::ast::Expr;
+  use deno_ast::swc::ast::MemberProp;
+
+  match expr {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(member_expr) => match &member_expr.prop {
+      MemberProp::Ident(ident) => Some(format!(
+        "{}.{}",
+        super_class_name(&member_expr.obj)?,
+        ident.sym
+      )),
+      _ => None,
+    },
+    _ => None,
+  }
 }
 
 pub fn class_to_class_def(
   parsed_source: &ParsedSource,
   class: &deno_ast::swc::ast::Class,
+  js_doc_options: JsDocOptions,
 ) -> (ClassDef, JsDoc) {
   let mut constructors = vec![];
   let mut methods = vec![];
   let mut properties = vec![];
   let mut index_signatures = vec![];
 
-  let extends: Option<String> = match &class.super_class {
-    Some(boxed) => {
-      use deno_ast::swc::ast::Expr;
-      let expr: &Expr = &**boxed;
-      match expr {
-        Expr::Ident(ident) => Some(ident.sym.to_string()),
-        _ => None,
-      }
-    }
-    None => None,
-  };
+  let extends = class
+    .super_class
+    .as_ref()
+    .and_then(|expr| super_class_name(expr));
 
   let implements = class
     .implements
@@ -247,7 +314,8 @@ pub fn class_to_class_def(
 
     match member {
       Constructor(ctor) => {
-        let ctor_js_doc = js_doc_for_span(parsed_source, &ctor.span());
+        let ctor_js_doc =
+          js_doc_for_span(parsed_source, &ctor.span(), js_doc_options);
         let constructor_name =
           prop_name_to_string(Some(parsed_source), &ctor.key);
 
@@ -285,11 +353,15 @@ pub fn class_to_class_def(
       }
       Method(class_method) => {
         let method_js_doc =
-          js_doc_for_span(parsed_source, &class_method.span());
+          js_doc_for_span(parsed_source, &class_method.span(), js_doc_options);
         let method_name =
           prop_name_to_string(Some(parsed_source), &class_method.key);
-        let fn_def =
+        let mut fn_def =
           function_to_function_def(parsed_source, &class_method.function);
+        if class_method.kind == deno_ast::swc::ast::MethodKind::Setter {
+          // setters have no return type
+          fn_def.return_type = None;
+        }
         let method_def = ClassMethodDef {
           js_doc: method_js_doc,
           accessibility: class_method.accessibility,
@@ -304,7 +376,8 @@ pub fn class_to_class_def(
         methods.push(method_def);
       }
       ClassProp(class_prop) => {
-        let prop_js_doc = js_doc_for_span(parsed_source, &class_prop.span());
+        let prop_js_doc =
+          js_doc_for_span(parsed_source, &class_prop.span(), js_doc_options);
 
         let ts_type = if let Some(type_ann) = &class_prop.type_ann {
           // if the property has a type annotation, use it
@@ -373,10 +446,12 @@ pub fn class_to_class_def(
 
   let decorators = decorators_to_defs(parsed_source, &class.decorators);
 
+  let accessors = class_accessors_to_defs(&methods);
+
   // JSDoc associated with the class may actually be a leading comment on a
   // decorator, and so we should parse out the JSDoc for the first decorator
   let js_doc = if !class.decorators.is_empty() {
-    js_doc_for_span(parsed_source, &class.decorators[0].span)
+    js_doc_for_span(parsed_source, &class.decorators[0].span, js_doc_options)
   } else {
     JsDoc::default()
   };
@@ -393,6 +468,8 @@ pub fn class_to_class_def(
       type_params,
       super_type_params,
       decorators,
+      accessors,
+      inherited: Vec::new(),
     },
     js_doc,
   )
@@ -401,10 +478,11 @@ pub fn class_to_class_def(
 pub fn get_doc_for_class_decl(
   parsed_source: &ParsedSource,
   class_decl: &deno_ast::swc::ast::ClassDecl,
+  js_doc_options: JsDocOptions,
 ) -> (String, ClassDef, JsDoc) {
   let class_name = class_decl.ident.sym.to_string();
   let (class_def, js_doc) =
-    class_to_class_def(parsed_source, &class_decl.class);
+    class_to_class_def(parsed_source, &class_decl.class, js_doc_options);
 
   (class_name, class_def, js_doc)
 }
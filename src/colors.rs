@@ -85,6 +85,13 @@ pub fn gray<S: AsRef<str>>(s: S) -> impl fmt::Display {
   style(s, style_spec)
 }
 
+#[cfg(feature = "rust")]
+pub fn bold_italic<S: AsRef<str>>(s: S) -> impl fmt::Display {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_bold(true).set_italic(true);
+  style(s, style_spec)
+}
+
 #[cfg(feature = "rust")]
 pub fn italic_gray<S: AsRef<str>>(s: S) -> impl fmt::Display {
   let mut style_spec = ColorSpec::new();
@@ -0,0 +1,166 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::visit::DocVisitorMut;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use std::collections::HashMap;
+
+lazy_static! {
+  static ref CUSTOM_TAG_RE: Regex =
+    Regex::new(r#"(?s)^\s*@(\S+)(?:\s+(.*))?"#).unwrap();
+  static ref NAME_RE: Regex =
+    Regex::new(r#"(?s)^([^\s{}]+)(?:\s+(.+))?"#).unwrap();
+  static ref TYPE_NAME_RE: Regex =
+    Regex::new(r#"(?s)^\{([^}]+)\}\s+([^\s{}]+)(?:\s+(.+))?"#).unwrap();
+}
+
+/// Parses the text following the name of a custom tag into the value of a
+/// `JsDocTag::Custom`, or returns `None` if the text is malformed.
+pub type TagParser<'a> = Box<dyn Fn(&str) -> Option<Value> + 'a>;
+
+/// The shape of the text of a custom tag, for tags registered without a
+/// parser of their own.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TagShape {
+  /// `@tag name comment`, parsed into `{ "name": name, "doc": comment }`
+  #[serde(rename = "name")]
+  Name,
+  /// `@tag {type} name comment`, parsed into
+  /// `{ "type": type, "name": name, "doc": comment }`
+  #[serde(rename = "type name")]
+  TypeName,
+  /// `@tag comment`, parsed into `{ "doc": comment }`
+  #[serde(rename = "text")]
+  Text,
+}
+
+impl TagShape {
+  /// Parses the text following the name of a tag of this shape. The comment
+  /// is left out of the value if there is none.
+  pub fn parse(self, text: &str) -> Option<Value> {
+    let fields = match self {
+      Self::Name => {
+        let caps = NAME_RE.captures(text)?;
+        vec![("name", caps.get(1)), ("doc", caps.get(2))]
+      }
+      Self::TypeName => {
+        let caps = TYPE_NAME_RE.captures(text)?;
+        vec![
+          ("type", caps.get(1)),
+          ("name", caps.get(2)),
+          ("doc", caps.get(3)),
+        ]
+      }
+      Self::Text if text.is_empty() => return None,
+      Self::Text => return Some(serde_json::json!({ "doc": text })),
+    };
+    let value = fields
+      .into_iter()
+      .filter_map(|(key, m)| {
+        Some((key.to_string(), Value::String(m?.as_str().to_string())))
+      })
+      .collect::<Map<_, _>>();
+    Some(Value::Object(value))
+  }
+}
+
+/// A custom tag parsed according to its shape, as given to the wasm `doc()`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CustomTag {
+  /// The name of the tag, without the `@`.
+  pub name: String,
+  pub shape: TagShape,
+}
+
+/// Turns the unsupported tags of the docs it visits into custom tags, if a
+/// parser is registered for their name.
+pub(crate) struct CustomTagParser<'p, 'a> {
+  parsers: &'p HashMap<String, TagParser<'a>>,
+}
+
+impl<'p, 'a> CustomTagParser<'p, 'a> {
+  pub fn new(parsers: &'p HashMap<String, TagParser<'a>>) -> Self {
+    Self { parsers }
+  }
+}
+
+impl DocVisitorMut for CustomTagParser<'_, '_> {
+  fn visit_js_doc_mut(&mut self, js_doc: &mut JsDoc) {
+    for tag in &mut js_doc.tags {
+      let value = match tag {
+        JsDocTag::Unsupported { value } => value,
+        _ => continue,
+      };
+      let caps = match CUSTOM_TAG_RE.captures(value) {
+        Some(caps) => caps,
+        None => continue,
+      };
+      let name = caps.get(1).unwrap().as_str().to_string();
+      let text = caps.get(2).map_or("", |m| m.as_str().trim_end());
+      let custom_value = match self.parsers.get(&name) {
+        Some(parser) => parser(text),
+        None => continue,
+      };
+      if let Some(custom_value) = custom_value {
+        *tag = JsDocTag::Custom {
+          name,
+          value: custom_value,
+        };
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_tag_shape_parse() {
+    assert_eq!(
+      TagShape::Name.parse("net Reads the network"),
+      Some(json!({ "name": "net", "doc": "Reads the network" }))
+    );
+    assert_eq!(TagShape::Name.parse("net"), Some(json!({ "name": "net" })));
+    assert_eq!(TagShape::Name.parse(""), None);
+    assert_eq!(
+      TagShape::TypeName.parse("{string} path The path\nto read"),
+      Some(json!({
+        "type": "string",
+        "name": "path",
+        "doc": "The path\nto read",
+      }))
+    );
+    assert_eq!(
+      TagShape::TypeName.parse("{string} path"),
+      Some(json!({ "type": "string", "name": "path" }))
+    );
+    assert_eq!(TagShape::TypeName.parse("path The path"), None);
+    assert_eq!(TagShape::Text.parse("2"), Some(json!({ "doc": "2" })));
+    assert_eq!(TagShape::Text.parse(""), None);
+  }
+
+  #[test]
+  fn test_tag_shape_deserialization() {
+    let custom_tags: Vec<CustomTag> = serde_json::from_value(json!([
+      { "name": "permission", "shape": "name" },
+      { "name": "platform", "shape": "type name" },
+      { "name": "stability", "shape": "text" },
+    ]))
+    .unwrap();
+    let shapes = custom_tags
+      .iter()
+      .map(|custom_tag| custom_tag.shape)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      shapes,
+      vec![TagShape::Name, TagShape::TypeName, TagShape::Text]
+    );
+  }
+}
@@ -0,0 +1,406 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::accessor::AccessorDef;
+use crate::class::ClassAncestorDef;
+use crate::class::ClassConstructorDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::r#enum::EnumMemberDef;
+use crate::ts_type::TsTypeDef;
+use crate::visit::walk_class_ancestor_def;
+use crate::visit::walk_class_ancestor_def_mut;
+use crate::visit::walk_doc_node;
+use crate::visit::walk_doc_node_mut;
+use crate::visit::walk_interface_ancestor_def;
+use crate::visit::walk_interface_ancestor_def_mut;
+use crate::visit::DocVisitor;
+use crate::visit::DocVisitorMut;
+use crate::Location;
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Why a symbol or member is deprecated, and which `@deprecated` tag
+/// deprecates it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Deprecation {
+  /// The description of the `@deprecated` tag, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<String>,
+  /// The name of the symbol or member the tag is written on, qualified by the
+  /// namespaces, classes and interfaces it is in, e.g. `ns.Client.connect`.
+  pub source: String,
+}
+
+/// A deprecated symbol or member, as listed by [`deprecations`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeprecatedSymbol {
+  /// The name of the symbol or member, qualified by the namespaces, classes
+  /// and interfaces it is in.
+  pub name: String,
+  pub location: Location,
+  pub deprecation: Deprecation,
+}
+
+/// Fills in the `deprecation` of the docs of doc nodes and their members. A
+/// symbol or member is deprecated by its own `@deprecated` tag, or else by
+/// the one of the namespace, class, interface or enum it is in.
+///
+/// Run it after `DocParser::resolve_inherited_docs`, if at all, so that the
+/// `@deprecated` tags of inherited docs are accounted for.
+pub fn resolve_deprecations(doc_nodes: &mut [DocNode]) {
+  let mut resolver = DeprecationResolver::default();
+  for doc_node in doc_nodes {
+    resolver.visit_doc_node_mut(doc_node);
+  }
+}
+
+/// Lists the public symbols and members of doc nodes which are deprecated,
+/// whether by their own `@deprecated` tag or by the one of the namespace,
+/// class, interface or enum they are in, in order. Private class members and
+/// the accessors' individual `get` and `set` methods are left out.
+pub fn deprecations(doc_nodes: &[DocNode]) -> Vec<DeprecatedSymbol> {
+  let mut resolver = DeprecationResolver {
+    deprecations: Some(Vec::new()),
+    ..Default::default()
+  };
+  for doc_node in doc_nodes {
+    resolver.visit_doc_node(doc_node);
+  }
+  resolver.deprecations.unwrap_or_default()
+}
+
+/// The deprecation given by the first `@deprecated` tag of `js_doc`, if any.
+fn own_deprecation(js_doc: &JsDoc, source: String) -> Option<Deprecation> {
+  js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::Deprecated { doc, .. } => Some(Deprecation {
+      reason: doc.clone(),
+      source: source.clone(),
+    }),
+    _ => None,
+  })
+}
+
+/// Resolves the deprecations of doc nodes and their members, either filling
+/// them in as a `DocVisitorMut` or listing them as a `DocVisitor`.
+#[derive(Default)]
+struct DeprecationResolver {
+  /// The names and locations of the namespaces, classes, interfaces and enums
+  /// being visited.
+  scopes: Vec<(String, Location)>,
+  /// The deprecation of the innermost namespace, class, interface or enum
+  /// being visited.
+  parent: Option<Deprecation>,
+  /// Whether a symbol which isn't exported is being visited.
+  in_private: bool,
+  /// Whether the members inherited from an ancestor are being visited, which
+  /// are listed at the ancestor.
+  in_inherited: bool,
+  /// The deprecated public symbols and members, when listing them.
+  deprecations: Option<Vec<DeprecatedSymbol>>,
+}
+
+impl DeprecationResolver {
+  /// Resolves the deprecation of the symbol or member named `name`, listing
+  /// it if it is deprecated and `listed` is set.
+  fn resolve(
+    &mut self,
+    js_doc: &JsDoc,
+    name: &str,
+    location: Option<&Location>,
+    listed: bool,
+  ) -> Option<Deprecation> {
+    let qualified_name = self
+      .scopes
+      .iter()
+      .map(|(scope, _)| scope.as_str())
+      .chain(std::iter::once(name))
+      .collect::<Vec<_>>()
+      .join(".");
+    let deprecation = own_deprecation(js_doc, qualified_name.clone())
+      .or_else(|| self.parent.clone());
+
+    // members without a location of their own, i.e. enum members, are listed
+    // at the location of the enum
+    let location = location.or_else(|| self.scopes.last().map(|(_, l)| l));
+    match (&mut self.deprecations, &deprecation, location) {
+      (Some(deprecations), Some(deprecation), Some(location))
+        if listed && !self.in_private && !self.in_inherited =>
+      {
+        deprecations.push(DeprecatedSymbol {
+          name: qualified_name,
+          location: location.clone(),
+          deprecation: deprecation.clone(),
+        });
+      }
+      _ => {}
+    }
+    deprecation
+  }
+
+  /// Resolves the deprecation of `doc_node` and enters it as the scope of its
+  /// members, returning what `leave` restores. `None` is returned for module
+  /// docs and imports, which are skipped.
+  fn enter(
+    &mut self,
+    doc_node: &DocNode,
+  ) -> Option<(Option<Deprecation>, Option<Deprecation>, bool)> {
+    if matches!(doc_node.kind, DocNodeKind::ModuleDoc | DocNodeKind::Import) {
+      return None;
+    }
+    let deprecation = self.resolve(
+      &doc_node.js_doc,
+      &doc_node.name,
+      Some(&doc_node.location),
+      true,
+    );
+
+    let in_private = self.in_private;
+    self.in_private |=
+      matches!(doc_node.declaration_kind, DeclarationKind::Private);
+    let parent = std::mem::replace(&mut self.parent, deprecation.clone());
+    self
+      .scopes
+      .push((doc_node.name.clone(), doc_node.location.clone()));
+    Some((deprecation, parent, in_private))
+  }
+
+  fn leave(&mut self, parent: Option<Deprecation>, in_private: bool) {
+    self.scopes.pop();
+    self.parent = parent;
+    self.in_private = in_private;
+  }
+
+  fn resolve_enum_member(
+    &mut self,
+    member_def: &EnumMemberDef,
+  ) -> Option<Deprecation> {
+    self.resolve(&member_def.js_doc, &member_def.name, None, true)
+  }
+
+  fn resolve_class_constructor(
+    &mut self,
+    constructor_def: &ClassConstructorDef,
+  ) -> Option<Deprecation> {
+    let is_public =
+      constructor_def.accessibility != Some(Accessibility::Private);
+    self.resolve(
+      &constructor_def.js_doc,
+      "constructor",
+      Some(&constructor_def.location),
+      is_public,
+    )
+  }
+
+  fn resolve_class_property(
+    &mut self,
+    property_def: &ClassPropertyDef,
+  ) -> Option<Deprecation> {
+    let is_public = property_def.accessibility != Some(Accessibility::Private);
+    self.resolve(
+      &property_def.js_doc,
+      &property_def.name,
+      Some(&property_def.location),
+      is_public,
+    )
+  }
+
+  fn resolve_class_method(
+    &mut self,
+    method_def: &ClassMethodDef,
+  ) -> Option<Deprecation> {
+    // getters and setters are listed as accessors
+    let listed = method_def.accessibility != Some(Accessibility::Private)
+      && method_def.kind == MethodKind::Method;
+    self.resolve(
+      &method_def.js_doc,
+      &method_def.name,
+      Some(&method_def.location),
+      listed,
+    )
+  }
+
+  fn resolve_accessor(
+    &mut self,
+    accessor_def: &AccessorDef,
+  ) -> Option<Deprecation> {
+    let is_public = accessor_def.accessibility != Some(Accessibility::Private);
+    self.resolve(
+      &accessor_def.js_doc,
+      &accessor_def.name,
+      Some(&accessor_def.location),
+      is_public,
+    )
+  }
+
+  fn resolve_interface_method(
+    &mut self,
+    method_def: &InterfaceMethodDef,
+  ) -> Option<Deprecation> {
+    let listed = method_def.kind == MethodKind::Method;
+    self.resolve(
+      &method_def.js_doc,
+      &method_def.name,
+      Some(&method_def.location),
+      listed,
+    )
+  }
+
+  fn resolve_interface_property(
+    &mut self,
+    property_def: &InterfacePropertyDef,
+  ) -> Option<Deprecation> {
+    self.resolve(
+      &property_def.js_doc,
+      &property_def.name,
+      Some(&property_def.location),
+      true,
+    )
+  }
+}
+
+impl DocVisitorMut for DeprecationResolver {
+  fn visit_doc_node_mut(&mut self, doc_node: &mut DocNode) {
+    if let Some((deprecation, parent, in_private)) = self.enter(doc_node) {
+      doc_node.js_doc.deprecation = deprecation;
+      walk_doc_node_mut(self, doc_node);
+      self.leave(parent, in_private);
+    }
+  }
+
+  fn visit_enum_member_def_mut(&mut self, member_def: &mut EnumMemberDef) {
+    member_def.js_doc.deprecation = self.resolve_enum_member(member_def);
+  }
+
+  fn visit_class_constructor_def_mut(
+    &mut self,
+    constructor_def: &mut ClassConstructorDef,
+  ) {
+    constructor_def.js_doc.deprecation =
+      self.resolve_class_constructor(constructor_def);
+  }
+
+  fn visit_class_property_def_mut(
+    &mut self,
+    property_def: &mut ClassPropertyDef,
+  ) {
+    property_def.js_doc.deprecation = self.resolve_class_property(property_def);
+  }
+
+  fn visit_class_method_def_mut(&mut self, method_def: &mut ClassMethodDef) {
+    method_def.js_doc.deprecation = self.resolve_class_method(method_def);
+  }
+
+  fn visit_accessor_def_mut(&mut self, accessor_def: &mut AccessorDef) {
+    accessor_def.js_doc.deprecation = self.resolve_accessor(accessor_def);
+  }
+
+  fn visit_interface_method_def_mut(
+    &mut self,
+    method_def: &mut InterfaceMethodDef,
+  ) {
+    method_def.js_doc.deprecation = self.resolve_interface_method(method_def);
+  }
+
+  fn visit_interface_property_def_mut(
+    &mut self,
+    property_def: &mut InterfacePropertyDef,
+  ) {
+    property_def.js_doc.deprecation =
+      self.resolve_interface_property(property_def);
+  }
+
+  fn visit_class_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut ClassAncestorDef,
+  ) {
+    let in_inherited = std::mem::replace(&mut self.in_inherited, true);
+    walk_class_ancestor_def_mut(self, ancestor_def);
+    self.in_inherited = in_inherited;
+  }
+
+  fn visit_interface_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut InterfaceAncestorDef,
+  ) {
+    let in_inherited = std::mem::replace(&mut self.in_inherited, true);
+    walk_interface_ancestor_def_mut(self, ancestor_def);
+    self.in_inherited = in_inherited;
+  }
+
+  // the members of types are not symbols
+  fn visit_ts_type_def_mut(&mut self, _ts_type_def: &mut TsTypeDef) {}
+}
+
+impl DocVisitor for DeprecationResolver {
+  fn visit_doc_node(&mut self, doc_node: &DocNode) {
+    if let Some((_, parent, in_private)) = self.enter(doc_node) {
+      walk_doc_node(self, doc_node);
+      self.leave(parent, in_private);
+    }
+  }
+
+  fn visit_enum_member_def(&mut self, member_def: &EnumMemberDef) {
+    self.resolve_enum_member(member_def);
+  }
+
+  fn visit_class_constructor_def(
+    &mut self,
+    constructor_def: &ClassConstructorDef,
+  ) {
+    self.resolve_class_constructor(constructor_def);
+  }
+
+  fn visit_class_property_def(&mut self, property_def: &ClassPropertyDef) {
+    self.resolve_class_property(property_def);
+  }
+
+  fn visit_class_method_def(&mut self, method_def: &ClassMethodDef) {
+    self.resolve_class_method(method_def);
+  }
+
+  fn visit_accessor_def(&mut self, accessor_def: &AccessorDef) {
+    self.resolve_accessor(accessor_def);
+  }
+
+  fn visit_interface_method_def(&mut self, method_def: &InterfaceMethodDef) {
+    self.resolve_interface_method(method_def);
+  }
+
+  fn visit_interface_property_def(
+    &mut self,
+    property_def: &InterfacePropertyDef,
+  ) {
+    self.resolve_interface_property(property_def);
+  }
+
+  fn visit_class_ancestor_def(&mut self, ancestor_def: &ClassAncestorDef) {
+    let in_inherited = std::mem::replace(&mut self.in_inherited, true);
+    walk_class_ancestor_def(self, ancestor_def);
+    self.in_inherited = in_inherited;
+  }
+
+  fn visit_interface_ancestor_def(
+    &mut self,
+    ancestor_def: &InterfaceAncestorDef,
+  ) {
+    let in_inherited = std::mem::replace(&mut self.in_inherited, true);
+    walk_interface_ancestor_def(self, ancestor_def);
+    self.in_inherited = in_inherited;
+  }
+
+  // the members of types are not symbols
+  fn visit_ts_type_def(&mut self, _ts_type_def: &TsTypeDef) {}
+}
@@ -99,5 +99,9 @@ cfg_if! {
     pub(crate) fn display_static(is_static: bool) -> impl Display {
       colors::magenta(if is_static { "static " } else { "" })
     }
+
+    pub(crate) fn display_write_only(is_write_only: bool) -> impl Display {
+      colors::magenta(if is_write_only { "writeonly " } else { "" })
+    }
   }
 }
@@ -0,0 +1,296 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassAncestorDef;
+use crate::class::ClassConstructorDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::js_doc::JsDoc;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::r#enum::EnumMemberDef;
+use crate::ts_type::TsTypeDef;
+use crate::visit::walk_class_constructor_def;
+use crate::visit::walk_class_method_def;
+use crate::visit::walk_class_property_def;
+use crate::visit::walk_doc_node;
+use crate::visit::walk_enum_member_def;
+use crate::visit::walk_interface_method_def;
+use crate::visit::walk_interface_property_def;
+use crate::visit::DocVisitor;
+use crate::Location;
+
+use deno_graph::ModuleSpecifier;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+/// A module made of a code block of an `@example` tag, which imports the
+/// documented symbol so it can be type checked.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExampleModule {
+  /// The location of the doc node or the member the example belongs to.
+  pub location: Location,
+  pub source: String,
+}
+
+/// Makes a module of each JavaScript or TypeScript code block of the
+/// `@example` tags of doc nodes, their namespace elements and the members of
+/// their classes, interfaces and enums.
+///
+/// The doc nodes are the ones returned by `parse_with_reexports` for
+/// `specifier`, which the modules import the exported symbols from by the
+/// names they are exported with.
+///
+/// The modules are named after the module and the path of the documented
+/// symbol, followed by the number of the example of the symbol, e.g.
+/// `deno.land/x/mod.ts$Foo.bar$1.ts`.
+pub fn example_modules(
+  doc_nodes: &[DocNode],
+  specifier: &ModuleSpecifier,
+) -> BTreeMap<String, ExampleModule> {
+  let mut collector = ExampleCollector {
+    specifier: specifier.to_string(),
+    ..Default::default()
+  };
+  for doc_node in doc_nodes {
+    collector.visit_doc_node(doc_node);
+  }
+  collector.modules
+}
+
+/// Writes the modules returned by `example_modules` into `dir`, each at the
+/// path of its name.
+#[cfg(feature = "rust")]
+pub fn write_example_modules(
+  modules: &BTreeMap<String, ExampleModule>,
+  dir: &std::path::Path,
+) -> std::io::Result<()> {
+  for (name, module) in modules {
+    let path = dir.join(name);
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, &module.source)?;
+  }
+  Ok(())
+}
+
+/// The extension of a module written in the language of a code fence, if the
+/// language is JavaScript or TypeScript.
+fn example_extension(lang: &str) -> Option<&'static str> {
+  match lang {
+    "ts" | "typescript" => Some("ts"),
+    "tsx" => Some("tsx"),
+    "js" | "javascript" => Some("js"),
+    "jsx" => Some("jsx"),
+    _ => None,
+  }
+}
+
+/// A specifier without its scheme, usable as a relative path.
+fn specifier_path(specifier: &str) -> String {
+  let path = specifier
+    .split_once("://")
+    .map_or(specifier, |(_, path)| path);
+  path.trim_start_matches('/').replace(':', "_")
+}
+
+/// The exported top level symbol which examples import.
+struct ExampleImport {
+  name: String,
+  /// Matches the import declarations which import the symbol itself. Names
+  /// can start or end with `$`, so their boundaries are not matched by `\b`.
+  import_re: Regex,
+}
+
+impl ExampleImport {
+  fn new(name: String) -> Self {
+    let import_re = Regex::new(&format!(
+      r"(?m)^\s*import\b[^;]*[^\w$]{}(?:[^\w$]|$)",
+      regex::escape(&name)
+    ))
+    .unwrap();
+    Self { name, import_re }
+  }
+}
+
+#[derive(Default)]
+struct ExampleCollector {
+  /// The module the doc nodes are exported from.
+  specifier: String,
+  modules: BTreeMap<String, ExampleModule>,
+  /// The number of examples of each symbol so far, by module name prefix.
+  counts: HashMap<String, usize>,
+  /// The names of the doc nodes enclosing the visited member.
+  path: Vec<String>,
+  /// The location of the visited doc node.
+  location: Option<Location>,
+  /// The top level symbol the examples import, if it is exported.
+  import: Option<ExampleImport>,
+}
+
+impl ExampleCollector {
+  /// Adds the examples of the symbol or member named `name`, which is at
+  /// `location`, or else at the location of the visited doc node.
+  fn add_examples(
+    &mut self,
+    name: Option<&str>,
+    location: Option<&Location>,
+    js_doc: &JsDoc,
+  ) {
+    let code_blocks = js_doc.example_code_blocks();
+    if code_blocks.is_empty() {
+      return;
+    }
+    let location = match location.or_else(|| self.location.as_ref()) {
+      Some(location) => location.clone(),
+      None => return,
+    };
+    let symbol_path = self
+      .path
+      .iter()
+      .map(String::as_str)
+      .chain(name)
+      .collect::<Vec<_>>()
+      .join(".");
+    let prefix =
+      format!("{}${}", specifier_path(&location.filename), symbol_path);
+
+    for code_block in code_blocks {
+      let extension = match example_extension(&code_block.lang) {
+        Some(extension) => extension,
+        None => continue,
+      };
+      let count = self.counts.entry(prefix.clone()).or_insert(0);
+      *count += 1;
+      let name = format!("{}${}.{}", prefix, count, extension);
+
+      let mut source = String::new();
+      if let Some(import) = &self.import {
+        if !import.import_re.is_match(&code_block.code) {
+          source.push_str(&format!(
+            "import {{ {} }} from \"{}\";\n\n",
+            import.name, self.specifier
+          ));
+        }
+      }
+      source.push_str(&code_block.code);
+      source.push('\n');
+      self.modules.insert(
+        name,
+        ExampleModule {
+          location: location.clone(),
+          source,
+        },
+      );
+    }
+  }
+}
+
+impl DocVisitor for ExampleCollector {
+  fn visit_doc_node(&mut self, doc_node: &DocNode) {
+    match doc_node.kind {
+      DocNodeKind::Import => return,
+      DocNodeKind::ModuleDoc => {
+        self.location = Some(doc_node.location.clone());
+        self.import = None;
+        self.add_examples(None, None, &doc_node.js_doc);
+        return;
+      }
+      _ => {}
+    }
+
+    self.location = Some(doc_node.location.clone());
+    if self.path.is_empty() {
+      let is_exported =
+        matches!(doc_node.declaration_kind, DeclarationKind::Export);
+      // the default export can't be imported by its name
+      self.import = if is_exported && doc_node.name != "default" {
+        Some(ExampleImport::new(doc_node.name.clone()))
+      } else {
+        None
+      };
+    }
+    self.add_examples(Some(&doc_node.name), None, &doc_node.js_doc);
+    self.path.push(doc_node.name.clone());
+    walk_doc_node(self, doc_node);
+    self.path.pop();
+  }
+
+  fn visit_class_constructor_def(
+    &mut self,
+    constructor_def: &ClassConstructorDef,
+  ) {
+    self.add_examples(
+      Some("constructor"),
+      Some(&constructor_def.location),
+      &constructor_def.js_doc,
+    );
+    walk_class_constructor_def(self, constructor_def);
+  }
+
+  fn visit_class_property_def(&mut self, property_def: &ClassPropertyDef) {
+    self.add_examples(
+      Some(&property_def.name),
+      Some(&property_def.location),
+      &property_def.js_doc,
+    );
+    walk_class_property_def(self, property_def);
+  }
+
+  fn visit_class_method_def(&mut self, method_def: &ClassMethodDef) {
+    self.add_examples(
+      Some(&method_def.name),
+      Some(&method_def.location),
+      &method_def.js_doc,
+    );
+    walk_class_method_def(self, method_def);
+  }
+
+  fn visit_interface_property_def(
+    &mut self,
+    property_def: &InterfacePropertyDef,
+  ) {
+    self.add_examples(
+      Some(&property_def.name),
+      Some(&property_def.location),
+      &property_def.js_doc,
+    );
+    walk_interface_property_def(self, property_def);
+  }
+
+  fn visit_interface_method_def(&mut self, method_def: &InterfaceMethodDef) {
+    self.add_examples(
+      Some(&method_def.name),
+      Some(&method_def.location),
+      &method_def.js_doc,
+    );
+    walk_interface_method_def(self, method_def);
+  }
+
+  // enum members have no location of their own
+  fn visit_enum_member_def(&mut self, member_def: &EnumMemberDef) {
+    self.add_examples(Some(&member_def.name), None, &member_def.js_doc);
+    walk_enum_member_def(self, member_def);
+  }
+
+  // the examples of inherited members are collected from their ancestors
+  fn visit_class_ancestor_def(&mut self, _ancestor_def: &ClassAncestorDef) {}
+
+  fn visit_interface_ancestor_def(
+    &mut self,
+    _ancestor_def: &InterfaceAncestorDef,
+  ) {
+  }
+
+  // types don't have examples
+  fn visit_ts_type_def(&mut self, _ts_type_def: &TsTypeDef) {}
+}
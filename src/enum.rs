@@ -6,6 +6,7 @@ use serde::Serialize;
 
 use crate::js_doc::JsDoc;
 use crate::swc_util::js_doc_for_span;
+use crate::swc_util::JsDocOptions;
 use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::TsTypeDef;
 
@@ -28,6 +29,7 @@ pub struct EnumDef {
 pub fn get_doc_for_ts_enum_decl(
   parsed_source: &ParsedSource,
   enum_decl: &deno_ast::swc::ast::TsEnumDecl,
+  js_doc_options: JsDocOptions,
 ) -> (String, EnumDef) {
   let enum_name = enum_decl.id.sym.to_string();
   let mut members = vec![];
@@ -35,7 +37,8 @@ pub fn get_doc_for_ts_enum_decl(
   for enum_member in &enum_decl.members {
     use deno_ast::swc::ast::TsEnumMemberId::*;
 
-    let js_doc = js_doc_for_span(parsed_source, &enum_member.span);
+    let js_doc =
+      js_doc_for_span(parsed_source, &enum_member.span, js_doc_options);
     let name = match &enum_member.id {
       Ident(ident) => ident.sym.to_string(),
       Str(str_) => str_.value.to_string(),
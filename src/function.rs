@@ -3,6 +3,7 @@
 use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
 use crate::params::param_to_param_def;
+use crate::ts_type::infer_return_ts_type;
 use crate::ts_type::ts_type_ann_to_def;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
@@ -21,6 +22,10 @@ pub struct FunctionDef {
   pub type_params: Vec<TsTypeParamDef>,
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub decorators: Vec<DecoratorDef>,
+  /// The description of the `@returns` tag of the function, as attached by
+  /// `merge_param_docs`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub return_doc: Option<String>,
 }
 
 pub fn function_to_function_def(
@@ -33,7 +38,11 @@ pub fn function_to_function_def(
     .map(|param| param_to_param_def(parsed_source, param))
     .collect();
 
-  let maybe_return_type = function.return_type.as_ref().map(ts_type_ann_to_def);
+  let maybe_return_type = function
+    .return_type
+    .as_ref()
+    .map(ts_type_ann_to_def)
+    .or_else(|| infer_return_ts_type(function));
 
   let type_params =
     maybe_type_param_decl_to_type_param_defs(function.type_params.as_ref());
@@ -47,6 +56,7 @@ pub fn function_to_function_def(
     is_generator: function.is_generator,
     type_params,
     decorators,
+    return_doc: None,
   }
 }
 
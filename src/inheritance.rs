@@ -0,0 +1,723 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassAncestorDef;
+use crate::class::ClassDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceDef;
+use crate::js_doc::parse_inline_tags;
+use crate::js_doc::replace_inline_inherit_doc;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocSegment;
+use crate::js_doc::JsDocTag;
+use crate::markdown::parse_doc_markdown;
+use crate::node::DocNode;
+use crate::parser::DocParser;
+use crate::swc_util::is_false;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TypeRefResolution;
+use crate::type_ref_resolver::namespace_scope;
+use crate::type_ref_resolver::Namespaces;
+use crate::type_ref_resolver::TypeRefResolver;
+use crate::visibility::VisibilityFilter;
+use crate::visit::walk_doc_node_mut;
+use crate::visit::DocVisitorMut;
+
+use deno_graph::ModuleSpecifier;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A member of a class or interface, as inherited from one of its ancestors.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InheritedMemberDef<T> {
+  #[serde(flatten)]
+  pub member: T,
+  /// Whether the member is overridden by a member with the same name of the
+  /// inheriting class or interface, or of an ancestor it is inherited
+  /// through.
+  #[serde(default, skip_serializing_if = "is_false")]
+  pub overridden: bool,
+}
+
+/// Marks which of `members` are overridden by the members with the keys in
+/// `overriding`, and adds their keys to `keys`.
+fn inherit<T, K: Eq + Hash>(
+  members: Vec<T>,
+  key: impl Fn(&T) -> K,
+  overriding: &HashSet<K>,
+  keys: &mut Vec<K>,
+) -> Vec<InheritedMemberDef<T>> {
+  members
+    .into_iter()
+    .map(|member| {
+      let member_key = key(&member);
+      let overridden = overriding.contains(&member_key);
+      keys.push(member_key);
+      InheritedMemberDef { member, overridden }
+    })
+    .collect()
+}
+
+/// Whether `js_doc` has an `@inheritDoc` tag, either a block tag or an inline
+/// tag, along with the target of the tag if any.
+fn inherit_doc_target(js_doc: &JsDoc) -> Option<Option<String>> {
+  let block_target = js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::InheritDoc { target } => Some(target.clone()),
+    _ => None,
+  });
+  block_target.or_else(|| {
+    js_doc.segments.iter().find_map(|segment| match segment {
+      JsDocSegment::InheritDoc { target } => Some(target.clone()),
+      _ => None,
+    })
+  })
+}
+
+/// Whether `a` and `b` document the same thing, so that an own tag replaces
+/// an inherited one.
+fn is_same_tag(a: &JsDocTag, b: &JsDocTag) -> bool {
+  match (a, b) {
+    (JsDocTag::Param { name: a, .. }, JsDocTag::Param { name: b, .. }) => {
+      a == b
+    }
+    (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+  }
+}
+
+/// The docs of `inherited` in place of `js_doc`, keeping the own tags of
+/// `js_doc` other than `@inheritDoc`, and marked as inherited from `source`.
+/// The own documentation around an inline `{@inheritDoc}` tag is kept, with
+/// the tag replaced by the inherited documentation.
+fn inherit_js_doc(js_doc: &JsDoc, inherited: &JsDoc, source: String) -> JsDoc {
+  let mut tags = js_doc
+    .tags
+    .iter()
+    .filter(|tag| !matches!(tag, JsDocTag::InheritDoc { .. }))
+    .cloned()
+    .collect::<Vec<_>>();
+  let inherited_tags = inherited
+    .tags
+    .iter()
+    .filter(|tag| !tags.iter().any(|own_tag| is_same_tag(own_tag, tag)))
+    .cloned()
+    .collect::<Vec<_>>();
+  tags.extend(inherited_tags);
+
+  let has_inline_tag = js_doc
+    .segments
+    .iter()
+    .any(|segment| matches!(segment, JsDocSegment::InheritDoc { .. }));
+  let (doc, segments, markdown) = match &js_doc.doc {
+    Some(doc) if has_inline_tag => {
+      let doc = replace_inline_inherit_doc(
+        doc,
+        inherited.doc.as_deref().unwrap_or_default(),
+      );
+      let segments = parse_inline_tags(&doc);
+      // the markdown of the docs is parsed again if it was parsed before
+      let markdown = if js_doc.markdown.is_empty() {
+        Vec::new()
+      } else {
+        parse_doc_markdown(&doc, &segments)
+      };
+      (Some(doc), segments, markdown)
+    }
+    _ => (
+      inherited.doc.clone(),
+      inherited.segments.clone(),
+      inherited.markdown.clone(),
+    ),
+  };
+  JsDoc {
+    doc,
+    segments,
+    markdown,
+    tags,
+    inherited_from: Some(source),
+    ..js_doc.clone()
+  }
+}
+
+/// The docs of the member named `name` of a class or interface, if it is
+/// documented.
+fn member_js_doc<'d>(
+  doc_node: &'d DocNode,
+  name: &str,
+  is_static: bool,
+) -> Option<&'d JsDoc> {
+  let js_docs: Vec<&JsDoc> = if let Some(class_def) = &doc_node.class_def {
+    let methods = class_def
+      .methods
+      .iter()
+      .filter(|method| method.name == name && method.is_static == is_static)
+      .map(|method| &method.js_doc);
+    let properties = class_def
+      .properties
+      .iter()
+      .filter(|property| {
+        property.name == name && property.is_static == is_static
+      })
+      .map(|property| &property.js_doc);
+    let accessors = class_def
+      .accessors
+      .iter()
+      .filter(|accessor| {
+        accessor.name == name && accessor.is_static == is_static
+      })
+      .map(|accessor| &accessor.js_doc);
+    methods.chain(properties).chain(accessors).collect()
+  } else if let (Some(interface_def), false) =
+    (&doc_node.interface_def, is_static)
+  {
+    let methods = interface_def
+      .methods
+      .iter()
+      .filter(|method| method.name == name)
+      .map(|method| &method.js_doc);
+    let properties = interface_def
+      .properties
+      .iter()
+      .filter(|property| property.name == name)
+      .map(|property| &property.js_doc);
+    let accessors = interface_def
+      .accessors
+      .iter()
+      .filter(|accessor| accessor.name == name)
+      .map(|accessor| &accessor.js_doc);
+    methods.chain(properties).chain(accessors).collect()
+  } else {
+    Vec::new()
+  };
+  js_docs.into_iter().find(|js_doc| !js_doc.is_empty())
+}
+
+/// Fills in the members which classes and interfaces inherit from their
+/// ancestors, following `extends` clauses across modules, or the docs which
+/// their members inherit.
+pub(crate) struct InheritedMembersResolver<'p, 'a> {
+  resolver: TypeRefResolver<'p, 'a>,
+  /// Whether the docs of the members are filled in rather than the inherited
+  /// members.
+  docs: bool,
+  /// Drops the members of ancestors which are hidden from the docs.
+  filter: VisibilityFilter,
+  specifier: Option<ModuleSpecifier>,
+  namespaces: Namespaces,
+}
+
+impl<'p, 'a> InheritedMembersResolver<'p, 'a> {
+  pub fn new(parser: &'p DocParser<'a>) -> Self {
+    Self {
+      resolver: TypeRefResolver::new(parser),
+      docs: false,
+      filter: VisibilityFilter::new(parser.strip_internal),
+      specifier: None,
+      namespaces: Vec::new(),
+    }
+  }
+
+  /// A resolver which gives the undocumented members of classes and
+  /// interfaces, and the members and symbols tagged with `@inheritDoc`, the
+  /// docs of the members they override or implement.
+  pub fn for_docs(parser: &'p DocParser<'a>) -> Self {
+    Self {
+      docs: true,
+      ..Self::new(parser)
+    }
+  }
+
+  /// Finds the doc node of the ancestor named `name` in the `extends` clause
+  /// of a doc node of the module at `specifier`, unless it was visited
+  /// before.
+  fn find_ancestor(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    namespaces: Namespaces,
+    name: &str,
+    visited: &mut HashSet<(String, Vec<String>)>,
+  ) -> Option<(DocNode, ModuleSpecifier, Namespaces)> {
+    match self.resolver.resolve_in(specifier, namespaces, name) {
+      TypeRefResolution::Symbol {
+        specifier,
+        symbol_path,
+        ..
+      } => {
+        if !visited.insert((specifier.clone(), symbol_path.clone())) {
+          return None;
+        }
+        let specifier = ModuleSpecifier::parse(&specifier).ok()?;
+        let (doc_node, namespaces) =
+          self.resolver.find_symbol(&specifier, &symbol_path)?;
+        Some((doc_node, specifier, namespaces))
+      }
+      _ => None,
+    }
+  }
+
+  fn class_ancestors(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    class_def: &ClassDef,
+  ) -> Vec<ClassAncestorDef> {
+    let mut overriding = class_def
+      .properties
+      .iter()
+      .map(|property| (property.name.clone(), property.is_static))
+      .chain(
+        class_def
+          .methods
+          .iter()
+          .map(|method| (method.name.clone(), method.is_static)),
+      )
+      .collect::<HashSet<_>>();
+    let mut visited = HashSet::new();
+    let mut ancestors = Vec::new();
+
+    let mut extends = class_def.extends.clone();
+    let mut specifier = specifier.clone();
+    let mut namespaces = self.namespaces.clone();
+    while let Some(name) = extends {
+      let (doc_node, ancestor_specifier, ancestor_namespaces) =
+        match self.find_ancestor(&specifier, namespaces, &name, &mut visited) {
+          Some(ancestor) => ancestor,
+          None => break,
+        };
+      let mut ancestor_def = match doc_node.class_def {
+        Some(class_def) => class_def,
+        None => break,
+      };
+      self.filter.visit_class_def_mut(&mut ancestor_def);
+
+      let mut keys = Vec::new();
+      let properties = inherit(
+        ancestor_def.properties,
+        |property| (property.name.clone(), property.is_static),
+        &overriding,
+        &mut keys,
+      );
+      let methods = inherit(
+        ancestor_def.methods,
+        |method| (method.name.clone(), method.is_static),
+        &overriding,
+        &mut keys,
+      );
+      let accessors = inherit(
+        ancestor_def.accessors,
+        |accessor| (accessor.name.clone(), accessor.is_static),
+        &overriding,
+        &mut keys,
+      );
+      overriding.extend(keys);
+      ancestors.push(ClassAncestorDef {
+        name,
+        location: doc_node.location,
+        properties,
+        methods,
+        accessors,
+      });
+
+      extends = ancestor_def.extends;
+      specifier = ancestor_specifier;
+      namespaces = ancestor_namespaces;
+    }
+
+    ancestors
+  }
+
+  /// Collects the ancestors of an interface, depth first and in the order of
+  /// the `extends` clauses, along with the names they are referenced by.
+  fn collect_interface_ancestors(
+    &mut self,
+    extends: &[TsTypeDef],
+    specifier: &ModuleSpecifier,
+    namespaces: &Namespaces,
+    visited: &mut HashSet<(String, Vec<String>)>,
+    ancestors: &mut Vec<(String, DocNode)>,
+  ) {
+    for ts_type in extends {
+      let type_ref = match &ts_type.kind {
+        TsTypeDefKind::TypeRef(type_ref) => type_ref,
+        _ => continue,
+      };
+      if let Some((doc_node, ancestor_specifier, ancestor_namespaces)) = self
+        .find_ancestor(
+          specifier,
+          namespaces.clone(),
+          &type_ref.type_name,
+          visited,
+        )
+      {
+        if let Some(interface_def) = &doc_node.interface_def {
+          let ancestor_extends = interface_def.extends.clone();
+          ancestors.push((type_ref.type_name.clone(), doc_node));
+          self.collect_interface_ancestors(
+            &ancestor_extends,
+            &ancestor_specifier,
+            &ancestor_namespaces,
+            visited,
+            ancestors,
+          );
+        }
+      }
+    }
+  }
+
+  /// Collects the ancestors of an interface, depth first and in the order of
+  /// the `extends` clauses, with their members which are overridden by the
+  /// members with the keys in `overriding` marked. Those are the members of
+  /// the inheriting interface and of the ancestors in between, but not of
+  /// sibling ancestors, which the interface extends side by side.
+  fn inherit_interface_ancestors(
+    &mut self,
+    extends: &[TsTypeDef],
+    specifier: &ModuleSpecifier,
+    namespaces: &Namespaces,
+    overriding: &HashSet<String>,
+    visited: &mut HashSet<(String, Vec<String>)>,
+    ancestors: &mut Vec<InterfaceAncestorDef>,
+  ) {
+    for ts_type in extends {
+      let type_ref = match &ts_type.kind {
+        TsTypeDefKind::TypeRef(type_ref) => type_ref,
+        _ => continue,
+      };
+      let (doc_node, ancestor_specifier, ancestor_namespaces) = match self
+        .find_ancestor(
+          specifier,
+          namespaces.clone(),
+          &type_ref.type_name,
+          visited,
+        ) {
+        Some(ancestor) => ancestor,
+        None => continue,
+      };
+      let mut ancestor_def = match doc_node.interface_def {
+        Some(interface_def) => interface_def,
+        None => continue,
+      };
+      self.filter.visit_interface_def_mut(&mut ancestor_def);
+
+      let mut keys = Vec::new();
+      let properties = inherit(
+        ancestor_def.properties,
+        |property| property.name.clone(),
+        overriding,
+        &mut keys,
+      );
+      let methods = inherit(
+        ancestor_def.methods,
+        |method| method.name.clone(),
+        overriding,
+        &mut keys,
+      );
+      let accessors = inherit(
+        ancestor_def.accessors,
+        |accessor| accessor.name.clone(),
+        overriding,
+        &mut keys,
+      );
+      ancestors.push(InterfaceAncestorDef {
+        name: type_ref.type_name.clone(),
+        location: doc_node.location,
+        properties,
+        methods,
+        accessors,
+      });
+
+      let mut ancestor_overriding = overriding.clone();
+      ancestor_overriding.extend(keys);
+      self.inherit_interface_ancestors(
+        &ancestor_def.extends,
+        &ancestor_specifier,
+        &ancestor_namespaces,
+        &ancestor_overriding,
+        visited,
+        ancestors,
+      );
+    }
+  }
+
+  fn interface_ancestors(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    interface_def: &InterfaceDef,
+  ) -> Vec<InterfaceAncestorDef> {
+    let overriding = interface_def
+      .properties
+      .iter()
+      .map(|property| property.name.clone())
+      .chain(
+        interface_def
+          .methods
+          .iter()
+          .map(|method| method.name.clone()),
+      )
+      .collect::<HashSet<_>>();
+    let mut ancestors = Vec::new();
+    let namespaces = self.namespaces.clone();
+    self.inherit_interface_ancestors(
+      &interface_def.extends,
+      specifier,
+      &namespaces,
+      &overriding,
+      &mut HashSet::new(),
+      &mut ancestors,
+    );
+    ancestors
+  }
+
+  /// The classes and interfaces which the members of a class can inherit the
+  /// docs of, the classes in its `extends` chain first, followed by the
+  /// interfaces they implement, along with the names they are referenced by.
+  fn class_doc_ancestors(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    class_def: &ClassDef,
+  ) -> Vec<(String, DocNode)> {
+    let mut visited = HashSet::new();
+    let mut ancestors = Vec::new();
+    let mut implements = vec![(
+      class_def.implements.clone(),
+      specifier.clone(),
+      self.namespaces.clone(),
+    )];
+
+    let mut extends = class_def.extends.clone();
+    let mut specifier = specifier.clone();
+    let mut namespaces = self.namespaces.clone();
+    while let Some(name) = extends {
+      let (doc_node, ancestor_specifier, ancestor_namespaces) =
+        match self.find_ancestor(&specifier, namespaces, &name, &mut visited) {
+          Some(ancestor) => ancestor,
+          None => break,
+        };
+      extends = match &doc_node.class_def {
+        Some(ancestor_def) => {
+          implements.push((
+            ancestor_def.implements.clone(),
+            ancestor_specifier.clone(),
+            ancestor_namespaces.clone(),
+          ));
+          ancestor_def.extends.clone()
+        }
+        None => break,
+      };
+      ancestors.push((name, doc_node));
+      specifier = ancestor_specifier;
+      namespaces = ancestor_namespaces;
+    }
+
+    for (implements, specifier, namespaces) in implements {
+      self.collect_interface_ancestors(
+        &implements,
+        &specifier,
+        &namespaces,
+        &mut visited,
+        &mut ancestors,
+      );
+    }
+    ancestors
+  }
+
+  /// Finds the docs of the target of an `@inheritDoc` tag, which is either a
+  /// symbol, e.g. `Base`, or a member of a class or interface, e.g.
+  /// `Base.method`.
+  fn find_inherit_doc_target(&mut self, target: &str) -> Option<JsDoc> {
+    let specifier = self.specifier.clone()?;
+    if let Some((doc_node, _, _)) = self.find_ancestor(
+      &specifier,
+      self.namespaces.clone(),
+      target,
+      &mut HashSet::new(),
+    ) {
+      return Some(doc_node.js_doc);
+    }
+    let (name, member_name) = target.rsplit_once('.')?;
+    let (doc_node, _, _) = self.find_ancestor(
+      &specifier,
+      self.namespaces.clone(),
+      name,
+      &mut HashSet::new(),
+    )?;
+    member_js_doc(&doc_node, member_name, false)
+      .or_else(|| member_js_doc(&doc_node, member_name, true))
+      .cloned()
+  }
+
+  /// Gives a member named `name` the docs of the first of `ancestors` with a
+  /// documented member of the same name, if it is undocumented or tagged
+  /// with `@inheritDoc`. The ancestors' members which are tagged with
+  /// `@inheritDoc` themselves are skipped.
+  fn inherit_member_doc(
+    &mut self,
+    js_doc: &mut JsDoc,
+    name: &str,
+    is_static: bool,
+    ancestors: &[(String, DocNode)],
+  ) {
+    let target = inherit_doc_target(js_doc);
+    if target.is_none() && !js_doc.is_empty() {
+      return;
+    }
+    let inherited = match target.flatten() {
+      Some(target) => self
+        .find_inherit_doc_target(&target)
+        .map(|inherited| (target, inherited)),
+      None => ancestors.iter().find_map(|(ancestor_name, doc_node)| {
+        let inherited = member_js_doc(doc_node, name, is_static)?;
+        if inherit_doc_target(inherited).is_some() {
+          return None;
+        }
+        Some((format!("{}.{}", ancestor_name, name), inherited.clone()))
+      }),
+    };
+    if let Some((source, inherited)) = inherited {
+      *js_doc = inherit_js_doc(js_doc, &inherited, source);
+    }
+  }
+
+  fn inherit_docs(&mut self, doc_node: &mut DocNode) {
+    let specifier = match self.specifier.clone() {
+      Some(specifier) => specifier,
+      None => return,
+    };
+    let ancestors = if let Some(class_def) = &doc_node.class_def {
+      self.class_doc_ancestors(&specifier, class_def)
+    } else if let Some(interface_def) = &doc_node.interface_def {
+      let mut ancestors = Vec::new();
+      let namespaces = self.namespaces.clone();
+      self.collect_interface_ancestors(
+        &interface_def.extends,
+        &specifier,
+        &namespaces,
+        &mut HashSet::new(),
+        &mut ancestors,
+      );
+      ancestors
+    } else {
+      Vec::new()
+    };
+
+    // symbols only inherit docs when tagged with `@inheritDoc`, from the
+    // target of the tag or else from their first ancestor
+    if let Some(target) = inherit_doc_target(&doc_node.js_doc) {
+      let inherited = match target {
+        Some(target) => self
+          .find_inherit_doc_target(&target)
+          .map(|inherited| (target, inherited)),
+        None => ancestors
+          .first()
+          .map(|(name, ancestor)| (name.clone(), ancestor.js_doc.clone())),
+      };
+      if let Some((source, inherited)) = inherited {
+        doc_node.js_doc = inherit_js_doc(&doc_node.js_doc, &inherited, source);
+      }
+    }
+
+    if let Some(class_def) = &mut doc_node.class_def {
+      for method in &mut class_def.methods {
+        self.inherit_member_doc(
+          &mut method.js_doc,
+          &method.name,
+          method.is_static,
+          &ancestors,
+        );
+      }
+      for property in &mut class_def.properties {
+        self.inherit_member_doc(
+          &mut property.js_doc,
+          &property.name,
+          property.is_static,
+          &ancestors,
+        );
+      }
+      for accessor in &mut class_def.accessors {
+        self.inherit_member_doc(
+          &mut accessor.js_doc,
+          &accessor.name,
+          accessor.is_static,
+          &ancestors,
+        );
+      }
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for method in &mut interface_def.methods {
+        self.inherit_member_doc(
+          &mut method.js_doc,
+          &method.name,
+          false,
+          &ancestors,
+        );
+      }
+      for property in &mut interface_def.properties {
+        self.inherit_member_doc(
+          &mut property.js_doc,
+          &property.name,
+          false,
+          &ancestors,
+        );
+      }
+      for accessor in &mut interface_def.accessors {
+        self.inherit_member_doc(
+          &mut accessor.js_doc,
+          &accessor.name,
+          false,
+          &ancestors,
+        );
+      }
+    }
+  }
+}
+
+impl DocVisitorMut for InheritedMembersResolver<'_, '_> {
+  fn visit_doc_node_mut(&mut self, doc_node: &mut DocNode) {
+    // doc nodes of reexports are declared in other modules than the doc nodes
+    // around them
+    let specifier = ModuleSpecifier::parse(&doc_node.location.filename).ok();
+    if specifier != self.specifier {
+      let specifier = std::mem::replace(&mut self.specifier, specifier);
+      let namespaces = std::mem::take(&mut self.namespaces);
+      self.visit_doc_node_mut(doc_node);
+      self.specifier = specifier;
+      self.namespaces = namespaces;
+      return;
+    }
+
+    if self.docs {
+      self.inherit_docs(doc_node);
+    }
+    if let Some(namespace) = namespace_scope(doc_node) {
+      self.namespaces.push(namespace);
+      walk_doc_node_mut(self, doc_node);
+      self.namespaces.pop();
+    } else {
+      walk_doc_node_mut(self, doc_node);
+    }
+  }
+
+  fn visit_class_def_mut(&mut self, class_def: &mut ClassDef) {
+    if self.docs {
+      return;
+    }
+    if let Some(specifier) = self.specifier.clone() {
+      class_def.inherited = self.class_ancestors(&specifier, class_def);
+    }
+  }
+
+  fn visit_interface_def_mut(&mut self, interface_def: &mut InterfaceDef) {
+    if self.docs {
+      return;
+    }
+    if let Some(specifier) = self.specifier.clone() {
+      interface_def.inherited =
+        self.interface_ancestors(&specifier, interface_def);
+    }
+  }
+
+  // only classes and interfaces have ancestors
+  fn visit_ts_type_def_mut(&mut self, _ts_type_def: &mut TsTypeDef) {}
+}
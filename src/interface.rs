@@ -4,13 +4,18 @@ use deno_ast::ParsedSource;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::accessor::merge_accessors;
+use crate::accessor::AccessorDef;
+use crate::accessor::AccessorPart;
 use crate::function::FunctionDef;
+use crate::inheritance::InheritedMemberDef;
 use crate::js_doc::JsDoc;
 use crate::node::DeclarationKind;
 use crate::params::ts_fn_param_to_param_def;
 use crate::swc_util::get_location;
 use crate::swc_util::is_false;
 use crate::swc_util::js_doc_for_span;
+use crate::swc_util::JsDocOptions;
 use crate::ts_type::ts_type_ann_to_def;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
@@ -47,6 +52,10 @@ pub struct InterfaceMethodDef {
   pub params: Vec<ParamDef>,
   pub return_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
+  /// The description of the `@returns` tag of the method, as attached by
+  /// `merge_param_docs`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub return_doc: Option<String>,
 }
 
 impl From<InterfaceMethodDef> for DocNode {
@@ -63,6 +72,7 @@ impl From<InterfaceMethodDef> for DocNode {
         is_generator: false,
         type_params: def.type_params,
         decorators: vec![],
+        return_doc: def.return_doc,
       },
     )
   }
@@ -99,6 +109,10 @@ pub struct InterfacePropertyDef {
   pub optional: bool,
   pub ts_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
+  /// The description of the `@returns` tag of the call signature, as
+  /// attached by `merge_param_docs`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub return_doc: Option<String>,
 }
 
 impl From<InterfacePropertyDef> for DocNode {
@@ -177,6 +191,53 @@ pub struct InterfaceDef {
   pub call_signatures: Vec<InterfaceCallSignatureDef>,
   pub index_signatures: Vec<InterfaceIndexSignatureDef>,
   pub type_params: Vec<TsTypeParamDef>,
+  /// The getters and setters of `methods`, merged into properties.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub accessors: Vec<AccessorDef>,
+  /// The members inherited from the ancestors of the interface, depth first
+  /// in the order of the `extends` clauses, as filled in by
+  /// `DocParser::resolve_inherited_members`.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub inherited: Vec<InterfaceAncestorDef>,
+}
+
+/// An ancestor of an interface, with the members inherited from it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceAncestorDef {
+  /// The name of the ancestor, as referenced by the `extends` clause.
+  pub name: String,
+  pub location: Location,
+  pub properties: Vec<InheritedMemberDef<InterfacePropertyDef>>,
+  pub methods: Vec<InheritedMemberDef<InterfaceMethodDef>>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub accessors: Vec<InheritedMemberDef<AccessorDef>>,
+}
+
+pub(crate) fn interface_accessors_to_defs(
+  methods: &[InterfaceMethodDef],
+) -> Vec<AccessorDef> {
+  merge_accessors(
+    methods
+      .iter()
+      .map(|method| AccessorPart {
+        kind: method.kind,
+        name: &method.name,
+        location: &method.location,
+        js_doc: &method.js_doc,
+        ts_type: match method.kind {
+          deno_ast::swc::ast::MethodKind::Setter => {
+            method.params.first().and_then(|param| param.ts_type())
+          }
+          _ => method.return_type.as_ref(),
+        },
+        optional: method.optional,
+        accessibility: None,
+        is_abstract: false,
+        is_static: false,
+      })
+      .collect(),
+  )
 }
 
 pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
@@ -223,6 +284,7 @@ pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
 pub fn get_doc_for_ts_interface_decl(
   parsed_source: &ParsedSource,
   interface_decl: &deno_ast::swc::ast::TsInterfaceDecl,
+  js_doc_options: JsDocOptions,
 ) -> (String, InterfaceDef) {
   let interface_name = interface_decl.id.sym.to_string();
 
@@ -236,7 +298,8 @@ pub fn get_doc_for_ts_interface_decl(
 
     match &type_element {
       TsMethodSignature(ts_method_sig) => {
-        let method_js_doc = js_doc_for_span(parsed_source, &ts_method_sig.span);
+        let method_js_doc =
+          js_doc_for_span(parsed_source, &ts_method_sig.span, js_doc_options);
 
         let mut params = vec![];
 
@@ -264,11 +327,13 @@ pub fn get_doc_for_ts_interface_decl(
           params,
           return_type: maybe_return_type,
           type_params,
+          return_doc: None,
         };
         methods.push(method_def);
       }
       TsGetterSignature(ts_getter_sig) => {
-        let method_js_doc = js_doc_for_span(parsed_source, &ts_getter_sig.span);
+        let method_js_doc =
+          js_doc_for_span(parsed_source, &ts_getter_sig.span, js_doc_options);
         let name = expr_to_name(&*ts_getter_sig.key);
 
         let maybe_return_type =
@@ -284,11 +349,13 @@ pub fn get_doc_for_ts_interface_decl(
           params: vec![],
           return_type: maybe_return_type,
           type_params: vec![],
+          return_doc: None,
         };
         methods.push(method_def);
       }
       TsSetterSignature(ts_setter_sig) => {
-        let method_js_doc = js_doc_for_span(parsed_source, &ts_setter_sig.span);
+        let method_js_doc =
+          js_doc_for_span(parsed_source, &ts_setter_sig.span, js_doc_options);
 
         let name = expr_to_name(&*ts_setter_sig.key);
 
@@ -306,11 +373,13 @@ pub fn get_doc_for_ts_interface_decl(
           params,
           return_type: None,
           type_params: vec![],
+          return_doc: None,
         };
         methods.push(method_def);
       }
       TsPropertySignature(ts_prop_sig) => {
-        let prop_js_doc = js_doc_for_span(parsed_source, &ts_prop_sig.span);
+        let prop_js_doc =
+          js_doc_for_span(parsed_source, &ts_prop_sig.span, js_doc_options);
         let name = expr_to_name(&*ts_prop_sig.key);
 
         let mut params = vec![];
@@ -340,7 +409,8 @@ pub fn get_doc_for_ts_interface_decl(
         properties.push(prop_def);
       }
       TsCallSignatureDecl(ts_call_sig) => {
-        let call_sig_js_doc = js_doc_for_span(parsed_source, &ts_call_sig.span);
+        let call_sig_js_doc =
+          js_doc_for_span(parsed_source, &ts_call_sig.span, js_doc_options);
 
         let mut params = vec![];
         for param in &ts_call_sig.params {
@@ -360,6 +430,7 @@ pub fn get_doc_for_ts_interface_decl(
           params,
           ts_type,
           type_params,
+          return_doc: None,
         };
         call_signatures.push(call_sig_def);
       }
@@ -384,8 +455,11 @@ pub fn get_doc_for_ts_interface_decl(
         index_signatures.push(index_sig_def);
       }
       TsConstructSignatureDecl(ts_construct_sig) => {
-        let construct_js_doc =
-          js_doc_for_span(parsed_source, &ts_construct_sig.span);
+        let construct_js_doc = js_doc_for_span(
+          parsed_source,
+          &ts_construct_sig.span,
+          js_doc_options,
+        );
 
         let mut params = vec![];
 
@@ -413,6 +487,7 @@ pub fn get_doc_for_ts_interface_decl(
           params,
           return_type: maybe_return_type,
           type_params,
+          return_doc: None,
         };
 
         methods.push(construct_sig_def);
@@ -430,6 +505,8 @@ pub fn get_doc_for_ts_interface_decl(
     .map(|expr| expr.into())
     .collect::<Vec<TsTypeDef>>();
 
+  let accessors = interface_accessors_to_defs(&methods);
+
   let interface_def = InterfaceDef {
     extends,
     methods,
@@ -437,6 +514,8 @@ pub fn get_doc_for_ts_interface_decl(
     call_signatures,
     index_signatures,
     type_params,
+    accessors,
+    inherited: Vec::new(),
   };
 
   (interface_name, interface_def)
@@ -2,6 +2,9 @@
 // https://github.com/rustwasm/wasm-bindgen/issues/2774
 #![allow(clippy::unused_unit)]
 
+use crate::category::GroupBy;
+use crate::custom_tag::CustomTag;
+use crate::node::DocNode;
 use crate::parser::DocParser;
 
 use anyhow::anyhow;
@@ -12,6 +15,7 @@ use deno_graph::source::Loader;
 use deno_graph::source::ResolveResponse;
 use deno_graph::source::Resolver;
 use deno_graph::ModuleSpecifier;
+use serde::Deserialize;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
@@ -87,16 +91,40 @@ impl Resolver for JsResolver {
   }
 }
 
+/// The options of `doc()`, each of which defaults to `false`, or to no custom
+/// tags.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ParseOptions {
+  include_all: bool,
+  strip_internal: bool,
+  resolve_type_refs: bool,
+  parse_js_doc_types: bool,
+  loose_js_doc: bool,
+  js_doc_comments: bool,
+  resolve_deprecations: bool,
+  markdown: bool,
+  tagged_json: bool,
+  custom_tags: Vec<CustomTag>,
+}
+
 #[wasm_bindgen]
 pub async fn doc(
   root_specifier: String,
-  include_all: bool,
+  options: JsValue,
   load: js_sys::Function,
   maybe_resolve: Option<js_sys::Function>,
 ) -> Result<JsValue, JsValue> {
   console_error_panic_hook::set_once();
   let root_specifier = ModuleSpecifier::parse(&root_specifier)
     .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))?;
+  let options: ParseOptions = if options.is_undefined() {
+    ParseOptions::default()
+  } else {
+    options
+      .into_serde()
+      .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))?
+  };
   let mut loader = JsLoader::new(load);
   let maybe_resolver = maybe_resolve.map(JsResolver::new);
   let graph = create_type_graph(
@@ -111,9 +139,58 @@ pub async fn doc(
   )
   .await;
   let source_parser = deno_graph::DefaultSourceParser::new();
-  let entries = DocParser::new(graph, include_all, &source_parser)
+  let mut doc_parser =
+    DocParser::new(graph, options.include_all, &source_parser);
+  for custom_tag in options.custom_tags {
+    doc_parser.register_tag_shape(custom_tag.name, custom_tag.shape);
+  }
+  doc_parser.strip_internal = options.strip_internal;
+  doc_parser.loose_js_doc = options.loose_js_doc;
+  doc_parser.js_doc_comments = options.js_doc_comments;
+  let mut entries = doc_parser
     .parse_with_reexports(&root_specifier)
     .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))?;
-  JsValue::from_serde(&entries)
+  if options.parse_js_doc_types {
+    crate::parse_js_doc_types(&mut entries);
+  }
+  // the references to stripped symbols are only flagged when resolved
+  if options.resolve_type_refs || options.strip_internal {
+    doc_parser.resolve_type_refs(&mut entries);
+  }
+  if options.resolve_deprecations {
+    crate::resolve_deprecations(&mut entries);
+  }
+  if options.markdown {
+    crate::parse_js_doc_markdown(&mut entries);
+  }
+  let entries = if options.tagged_json {
+    JsValue::from_serde(&crate::TaggedJson(&entries))
+  } else {
+    JsValue::from_serde(&entries)
+  };
+  entries.map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
+}
+
+#[wasm_bindgen]
+pub fn group_by_category(
+  entries: JsValue,
+  group_by: JsValue,
+) -> Result<JsValue, JsValue> {
+  let entries: Vec<DocNode> = entries
+    .into_serde()
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))?;
+  let group_by: GroupBy = group_by
+    .into_serde()
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))?;
+  JsValue::from_serde(&crate::category::group_by_category(&entries, group_by))
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
+}
+
+#[wasm_bindgen]
+pub fn deprecations(entries: JsValue) -> Result<JsValue, JsValue> {
+  let entries: Vec<DocNode> = entries
+    .into_serde()
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))?;
+  JsValue::from_serde(&crate::deprecation::deprecations(&entries))
     .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
 }
@@ -1,45 +1,89 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use crate::deprecation::Deprecation;
+use crate::markdown::MarkdownBlock;
+use crate::node::Location;
+use crate::swc_util::is_false;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TypeRefResolution;
+
+use regex::Captures;
 use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 
 lazy_static! {
-  static ref JS_DOC_TAG_MAYBE_DOC_RE: Regex = Regex::new(r#"(?s)^\s*@(deprecated|example)(?:\s+(.+))?"#).unwrap();
+  static ref JS_DOC_TAG_INHERIT_DOC_RE: Regex = Regex::new(r#"(?i)^\s*@inheritdoc(?:[ \t]+(\S+))?(?:\s|$)"#).unwrap();
+  static ref JS_DOC_TAG_MAYBE_DOC_RE: Regex = Regex::new(r#"(?s)^\s*@(deprecated|example|experimental)(?:\s+(.+))?$"#).unwrap();
   static ref JS_DOC_TAG_NAMED_RE: Regex = Regex::new(r#"(?s)^\s*@(callback|template)\s+([a-zA-Z_$]\S*)(?:\s+(.+))?"#).unwrap();
-  static ref JS_DOC_TAG_NAMED_TYPED_RE: Regex = Regex::new(r#"(?s)^\s*@(prop(?:erty)?|typedef)\s+\{([^}]+)\}\s+([a-zA-Z_$]\S*)(?:\s+(.+))?"#).unwrap();
-  static ref JS_DOC_TAG_ONLY_RE: Regex = Regex::new(r#"^\s*@(constructor|class|module|public|private|protected|readonly)"#).unwrap();
+  static ref JS_DOC_TAG_NAMED_TYPED_RE: Regex = Regex::new(r#"(?s)^\s*@(prop(?:erty)?|typedef)\s+([a-zA-Z_$]\S*)(?:\s+(.+))?"#).unwrap();
+  static ref JS_DOC_TAG_ONLY_RE: Regex = Regex::new(r#"^\s*@(constructor|class|module|public|private|protected|readonly|ignore|internal|override|async)(?:\s|$)"#).unwrap();
   static ref JS_DOC_TAG_PARAM_RE: Regex = Regex::new(
-    r#"(?s)^\s*@(?:param|arg(?:ument)?)(?:\s+\{([^}]+)\})?\s+([a-zA-Z_$]\S*)(?:\s+(.+))?"#
+    r#"(?s)^\s*@(?:param|arg(?:ument)?)\s+(?:([a-zA-Z_$]\S*)|\[\s*([a-zA-Z_$][^\]=\s]*)\s*(?:=\s*([^\]]*?))?\s*\])(?:\s+(.+))?"#
   )
   .unwrap();
   static ref JS_DOC_TAG_RE: Regex = Regex::new(r#"(?s)^\s*@(\S+)"#).unwrap();
-  static ref JS_DOC_TAG_RETURN_RE: Regex = Regex::new(r#"(?s)^\s*@returns?(?:\s+\{([^}]+)\})?(?:\s+(.+))?"#).unwrap();
-  static ref JS_DOC_TAG_TYPED_RE: Regex = Regex::new(r#"(?s)^\s*@(enum|extends|augments|this|type)\s+\{([^}]+)\}(?:\s+(.+))?"#).unwrap();
+  static ref JS_DOC_TAG_RETURN_RE: Regex = Regex::new(r#"(?s)^\s*@returns?(?:\s+(.+))?$"#).unwrap();
+  static ref JS_DOC_TAG_THROWS_RE: Regex = Regex::new(r#"(?s)^\s*@(?:throws|exception)(?:\s+(.+))?$"#).unwrap();
+  static ref JS_DOC_TAG_TYPED_RE: Regex = Regex::new(r#"(?s)^\s*@(enum|extends|augments|this|type)(?:\s+(.+))?$"#).unwrap();
+  static ref JS_DOC_TAG_VALUE_RE: Regex = Regex::new(r#"(?s)^\s*@(default(?:Value)?|see|since|category|group|tags)\s+(.+)"#).unwrap();
+  static ref JS_DOC_INLINE_TAG_RE: Regex = Regex::new(r#"\{@(link|linkcode|linkplain|inheritDoc)(?:\s+([^\s|}]+)(?:\s*\|\s*|\s+)?([^}]*))?\}"#).unwrap();
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
 pub struct JsDoc {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub doc: Option<String>,
+  /// `doc` split into text and inline tags, if it has any inline tags.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub segments: Vec<JsDocSegment>,
+  /// `doc` parsed as Markdown, as filled in by `parse_js_doc_markdown`.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub markdown: Vec<MarkdownBlock>,
   #[serde(skip_serializing_if = "Vec::is_empty")]
   pub tags: Vec<JsDocTag>,
+  /// The symbol or member the docs are inherited from, e.g. `Base.method`,
+  /// as filled in by `DocParser::resolve_inherited_docs`.
+  #[serde(
+    rename = "inheritedFrom",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub inherited_from: Option<String>,
+  /// The comment the docs were parsed from, if kept with
+  /// `DocParser::js_doc_comments`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub comment: Option<JsDocComment>,
+  /// The deprecation of the symbol or member, by its own `@deprecated` tag or
+  /// by the one of the namespace, class, interface or enum it is in, as
+  /// filled in by `resolve_deprecations`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub deprecation: Option<Deprecation>,
 }
 
 impl JsDoc {
-  pub fn is_empty(&self) -> bool {
-    self.doc.is_none() && self.tags.is_empty()
+  pub fn new(doc: Option<String>, tags: Vec<JsDocTag>) -> Self {
+    Self {
+      segments: doc_segments(doc.as_deref()),
+      markdown: Vec::new(),
+      doc,
+      tags,
+      inherited_from: None,
+      comment: None,
+      deprecation: None,
+    }
   }
-}
 
-impl From<String> for JsDoc {
-  fn from(value: String) -> Self {
+  /// Parses the text of a comment, stripped of its leading `*`s, along with
+  /// the line of each of the tags within the text.
+  pub(crate) fn parse_with_tag_lines(value: &str) -> (Self, Vec<JsDocTagLine>) {
     let mut tags = Vec::new();
+    let mut tag_lines = Vec::new();
     let mut doc_lines = Vec::new();
     let mut is_tag = false;
     let mut current_tag: Vec<&str> = Vec::new();
     let mut current_tag_name = "";
-    for line in value.lines() {
+    for (index, line) in value.lines().enumerate() {
       let caps = JS_DOC_TAG_RE.captures(line);
       if is_tag || caps.is_some() {
         if !is_tag {
@@ -52,6 +96,10 @@ impl From<String> for JsDoc {
         }
         if let Some(caps) = caps {
           current_tag_name = caps.get(1).unwrap().as_str();
+          tag_lines.push(JsDocTagLine {
+            tag: tag_lines.len(),
+            line: index,
+          });
         }
         // certain tags, we want to preserve any leading whitespace
         if matches!(current_tag_name, "example") {
@@ -71,54 +119,445 @@ impl From<String> for JsDoc {
     } else {
       Some(doc_lines.join("\n"))
     };
-    Self { doc, tags }
+    (Self::new(doc, tags), tag_lines)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.doc.is_none() && self.tags.is_empty() && self.deprecation.is_none()
+  }
+
+  /// The code blocks of the `@example` tags, in order.
+  pub fn example_code_blocks(&self) -> Vec<&CodeBlock> {
+    self
+      .tags
+      .iter()
+      .filter_map(|tag| match tag {
+        JsDocTag::Example { blocks, .. } => Some(blocks),
+        _ => None,
+      })
+      .flatten()
+      .filter_map(|block| match block {
+        ExampleBlock::Code(code_block) => Some(code_block),
+        ExampleBlock::Prose { .. } => None,
+      })
+      .collect()
+  }
+}
+
+/// A part of the documentation of a JSDoc comment or tag, which is either text
+/// or an inline tag.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum JsDocSegment {
+  Text {
+    value: String,
+  },
+  /// `{@link target}`, `{@link target | label}` or `{@link target label}`,
+  /// and the same for `{@linkcode}` and `{@linkplain}`
+  Link {
+    target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    style: LinkStyle,
+    /// The symbol the target refers to, as filled in by
+    /// `DocParser::resolve_type_refs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<TypeRefResolution>,
+  },
+  /// `{@inheritDoc}` or `{@inheritDoc target}`
+  InheritDoc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+  },
+}
+
+/// How the target of a link should be rendered.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkStyle {
+  /// `{@link}`
+  Link,
+  /// `{@linkcode}`, rendered as code
+  Code,
+  /// `{@linkplain}`, rendered as plain text
+  Plain,
+}
+
+fn doc_segments(doc: Option<&str>) -> Vec<JsDocSegment> {
+  doc.map(parse_inline_tags).unwrap_or_default()
+}
+
+/// Splits documentation into text and inline tags. Returns no segments when
+/// there are no inline tags.
+pub fn parse_inline_tags(doc: &str) -> Vec<JsDocSegment> {
+  let mut segments = Vec::new();
+  let mut last = 0;
+  for caps in JS_DOC_INLINE_TAG_RE.captures_iter(doc) {
+    let whole = caps.get(0).unwrap();
+    if whole.start() > last {
+      segments.push(JsDocSegment::Text {
+        value: doc[last..whole.start()].to_string(),
+      });
+    }
+    last = whole.end();
+    segments.push(inline_tag_segment(&caps));
+  }
+  if segments.is_empty() {
+    return segments;
+  }
+  if last < doc.len() {
+    segments.push(JsDocSegment::Text {
+      value: doc[last..].to_string(),
+    });
+  }
+  segments
+}
+
+/// Replaces the inline `{@inheritDoc}` tags of documentation with the
+/// inherited documentation.
+pub(crate) fn replace_inline_inherit_doc(doc: &str, inherited: &str) -> String {
+  JS_DOC_INLINE_TAG_RE
+    .replace_all(doc, |caps: &Captures| {
+      if &caps[1] == "inheritDoc" {
+        inherited.to_string()
+      } else {
+        caps[0].to_string()
+      }
+    })
+    .into_owned()
+}
+
+/// The inline tag at the start of `text`, if it starts with one, and its
+/// length.
+pub(crate) fn leading_inline_tag(text: &str) -> Option<(JsDocSegment, usize)> {
+  let caps = JS_DOC_INLINE_TAG_RE.captures(text)?;
+  let whole = caps.get(0).unwrap();
+  if whole.start() != 0 {
+    return None;
+  }
+  Some((inline_tag_segment(&caps), whole.end()))
+}
+
+fn inline_tag_segment(caps: &Captures) -> JsDocSegment {
+  let target = caps.get(2).map(|m| m.as_str().to_string());
+  match (caps.get(1).unwrap().as_str(), target) {
+    ("inheritDoc", target) => JsDocSegment::InheritDoc { target },
+    (kind, Some(target)) => JsDocSegment::Link {
+      target,
+      label: caps
+        .get(3)
+        .map(|m| m.as_str().trim())
+        .filter(|label| !label.is_empty())
+        .map(String::from),
+      style: match kind {
+        "linkcode" => LinkStyle::Code,
+        "linkplain" => LinkStyle::Plain,
+        _ => LinkStyle::Link,
+      },
+      resolution: None,
+    },
+    // a link without a target is kept as text
+    (_, None) => JsDocSegment::Text {
+      value: caps.get(0).unwrap().as_str().to_string(),
+    },
   }
 }
 
+/// A part of an `@example` tag.
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ExampleBlock {
+  Prose { doc: String },
+  Code(CodeBlock),
+}
+
+/// A code block of an `@example` tag, either fenced or the whole example.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeBlock {
+  /// The language of the code fence, `ts` if there is none.
+  pub lang: String,
+  pub code: String,
+}
+
+fn is_code_fence(line: &str) -> bool {
+  line.trim_start().starts_with("```")
+}
+
+/// Splits the documentation of an `@example` tag into its caption and blocks.
+/// The first line of the documentation is a title when it follows `@example`
+/// on the line of the tag, and is followed by a code fence.
+fn parse_example(tag: &str, doc: &str) -> (Option<String>, Vec<ExampleBlock>) {
+  let mut body = doc;
+  let mut caption = None;
+  if let Some(rest) = doc.trim_start().strip_prefix("<caption>") {
+    if let Some((inner, rest)) = rest.split_once("</caption>") {
+      caption = Some(inner.trim().to_string());
+      body = rest;
+    }
+  } else if let Some((first_line, rest)) = doc.split_once('\n') {
+    let has_title = tag
+      .lines()
+      .next()
+      .map_or(false, |line| line.trim() != "@example");
+    if has_title && rest.lines().any(is_code_fence) {
+      caption = Some(first_line.trim().to_string());
+      body = rest;
+    }
+  }
+  (caption, example_blocks(body))
+}
+
+/// Splits an example into prose and fenced code blocks. An example without
+/// code fences is a single block of TypeScript.
+fn example_blocks(body: &str) -> Vec<ExampleBlock> {
+  let mut blocks = Vec::new();
+  if !body.lines().any(is_code_fence) {
+    let code = dedent(&body.lines().collect::<Vec<_>>());
+    if !code.is_empty() {
+      blocks.push(ExampleBlock::Code(CodeBlock {
+        lang: "ts".to_string(),
+        code,
+      }));
+    }
+    return blocks;
+  }
+
+  let mut lines = Vec::new();
+  // the language of the code block the lines belong to, if any
+  let mut lang = None;
+  for line in body.lines() {
+    if !is_code_fence(line) {
+      lines.push(line);
+      continue;
+    }
+    match lang.take() {
+      Some(lang) => blocks.push(ExampleBlock::Code(CodeBlock {
+        lang,
+        code: dedent(&lines),
+      })),
+      None => {
+        push_prose(&mut blocks, &lines);
+        let info = line.trim_start().trim_start_matches('`');
+        lang = Some(info.split_whitespace().next().unwrap_or("ts").to_string());
+      }
+    }
+    lines.clear();
+  }
+  match lang {
+    // an unterminated code fence runs to the end of the example
+    Some(lang) => blocks.push(ExampleBlock::Code(CodeBlock {
+      lang,
+      code: dedent(&lines),
+    })),
+    None => push_prose(&mut blocks, &lines),
+  }
+  blocks
+}
+
+fn push_prose(blocks: &mut Vec<ExampleBlock>, lines: &[&str]) {
+  let doc = lines.join("\n").trim().to_string();
+  if !doc.is_empty() {
+    blocks.push(ExampleBlock::Prose { doc });
+  }
+}
+
+/// Joins lines of code without the blank lines around them and the
+/// indentation they have in common.
+fn dedent(lines: &[&str]) -> String {
+  let is_blank = |line: &&str| line.trim().is_empty();
+  let start = lines.iter().position(|line| !is_blank(line));
+  let end = lines.iter().rposition(|line| !is_blank(line));
+  let lines = match (start, end) {
+    (Some(start), Some(end)) => &lines[start..=end],
+    _ => return String::new(),
+  };
+  let indent = lines
+    .iter()
+    .filter(|line| !is_blank(line))
+    .map(|line| line.len() - line.trim_start().len())
+    .min()
+    .unwrap_or(0);
+  lines
+    .iter()
+    .map(|line| line.get(indent..).unwrap_or("").trim_end())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+impl From<String> for JsDoc {
+  fn from(value: String) -> Self {
+    Self::parse_with_tag_lines(&value).0
+  }
+}
+
+/// A JSDoc comment as written in the source.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsDocComment {
+  /// The location of the `/**` of the comment.
+  pub location: Location,
+  /// The byte offset of the `/**` of the comment in the source.
+  pub start: usize,
+  /// The byte offset just past the `*/` of the comment in the source.
+  pub end: usize,
+  /// The text of the comment, from its `/**` to its `*/`.
+  pub text: String,
+  /// The lines of the tags parsed from the comment.
+  pub tag_lines: Vec<JsDocTagLine>,
+}
+
+/// The line of a tag parsed from a JSDoc comment.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsDocTagLine {
+  /// The index of the tag in the `tags` of the docs parsed from the comment.
+  pub tag: usize,
+  /// The line of the tag, counted from the line of the `/**`.
+  pub line: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(tag = "kind", rename_all = "lowercase")]
 pub enum JsDocTag {
+  /// `@async`
+  Async,
   /// `@callback Predicate comment`
   Callback {
     name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
+  },
+  /// `@category name`
+  Category {
+    name: String,
   },
   /// `@constructor` or `@class`
   Constructor,
+  /// `@name value`, a tag parsed by a parser registered with
+  /// `DocParser::register_tag_parser`
+  Custom {
+    name: String,
+    value: serde_json::Value,
+  },
+  /// `@default value` or `@defaultValue value`
+  Default {
+    value: String,
+  },
   /// `@deprecated comment`
   Deprecated {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@enum {type} comment`
   Enum {
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
+  /// `@example comment`, `@example title` followed by code fences, or
+  /// `@example <caption>caption</caption> comment`
   Example {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
+    /// The caption or title of the example.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    /// `doc` without the caption, split into prose and code blocks.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    blocks: Vec<ExampleBlock>,
+  },
+  /// `@experimental comment`
+  Experimental {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@extends {type} comment`
   Extends {
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
+  },
+  /// `@group name`
+  Group {
+    name: String,
+  },
+  /// `@ignore`
+  Ignore,
+  /// `@inheritDoc` or `@inheritDoc target`
+  InheritDoc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
   },
+  /// `@internal`
+  Internal,
   /// `@module`
   Module,
+  /// `@override`
+  Override,
   /// `@param {type} name comment` or `@arg {type} name comment` or
   /// `@argument {type} name comment`
   Param {
     name: String,
+    /// Whether the parameter is optional, as written `[name]`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    optional: bool,
+    /// The default value of an optional parameter, as written
+    /// `[name=default]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<String>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     type_ref: Option<String>,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@public`
   Public,
@@ -129,8 +568,18 @@ pub enum JsDocTag {
     name: String,
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@protected`
   Protected,
@@ -140,45 +589,326 @@ pub enum JsDocTag {
   Return {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     type_ref: Option<String>,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
+  },
+  /// `@see reference`
+  See {
+    doc: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
+  },
+  /// `@since version`
+  Since {
+    version: String,
+  },
+  /// `@tags tag, tag`
+  Tags {
+    tags: Vec<String>,
   },
   /// `@template T comment`
   Template {
     name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@this {type} comment`
   This {
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
+  },
+  /// `@throws {type} comment` or `@exception {type} comment`
+  Throws {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    type_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@typedef {type} name comment`
   TypeDef {
     name: String,
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   /// `@type {type} comment`
   #[serde(rename = "type")]
   TypeRef {
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<JsDocSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    markdown: Vec<MarkdownBlock>,
   },
   Unsupported {
     value: String,
   },
 }
 
+impl JsDocTag {
+  /// The segments of the documentation of the tag, if the tag has
+  /// documentation.
+  pub fn segments_mut(&mut self) -> Option<&mut Vec<JsDocSegment>> {
+    match self {
+      Self::Callback { segments, .. }
+      | Self::Deprecated { segments, .. }
+      | Self::Enum { segments, .. }
+      | Self::Example { segments, .. }
+      | Self::Experimental { segments, .. }
+      | Self::Extends { segments, .. }
+      | Self::Param { segments, .. }
+      | Self::Property { segments, .. }
+      | Self::Return { segments, .. }
+      | Self::See { segments, .. }
+      | Self::Template { segments, .. }
+      | Self::This { segments, .. }
+      | Self::Throws { segments, .. }
+      | Self::TypeDef { segments, .. }
+      | Self::TypeRef { segments, .. } => Some(segments),
+      Self::Async
+      | Self::Category { .. }
+      | Self::Constructor
+      | Self::Custom { .. }
+      | Self::Default { .. }
+      | Self::Group { .. }
+      | Self::Ignore
+      | Self::InheritDoc { .. }
+      | Self::Internal
+      | Self::Module
+      | Self::Override
+      | Self::Public
+      | Self::Private
+      | Self::Protected
+      | Self::ReadOnly
+      | Self::Since { .. }
+      | Self::Tags { .. }
+      | Self::Unsupported { .. } => None,
+    }
+  }
+
+  /// The documentation of the tag, if the tag has documentation, along with
+  /// its segments and its Markdown.
+  pub(crate) fn markdown_mut(
+    &mut self,
+  ) -> Option<(&str, &[JsDocSegment], &mut Vec<MarkdownBlock>)> {
+    match self {
+      Self::Callback {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Deprecated {
+        doc,
+        segments,
+        markdown,
+      }
+      | Self::Enum {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Example {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Experimental {
+        doc,
+        segments,
+        markdown,
+      }
+      | Self::Extends {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Param {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Property {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Return {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Template {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::This {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::Throws {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::TypeDef {
+        doc,
+        segments,
+        markdown,
+        ..
+      }
+      | Self::TypeRef {
+        doc,
+        segments,
+        markdown,
+        ..
+      } => Some((doc.as_deref()?, segments.as_slice(), markdown)),
+      Self::See {
+        doc,
+        segments,
+        markdown,
+      } => Some((doc.as_str(), segments.as_slice(), markdown)),
+      Self::Async
+      | Self::Category { .. }
+      | Self::Constructor
+      | Self::Custom { .. }
+      | Self::Default { .. }
+      | Self::Group { .. }
+      | Self::Ignore
+      | Self::InheritDoc { .. }
+      | Self::Internal
+      | Self::Module
+      | Self::Override
+      | Self::Public
+      | Self::Private
+      | Self::Protected
+      | Self::ReadOnly
+      | Self::Since { .. }
+      | Self::Tags { .. }
+      | Self::Unsupported { .. } => None,
+    }
+  }
+}
+
+/// The tags which take a `{type}` after their name.
+const TYPED_TAG_NAMES: &[&str] = &[
+  "arg",
+  "argument",
+  "augments",
+  "enum",
+  "exception",
+  "extends",
+  "param",
+  "prop",
+  "property",
+  "return",
+  "returns",
+  "this",
+  "throws",
+  "type",
+  "typedef",
+];
+
+/// Splits the `{type}` after the name of a tag from the rest of the tag,
+/// counting braces so that types such as `{{ a: number }}` are kept whole.
+fn split_tag_type(value: &str) -> (Option<String>, String) {
+  if let Some(name) = JS_DOC_TAG_RE.captures(value).map(|c| c.get(1).unwrap()) {
+    if TYPED_TAG_NAMES.contains(&name.as_str()) {
+      let rest = &value[name.end()..];
+      let type_start = rest.trim_start();
+      if type_start.starts_with('{') && type_start.len() < rest.len() {
+        let mut depth = 0;
+        for (i, c) in type_start.char_indices() {
+          match c {
+            '{' => depth += 1,
+            '}' => {
+              depth -= 1;
+              if depth == 0 {
+                if i == 1 {
+                  break;
+                }
+                let type_ref = type_start[1..i].to_string();
+                let untyped =
+                  format!("{}{}", &value[..name.end()], &type_start[i + 1..]);
+                return (Some(type_ref), untyped);
+              }
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+  (None, value.to_string())
+}
+
 impl From<String> for JsDocTag {
   fn from(value: String) -> Self {
+    // the regexes for the tags which take a type match the tag without it
+    let (type_ref, untyped) = split_tag_type(&value);
     if let Some(caps) = JS_DOC_TAG_ONLY_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       match kind {
@@ -188,67 +918,204 @@ impl From<String> for JsDocTag {
         "private" => Self::Private,
         "protected" => Self::Protected,
         "readonly" => Self::ReadOnly,
+        "ignore" => Self::Ignore,
+        "internal" => Self::Internal,
+        "override" => Self::Override,
+        "async" => Self::Async,
         _ => unreachable!("kind unexpected: {}", kind),
       }
+    } else if let Some(caps) = JS_DOC_TAG_INHERIT_DOC_RE.captures(&value) {
+      let target = caps.get(1).map(|m| m.as_str().to_string());
+      Self::InheritDoc { target }
     } else if let Some(caps) = JS_DOC_TAG_NAMED_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       let name = caps.get(2).unwrap().as_str().to_string();
       let doc = caps.get(3).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
       match kind {
-        "callback" => Self::Callback { name, doc },
-        "template" => Self::Template { name, doc },
+        "callback" => Self::Callback {
+          name,
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
+        "template" => Self::Template {
+          name,
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
         _ => unreachable!("kind unexpected: {}", kind),
       }
-    } else if let Some(caps) = JS_DOC_TAG_TYPED_RE.captures(&value) {
+    } else if let (Some(type_ref), Some(caps)) =
+      (&type_ref, JS_DOC_TAG_TYPED_RE.captures(&untyped))
+    {
       let kind = caps.get(1).unwrap().as_str();
-      let type_ref = caps.get(2).unwrap().as_str().to_string();
-      let doc = caps.get(3).map(|m| m.as_str().to_string());
+      let type_ref = type_ref.clone();
+      let doc = caps.get(2).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
       match kind {
-        "enum" => Self::Enum { type_ref, doc },
-        "extends" | "augments" => Self::Extends { type_ref, doc },
-        "this" => Self::This { type_ref, doc },
-        "type" => Self::TypeRef { type_ref, doc },
+        "enum" => Self::Enum {
+          type_ref,
+          ts_type: None,
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
+        "extends" | "augments" => Self::Extends {
+          type_ref,
+          ts_type: None,
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
+        "this" => Self::This {
+          type_ref,
+          ts_type: None,
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
+        "type" => Self::TypeRef {
+          type_ref,
+          ts_type: None,
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
         _ => unreachable!("kind unexpected: {}", kind),
       }
-    } else if let Some(caps) = JS_DOC_TAG_NAMED_TYPED_RE.captures(&value) {
+    } else if let (Some(type_ref), Some(caps)) =
+      (&type_ref, JS_DOC_TAG_NAMED_TYPED_RE.captures(&untyped))
+    {
       let kind = caps.get(1).unwrap().as_str();
-      let type_ref = caps.get(2).unwrap().as_str().to_string();
-      let name = caps.get(3).unwrap().as_str().to_string();
-      let doc = caps.get(4).map(|m| m.as_str().to_string());
+      let type_ref = type_ref.clone();
+      let name = caps.get(2).unwrap().as_str().to_string();
+      let doc = caps.get(3).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
       match kind {
         "prop" | "property" => Self::Property {
           name,
           type_ref,
+          ts_type: None,
           doc,
+          segments,
+          markdown: Vec::new(),
         },
         "typedef" => Self::TypeDef {
           name,
           type_ref,
+          ts_type: None,
           doc,
+          segments,
+          markdown: Vec::new(),
         },
         _ => unreachable!("kind unexpected: {}", kind),
       }
     } else if let Some(caps) = JS_DOC_TAG_MAYBE_DOC_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       let doc = caps.get(2).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
+      match kind {
+        "deprecated" => Self::Deprecated {
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
+        "example" => {
+          let (caption, blocks) = match &doc {
+            Some(doc) => parse_example(&value, doc),
+            None => (None, Vec::new()),
+          };
+          Self::Example {
+            doc,
+            segments,
+            markdown: Vec::new(),
+            caption,
+            blocks,
+          }
+        }
+        "experimental" => Self::Experimental {
+          doc,
+          segments,
+          markdown: Vec::new(),
+        },
+        _ => unreachable!("kind unexpected: {}", kind),
+      }
+    } else if let Some(caps) = JS_DOC_TAG_VALUE_RE.captures(&value) {
+      let kind = caps.get(1).unwrap().as_str();
+      let value = caps.get(2).unwrap().as_str().trim_end().to_string();
+      if kind == "see" {
+        return Self::See {
+          segments: parse_inline_tags(&value),
+          markdown: Vec::new(),
+          doc: value,
+        };
+      }
+      // the values of the other tags end with their first line
+      let value = value
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_end()
+        .to_string();
       match kind {
-        "deprecated" => Self::Deprecated { doc },
-        "example" => Self::Example { doc },
+        "default" | "defaultValue" => Self::Default { value },
+        "since" => Self::Since { version: value },
+        "category" => Self::Category { name: value },
+        "group" => Self::Group { name: value },
+        "tags" => Self::Tags {
+          tags: value
+            .split(',')
+            .map(|tag| tag.trim())
+            .filter(|tag| !tag.is_empty())
+            .map(String::from)
+            .collect(),
+        },
         _ => unreachable!("kind unexpected: {}", kind),
       }
-    } else if let Some(caps) = JS_DOC_TAG_PARAM_RE.captures(&value) {
-      let name = caps.get(2).unwrap().as_str().to_string();
-      let type_ref = caps.get(1).map(|m| m.as_str().to_string());
-      let doc = caps.get(3).map(|m| m.as_str().to_string());
+    } else if let Some(caps) = JS_DOC_TAG_PARAM_RE.captures(&untyped) {
+      // optional parameters are written in brackets, `[name=default]`
+      let (name, optional) = match caps.get(1) {
+        Some(name) => (name, false),
+        None => (caps.get(2).unwrap(), true),
+      };
+      let name = name.as_str().to_string();
+      let default = caps
+        .get(3)
+        .map(|m| m.as_str().to_string())
+        .filter(|default| !default.is_empty());
+      let doc = caps.get(4).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
       Self::Param {
         name,
+        optional,
+        default,
         type_ref,
+        ts_type: None,
         doc,
+        segments,
+        markdown: Vec::new(),
+      }
+    } else if let Some(caps) = JS_DOC_TAG_RETURN_RE.captures(&untyped) {
+      let doc = caps.get(1).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
+      Self::Return {
+        type_ref,
+        ts_type: None,
+        doc,
+        segments,
+        markdown: Vec::new(),
+      }
+    } else if let Some(caps) = JS_DOC_TAG_THROWS_RE.captures(&untyped) {
+      let doc = caps.get(1).map(|m| m.as_str().to_string());
+      let segments = doc_segments(doc.as_deref());
+      Self::Throws {
+        type_ref,
+        doc,
+        segments,
+        markdown: Vec::new(),
       }
-    } else if let Some(caps) = JS_DOC_TAG_RETURN_RE.captures(&value) {
-      let type_ref = caps.get(1).map(|m| m.as_str().to_string());
-      let doc = caps.get(2).map(|m| m.as_str().to_string());
-      Self::Return { type_ref, doc }
     } else {
       Self::Unsupported { value }
     }
@@ -286,6 +1153,30 @@ mod tests {
       serde_json::to_value(JsDoc::from("@readonly more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "readonly" } ] }),
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@ignore\n@internal\n@override\n@async".to_string()
+      ))
+      .unwrap(),
+      json!({ "tags": [
+        { "kind": "ignore" },
+        { "kind": "internal" },
+        { "kind": "override" },
+        { "kind": "async" },
+      ] }),
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@publicKey\n@internalApi\n@deprecatedSoon\n@returnsValue".to_string()
+      ))
+      .unwrap(),
+      json!({ "tags": [
+        { "kind": "unsupported", "value": "@publicKey" },
+        { "kind": "unsupported", "value": "@internalApi" },
+        { "kind": "unsupported", "value": "@deprecatedSoon" },
+        { "kind": "unsupported", "value": "@returnsValue" },
+      ] }),
+    );
   }
 
   #[test]
@@ -317,7 +1208,19 @@ if (true) {
         "tags": [
           {
             "kind": "example",
-            "doc": "something like this\n\nexplain\n\n```ts\nif (true) {\n  console.log(\"hello\");\n}\n```\n"
+            "doc": "something like this\n\nexplain\n\n```ts\nif (true) {\n  console.log(\"hello\");\n}\n```\n",
+            "caption": "something like this",
+            "blocks": [
+              {
+                "kind": "prose",
+                "doc": "explain"
+              },
+              {
+                "kind": "code",
+                "lang": "ts",
+                "code": "if (true) {\n  console.log(\"hello\");\n}"
+              }
+            ]
           },
           {
             "kind": "param",
@@ -333,6 +1236,109 @@ if (true) {
     );
   }
 
+  #[test]
+  fn test_js_doc_inline_tags() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        r#"See {@link Foo.bar | the bar} and {@linkcode baz}.
+{@inheritDoc}
+@param a uses {@linkplain Qux}"#
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "doc": "See {@link Foo.bar | the bar} and {@linkcode baz}.\n{@inheritDoc}",
+        "segments": [
+          { "kind": "text", "value": "See " },
+          {
+            "kind": "link",
+            "target": "Foo.bar",
+            "label": "the bar",
+            "style": "link"
+          },
+          { "kind": "text", "value": " and " },
+          { "kind": "link", "target": "baz", "style": "code" },
+          { "kind": "text", "value": ".\n" },
+          { "kind": "inheritDoc" }
+        ],
+        "tags": [
+          {
+            "kind": "param",
+            "name": "a",
+            "doc": "uses {@linkplain Qux}",
+            "segments": [
+              { "kind": "text", "value": "uses " },
+              { "kind": "link", "target": "Qux", "style": "plain" }
+            ]
+          }
+        ]
+      })
+    );
+    assert!(parse_inline_tags("no inline tags {@code here}").is_empty());
+  }
+
+  #[test]
+  fn test_js_doc_tag_example() {
+    let js_doc = JsDoc::from(
+      r#"@example <caption>Reading a file</caption>
+Open the file first.
+
+```js
+  const file = await Deno.open("a.txt");
+```
+
+Then read it.
+
+```
+  await file.read(buf);
+```"#
+        .to_string(),
+    );
+    assert_eq!(
+      serde_json::to_value(&js_doc.tags[0]).unwrap()["blocks"],
+      json!([
+        { "kind": "prose", "doc": "Open the file first." },
+        {
+          "kind": "code",
+          "lang": "js",
+          "code": "const file = await Deno.open(\"a.txt\");"
+        },
+        { "kind": "prose", "doc": "Then read it." },
+        { "kind": "code", "lang": "ts", "code": "await file.read(buf);" },
+      ])
+    );
+    assert_eq!(
+      serde_json::to_value(&js_doc.tags[0]).unwrap()["caption"],
+      json!("Reading a file")
+    );
+    assert_eq!(
+      js_doc.example_code_blocks(),
+      vec![
+        &CodeBlock {
+          lang: "js".to_string(),
+          code: "const file = await Deno.open(\"a.txt\");".to_string(),
+        },
+        &CodeBlock {
+          lang: "ts".to_string(),
+          code: "await file.read(buf);".to_string(),
+        },
+      ]
+    );
+
+    // a single line after the tag is code, not a title
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@example add(1, 2)".to_string()))
+        .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "example",
+          "doc": "add(1, 2)",
+          "blocks": [{ "kind": "code", "lang": "ts", "code": "add(1, 2)" }],
+        }]
+      })
+    );
+  }
+
   #[test]
   fn test_js_doc_tag_named() {
     assert_eq!(
@@ -520,7 +1526,24 @@ if (true) {
       json!({
         "tags": [{
           "kind": "example",
-          "doc": "const a = \"a\";"
+          "doc": "const a = \"a\";",
+          "blocks": [{
+            "kind": "code",
+            "lang": "ts",
+            "code": "const a = \"a\";"
+          }]
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@experimental may change without notice".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "experimental",
+          "doc": "may change without notice",
         }]
       })
     );
@@ -528,6 +1551,27 @@ if (true) {
 
   #[test]
   fn test_js_doc_tag_param() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@param [opts.timeout=1000] ms to wait\n@param {string} [name]"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "param",
+          "name": "opts.timeout",
+          "optional": true,
+          "default": "1000",
+          "doc": "ms to wait",
+        }, {
+          "kind": "param",
+          "name": "name",
+          "optional": true,
+          "type": "string",
+        }]
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from(
         "@param a maybe doc\n\nnew paragraph".to_string()
@@ -594,6 +1638,20 @@ if (true) {
         }]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@param {{ a: { b: number } }} opts maybe doc".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "param",
+          "name": "opts",
+          "type": "{ a: { b: number } }",
+          "doc": "maybe doc",
+        }]
+      })
+    );
   }
 
   #[test]
@@ -638,6 +1696,126 @@ if (true) {
     );
   }
 
+  #[test]
+  fn test_js_doc_tag_throws() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@throws {TypeError} maybe doc\n\nnew paragraph".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "throws",
+          "type": "TypeError",
+          "doc": "maybe doc\n\nnew paragraph",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@exception when the file is missing".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "throws",
+          "doc": "when the file is missing",
+        }]
+      })
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_value() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@default 42\n@defaultValue \"a\"\n@since 1.2.0\n@category Streams\n\
+          @group I/O\n@tags allow-read, unstable"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "default",
+          "value": "42",
+        }, {
+          "kind": "default",
+          "value": "\"a\"",
+        }, {
+          "kind": "since",
+          "version": "1.2.0",
+        }, {
+          "kind": "category",
+          "name": "Streams",
+        }, {
+          "kind": "group",
+          "name": "I/O",
+        }, {
+          "kind": "tags",
+          "tags": ["allow-read", "unstable"],
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@see {@link Reader}".to_string()))
+        .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "see",
+          "doc": "{@link Reader}",
+          "segments": [{
+            "kind": "link",
+            "target": "Reader",
+            "style": "link",
+          }],
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@default".to_string())).unwrap(),
+      json!({
+        "tags": [{
+          "kind": "unsupported",
+          "value": "@default",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@since 1.2.0\nReleased in the spring.\n@category Streams\nI/O"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "since",
+          "version": "1.2.0",
+        }, {
+          "kind": "category",
+          "name": "Streams",
+        }]
+      })
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_inherit_doc() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@inheritDoc\n@inheritdoc Base.method".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "inheritdoc",
+        }, {
+          "kind": "inheritdoc",
+          "target": "Base.method",
+        }]
+      })
+    );
+  }
+
   #[test]
   fn test_js_doc_from_str() {
     assert_eq!(
@@ -684,6 +1862,8 @@ multi-line
       serde_json::to_value(JsDocTag::Callback {
         name: "Predicate".to_string(),
         doc: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -700,6 +1880,8 @@ multi-line
     assert_eq!(
       serde_json::to_value(JsDocTag::Deprecated {
         doc: Some("comment".to_string()),
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -711,6 +1893,9 @@ multi-line
       serde_json::to_value(JsDocTag::Enum {
         type_ref: "number".to_string(),
         doc: None,
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -722,6 +1907,9 @@ multi-line
       serde_json::to_value(JsDocTag::Extends {
         type_ref: "OtherType<T>".to_string(),
         doc: None,
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -732,8 +1920,13 @@ multi-line
     assert_eq!(
       serde_json::to_value(JsDocTag::Param {
         name: "arg".to_string(),
+        optional: false,
+        default: None,
         type_ref: Some("number".to_string()),
         doc: Some("comment".to_string()),
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -746,8 +1939,13 @@ multi-line
     assert_eq!(
       serde_json::to_value(JsDocTag::Param {
         name: "arg".to_string(),
+        optional: false,
+        default: None,
         type_ref: None,
         doc: Some("comment".to_string()),
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -769,6 +1967,9 @@ multi-line
         name: "prop".to_string(),
         type_ref: "string".to_string(),
         doc: None,
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -789,6 +1990,9 @@ multi-line
       serde_json::to_value(JsDocTag::Return {
         type_ref: Some("string".to_string()),
         doc: Some("comment".to_string()),
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -801,6 +2005,8 @@ multi-line
       serde_json::to_value(JsDocTag::Template {
         name: "T".to_string(),
         doc: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -812,6 +2018,9 @@ multi-line
       serde_json::to_value(JsDocTag::This {
         type_ref: "Record<string, unknown>".to_string(),
         doc: None,
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -824,6 +2033,9 @@ multi-line
         name: "Interface".to_string(),
         type_ref: "object".to_string(),
         doc: None,
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -836,6 +2048,9 @@ multi-line
       serde_json::to_value(JsDocTag::TypeRef {
         type_ref: "Map<string, string>".to_string(),
         doc: None,
+        ts_type: None,
+        segments: vec![],
+        markdown: vec![],
       })
       .unwrap(),
       json!({
@@ -0,0 +1,714 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
+use crate::ts_type::LiteralDef;
+use crate::ts_type::LiteralDefKind;
+use crate::ts_type::LiteralPropertyDef;
+use crate::ts_type::TsFnOrConstructorDef;
+use crate::ts_type::TsTupleElementDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeLiteralDef;
+use crate::ts_type::TsTypeOperatorDef;
+use crate::visit::DocVisitorMut;
+
+use deno_ast::swc::ast::Decl;
+use deno_ast::swc::ast::ModuleItem;
+use deno_ast::swc::ast::Stmt;
+use deno_graph::DefaultSourceParser;
+use deno_graph::MediaType;
+use deno_graph::ModuleSpecifier;
+use deno_graph::SourceParser;
+
+use std::sync::Arc;
+
+const KEYWORDS: &[&str] = &[
+  "any",
+  "bigint",
+  "boolean",
+  "never",
+  "null",
+  "number",
+  "object",
+  "string",
+  "symbol",
+  "undefined",
+  "unknown",
+  "void",
+];
+
+/// Parses the type expression of a JSDoc tag, written either in the
+/// Closure Compiler syntax of JSDoc, e.g. `?Array.<string>` or
+/// `function(string, number=): boolean`, or in TypeScript syntax.
+///
+/// The Closure specific syntax maps to TypeScript types: `?T` and `T?` to
+/// `T | null`, `!T` to `T`, `*` and a bare `?` to `any` and `unknown`, `T=` to
+/// an optional type and `...T` to a rest type. The TypeScript types which the
+/// Closure syntax has no equivalent of, such as conditional, mapped, indexed
+/// access, template literal and `import("…")` types, are parsed by the
+/// TypeScript parser. Returns `None` for expressions which aren't valid types.
+pub fn parse_js_doc_type(type_ref: &str) -> Option<TsTypeDef> {
+  parse_closure_type(type_ref).or_else(|| parse_ts_type(type_ref))
+}
+
+fn parse_closure_type(type_ref: &str) -> Option<TsTypeDef> {
+  let mut parser = TypeParser {
+    tokens: tokenize(type_ref)?,
+    pos: 0,
+  };
+  let ts_type = parser.parse_type()?;
+  if parser.pos == parser.tokens.len() {
+    Some(ts_type)
+  } else {
+    None
+  }
+}
+
+/// Parses a type expression as the type of a type alias in a TypeScript
+/// module of its own.
+fn parse_ts_type(type_ref: &str) -> Option<TsTypeDef> {
+  let specifier = ModuleSpecifier::parse("file:///js_doc_type.ts").unwrap();
+  let source_code = format!("type __T = {};\n", type_ref);
+  let parsed_source = DefaultSourceParser::new()
+    .parse_module(&specifier, Arc::new(source_code), MediaType::TypeScript)
+    .ok()?;
+  // the expression must not end the type alias and declare anything else
+  match parsed_source.module().body.as_slice() {
+    [ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(type_alias)))] => {
+      Some(TsTypeDef::from(&*type_alias.type_ann))
+    }
+    _ => None,
+  }
+}
+
+/// Fills in the `ts_type` of the JSDoc tags of doc nodes, their members and
+/// their types from the type expression of the tags.
+pub fn parse_js_doc_types(doc_nodes: &mut [DocNode]) {
+  let mut parser = JsDocTypesParser;
+  for doc_node in doc_nodes {
+    parser.visit_doc_node_mut(doc_node);
+  }
+}
+
+struct JsDocTypesParser;
+
+impl DocVisitorMut for JsDocTypesParser {
+  fn visit_js_doc_mut(&mut self, js_doc: &mut JsDoc) {
+    for tag in &mut js_doc.tags {
+      match tag {
+        JsDocTag::Enum {
+          type_ref, ts_type, ..
+        }
+        | JsDocTag::Extends {
+          type_ref, ts_type, ..
+        }
+        | JsDocTag::Property {
+          type_ref, ts_type, ..
+        }
+        | JsDocTag::This {
+          type_ref, ts_type, ..
+        }
+        | JsDocTag::TypeDef {
+          type_ref, ts_type, ..
+        }
+        | JsDocTag::TypeRef {
+          type_ref, ts_type, ..
+        } => *ts_type = parse_js_doc_type(type_ref),
+        JsDocTag::Param {
+          type_ref: Some(type_ref),
+          ts_type,
+          ..
+        }
+        | JsDocTag::Return {
+          type_ref: Some(type_ref),
+          ts_type,
+          ..
+        } => *ts_type = parse_js_doc_type(type_ref),
+        _ => {}
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Ident(String),
+  Str(String),
+  Num(String),
+  Punct(&'static str),
+}
+
+const PUNCTUATORS: &[&str] = &[
+  "...", "=>", "|", "&", "?", "!", "=", "*", ".", "<", ">", "(", ")", "[", "]",
+  "{", "}", ",", ":", ";", "-",
+];
+
+fn tokenize(source: &str) -> Option<Vec<Token>> {
+  let mut tokens = Vec::new();
+  let mut rest = source;
+  loop {
+    rest = rest.trim_start();
+    let c = match rest.chars().next() {
+      Some(c) => c,
+      None => return Some(tokens),
+    };
+    if c.is_alphabetic() || c == '_' || c == '$' {
+      let end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(rest.len());
+      tokens.push(Token::Ident(rest[..end].to_string()));
+      rest = &rest[end..];
+    } else if c.is_ascii_digit() {
+      let end = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(rest.len());
+      tokens.push(Token::Num(rest[..end].to_string()));
+      rest = &rest[end..];
+    } else if c == '"' || c == '\'' {
+      let end = rest[1..].find(c)? + 1;
+      tokens.push(Token::Str(rest[1..end].to_string()));
+      rest = &rest[end + 1..];
+    } else {
+      let punct = PUNCTUATORS.iter().find(|punct| rest.starts_with(**punct))?;
+      tokens.push(Token::Punct(*punct));
+      rest = &rest[punct.len()..];
+    }
+  }
+}
+
+fn keyword_or_type_ref(
+  name: String,
+  type_params: Option<Vec<TsTypeDef>>,
+) -> TsTypeDef {
+  if type_params.is_none() && KEYWORDS.contains(&name.as_str()) {
+    TsTypeDef::keyword(&name)
+  } else {
+    TsTypeDef::type_ref(name, type_params)
+  }
+}
+
+fn literal(repr: String, kind: LiteralDefKind) -> TsTypeDef {
+  let lit = LiteralDef {
+    number: match kind {
+      LiteralDefKind::Number => repr.parse().ok(),
+      _ => None,
+    },
+    string: match kind {
+      LiteralDefKind::String => Some(repr.clone()),
+      _ => None,
+    },
+    boolean: match kind {
+      LiteralDefKind::Boolean => Some(repr == "true"),
+      _ => None,
+    },
+    ts_types: None,
+    kind,
+  };
+  TsTypeDef::with_repr(&repr, TsTypeDefKind::Literal(Box::new(lit)))
+}
+
+fn nullable(ts_type: TsTypeDef) -> TsTypeDef {
+  TsTypeDefKind::Union(vec![ts_type, TsTypeDef::keyword("null")]).into()
+}
+
+fn param_def(
+  name: String,
+  optional: bool,
+  rest: bool,
+  ts_type: Option<TsTypeDef>,
+) -> ParamDef {
+  let ident = ParamDef {
+    pattern: ParamPatternDef::Identifier { name, optional },
+    decorators: Vec::new(),
+    ts_type: if rest { None } else { ts_type.clone() },
+    doc: None,
+  };
+  if rest {
+    ParamDef {
+      pattern: ParamPatternDef::Rest {
+        arg: Box::new(ident),
+      },
+      decorators: Vec::new(),
+      ts_type,
+      doc: None,
+    }
+  } else {
+    ident
+  }
+}
+
+struct TypeParser {
+  tokens: Vec<Token>,
+  pos: usize,
+}
+
+impl TypeParser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos)
+  }
+
+  fn peek_punct(&self, punct: &str) -> bool {
+    matches!(self.peek(), Some(Token::Punct(p)) if *p == punct)
+  }
+
+  fn eat_punct(&mut self, punct: &str) -> bool {
+    let is_punct = self.peek_punct(punct);
+    if is_punct {
+      self.pos += 1;
+    }
+    is_punct
+  }
+
+  fn expect_punct(&mut self, punct: &str) -> Option<()> {
+    if self.eat_punct(punct) {
+      Some(())
+    } else {
+      None
+    }
+  }
+
+  fn ident(&mut self) -> Option<String> {
+    match self.peek() {
+      Some(Token::Ident(ident)) => {
+        let ident = ident.clone();
+        self.pos += 1;
+        Some(ident)
+      }
+      _ => None,
+    }
+  }
+
+  /// Whether the next token can start a type.
+  fn at_type_start(&self) -> bool {
+    match self.peek() {
+      Some(Token::Punct(punct)) => {
+        matches!(*punct, "(" | "[" | "{" | "*" | "?" | "!" | "..." | "-")
+      }
+      Some(_) => true,
+      None => false,
+    }
+  }
+
+  fn parse_type(&mut self) -> Option<TsTypeDef> {
+    // TypeScript allows a leading `|`
+    self.eat_punct("|");
+    let mut types = vec![self.parse_intersection()?];
+    while self.eat_punct("|") {
+      types.push(self.parse_intersection()?);
+    }
+    Some(if types.len() == 1 {
+      types.remove(0)
+    } else {
+      TsTypeDefKind::Union(types).into()
+    })
+  }
+
+  fn parse_intersection(&mut self) -> Option<TsTypeDef> {
+    let mut types = vec![self.parse_prefixed()?];
+    while self.eat_punct("&") {
+      types.push(self.parse_prefixed()?);
+    }
+    Some(if types.len() == 1 {
+      types.remove(0)
+    } else {
+      TsTypeDefKind::Intersection(types).into()
+    })
+  }
+
+  fn parse_prefixed(&mut self) -> Option<TsTypeDef> {
+    if self.eat_punct("?") {
+      // a bare `?` is the unknown type
+      return if self.at_type_start() {
+        Some(nullable(self.parse_prefixed()?))
+      } else {
+        Some(TsTypeDef::keyword("unknown"))
+      };
+    }
+    if self.eat_punct("!") {
+      return self.parse_prefixed();
+    }
+    if self.eat_punct("...") {
+      let ts_type = self.parse_prefixed()?;
+      return Some(TsTypeDefKind::Rest(Box::new(ts_type)).into());
+    }
+    if let Some(Token::Ident(ident)) = self.peek() {
+      if matches!(ident.as_str(), "keyof" | "readonly" | "unique") {
+        let operator = ident.clone();
+        self.pos += 1;
+        let ts_type = self.parse_prefixed()?;
+        return Some(
+          TsTypeDefKind::TypeOperator(Box::new(TsTypeOperatorDef {
+            operator,
+            ts_type,
+          }))
+          .into(),
+        );
+      }
+    }
+    self.parse_postfix()
+  }
+
+  fn parse_postfix(&mut self) -> Option<TsTypeDef> {
+    let mut ts_type = self.parse_primary()?;
+    loop {
+      if self.peek_punct("[")
+        && matches!(self.tokens.get(self.pos + 1), Some(Token::Punct("]")))
+      {
+        self.pos += 2;
+        ts_type = TsTypeDefKind::Array(Box::new(ts_type)).into();
+      } else if self.eat_punct("?") {
+        ts_type = nullable(ts_type);
+      } else if self.eat_punct("!") {
+        // non-nullable, which is what types are by default
+      } else if self.eat_punct("=") {
+        ts_type = TsTypeDefKind::Optional(Box::new(ts_type)).into();
+      } else {
+        return Some(ts_type);
+      }
+    }
+  }
+
+  fn parse_primary(&mut self) -> Option<TsTypeDef> {
+    let token = self.peek()?.clone();
+    self.pos += 1;
+    match token {
+      Token::Punct("*") => Some(TsTypeDef::keyword("any")),
+      Token::Punct("(") => {
+        let start = self.pos;
+        if let Some(fn_type) = self.parse_arrow_fn(false) {
+          return Some(fn_type);
+        }
+        self.pos = start;
+        let ts_type = self.parse_type()?;
+        self.expect_punct(")")?;
+        Some(TsTypeDefKind::Parenthesized(Box::new(ts_type)).into())
+      }
+      Token::Punct("{") => self.parse_record(),
+      Token::Punct("[") => self.parse_tuple(),
+      Token::Punct("-") => match self.peek()?.clone() {
+        Token::Num(num) => {
+          self.pos += 1;
+          Some(literal(format!("-{}", num), LiteralDefKind::Number))
+        }
+        _ => None,
+      },
+      Token::Str(string) => Some(literal(string, LiteralDefKind::String)),
+      Token::Num(num) => Some(literal(num, LiteralDefKind::Number)),
+      Token::Ident(ident) => match ident.as_str() {
+        "function" if self.eat_punct("(") => self.parse_closure_fn(),
+        "new" if self.eat_punct("(") => self.parse_arrow_fn(true),
+        "true" | "false" => Some(literal(ident, LiteralDefKind::Boolean)),
+        "this" => Some(TsTypeDef::with_repr("this", TsTypeDefKind::This)),
+        "typeof" => {
+          let name = self.parse_name()?;
+          Some(TsTypeDef::with_repr(
+            &name,
+            TsTypeDefKind::TypeQuery(name.clone()),
+          ))
+        }
+        _ => {
+          self.pos -= 1;
+          let name = self.parse_name()?;
+          // `Array.<T>` in the Closure syntax, `Array<T>` in TypeScript
+          if self.peek_punct(".")
+            && matches!(self.tokens.get(self.pos + 1), Some(Token::Punct("<")))
+          {
+            self.pos += 1;
+          }
+          let type_params = if self.eat_punct("<") {
+            let mut type_params = vec![self.parse_type()?];
+            while self.eat_punct(",") {
+              type_params.push(self.parse_type()?);
+            }
+            self.expect_punct(">")?;
+            Some(type_params)
+          } else {
+            None
+          };
+          Some(keyword_or_type_ref(name, type_params))
+        }
+      },
+      Token::Punct(_) => None,
+    }
+  }
+
+  /// A possibly dotted name, e.g. `ns.Foo`.
+  fn parse_name(&mut self) -> Option<String> {
+    let mut name = self.ident()?;
+    while self.peek_punct(".")
+      && matches!(self.tokens.get(self.pos + 1), Some(Token::Ident(_)))
+    {
+      self.pos += 1;
+      name.push('.');
+      name.push_str(&self.ident()?);
+    }
+    Some(name)
+  }
+
+  /// The parameters and return type of `function(string, number=): boolean`,
+  /// after the opening parenthesis. The parameters are named after their
+  /// position, or `this` for `this:T`, while `new:T` makes a constructor of
+  /// `T`.
+  fn parse_closure_fn(&mut self) -> Option<TsTypeDef> {
+    let mut params = Vec::new();
+    let mut constructed = None;
+    let mut arg_count = 0;
+    if !self.eat_punct(")") {
+      loop {
+        let context = match (self.peek(), self.tokens.get(self.pos + 1)) {
+          (Some(Token::Ident(ident)), Some(Token::Punct(":")))
+            if ident == "this" || ident == "new" =>
+          {
+            let context = ident.clone();
+            self.pos += 2;
+            Some(context)
+          }
+          _ => None,
+        };
+        let ts_type = self.parse_type()?;
+        match context.as_deref() {
+          Some("new") => constructed = Some(ts_type),
+          Some(_) => params.push(param_def(
+            "this".to_string(),
+            false,
+            false,
+            Some(ts_type),
+          )),
+          None => {
+            let name = format!("arg{}", arg_count);
+            arg_count += 1;
+            params.push(match ts_type.kind {
+              TsTypeDefKind::Rest(ts_type) => param_def(
+                name,
+                false,
+                true,
+                Some(TsTypeDefKind::Array(ts_type).into()),
+              ),
+              TsTypeDefKind::Optional(ts_type) => {
+                param_def(name, true, false, Some(*ts_type))
+              }
+              _ => param_def(name, false, false, Some(ts_type)),
+            });
+          }
+        }
+        if !self.eat_punct(",") {
+          break;
+        }
+      }
+      self.expect_punct(")")?;
+    }
+    let return_type = if self.eat_punct(":") {
+      Some(self.parse_prefixed()?)
+    } else {
+      None
+    };
+    let fn_def = TsFnOrConstructorDef {
+      constructor: constructed.is_some(),
+      ts_type: constructed
+        .or(return_type)
+        .unwrap_or_else(|| TsTypeDef::keyword("void")),
+      params,
+      type_params: Vec::new(),
+      return_doc: None,
+    };
+    Some(TsTypeDefKind::FnOrConstructor(Box::new(fn_def)).into())
+  }
+
+  /// The parameters and return type of `(a: string, b?: number) => boolean`,
+  /// after the opening parenthesis.
+  fn parse_arrow_fn(&mut self, constructor: bool) -> Option<TsTypeDef> {
+    let mut params = Vec::new();
+    if !self.eat_punct(")") {
+      loop {
+        let rest = self.eat_punct("...");
+        let name = self.ident()?;
+        let optional = self.eat_punct("?");
+        let ts_type = if self.eat_punct(":") {
+          Some(self.parse_type()?)
+        } else {
+          None
+        };
+        params.push(param_def(name, optional, rest, ts_type));
+        if !self.eat_punct(",") {
+          break;
+        }
+      }
+      self.expect_punct(")")?;
+    }
+    self.expect_punct("=>")?;
+    let fn_def = TsFnOrConstructorDef {
+      constructor,
+      ts_type: self.parse_type()?,
+      params,
+      type_params: Vec::new(),
+      return_doc: None,
+    };
+    Some(TsTypeDefKind::FnOrConstructor(Box::new(fn_def)).into())
+  }
+
+  /// The properties of `{a: number, b?: string}`, after the opening brace.
+  /// The types of properties can be omitted, as in `{a, b}`.
+  fn parse_record(&mut self) -> Option<TsTypeDef> {
+    let mut properties = Vec::new();
+    while !self.eat_punct("}") {
+      let name = match self.peek()?.clone() {
+        Token::Ident(name) | Token::Str(name) | Token::Num(name) => name,
+        Token::Punct(_) => return None,
+      };
+      self.pos += 1;
+      let optional = self.eat_punct("?");
+      let ts_type = if self.eat_punct(":") {
+        Some(self.parse_type()?)
+      } else {
+        None
+      };
+      properties.push(LiteralPropertyDef {
+        name,
+        params: Vec::new(),
+        readonly: false,
+        computed: false,
+        optional,
+        ts_type,
+        type_params: Vec::new(),
+        doc: None,
+      });
+      if !self.eat_punct(",") && !self.eat_punct(";") {
+        self.expect_punct("}")?;
+        break;
+      }
+    }
+    let type_literal = TsTypeLiteralDef {
+      methods: Vec::new(),
+      properties,
+      call_signatures: Vec::new(),
+      index_signatures: Vec::new(),
+    };
+    Some(TsTypeDefKind::TypeLiteral(Box::new(type_literal)).into())
+  }
+
+  /// The elements of `[string, number]`, after the opening bracket.
+  fn parse_tuple(&mut self) -> Option<TsTypeDef> {
+    let mut elements = Vec::new();
+    while !self.eat_punct("]") {
+      elements.push(TsTupleElementDef {
+        label: None,
+        ts_type: self.parse_type()?,
+      });
+      if !self.eat_punct(",") {
+        self.expect_punct("]")?;
+        break;
+      }
+    }
+    Some(TsTypeDefKind::Tuple(elements).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn parse(type_ref: &str) -> TsTypeDef {
+    parse_js_doc_type(type_ref).unwrap()
+  }
+
+  #[test]
+  fn test_parse_js_doc_type_closure_syntax() {
+    assert_eq!(parse("Array.<string>"), parse("Array<string>"));
+    assert_eq!(parse("?number"), parse("number | null"));
+    assert_eq!(parse("number?"), parse("number | null"));
+    assert_eq!(parse("!Object"), parse("Object"));
+    assert_eq!(parse("*"), parse("any"));
+    assert_eq!(parse("?"), parse("unknown"));
+    assert_eq!(parse("...string").kind.name(), "rest");
+    assert_eq!(parse("string=").kind.name(), "optional");
+    assert_eq!(
+      parse("function(string, number=): boolean"),
+      parse("(arg0: string, arg1?: number) => boolean")
+    );
+    assert_eq!(
+      parse("function(this:Window, ...number)"),
+      parse("(this: Window, ...arg0: number[]) => void")
+    );
+    assert_eq!(
+      parse("function(new:Date, string)"),
+      parse("new (arg0: string) => Date")
+    );
+    assert_eq!(parse("Array.<?string>"), parse("Array<string | null>"));
+  }
+
+  #[test]
+  fn test_parse_js_doc_type_json() {
+    assert_eq!(
+      serde_json::to_value(parse("Object.<string, number>")).unwrap(),
+      json!({
+        "repr": "Object",
+        "kind": "typeRef",
+        "typeRef": {
+          "typeParams": [
+            { "repr": "string", "kind": "keyword", "keyword": "string" },
+            { "repr": "number", "kind": "keyword", "keyword": "number" },
+          ],
+          "typeName": "Object",
+        },
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(parse("{a: number, b}")).unwrap(),
+      json!({
+        "repr": "",
+        "kind": "typeLiteral",
+        "typeLiteral": {
+          "methods": [],
+          "properties": [
+            {
+              "name": "a",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": { "repr": "number", "kind": "keyword", "keyword": "number" },
+              "typeParams": [],
+            },
+            {
+              "name": "b",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": null,
+              "typeParams": [],
+            },
+          ],
+          "callSignatures": [],
+          "indexSignatures": [],
+        },
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_js_doc_type_typescript_syntax() {
+    assert_eq!(
+      parse("T extends string ? T : never").kind.name(),
+      "conditional"
+    );
+    assert_eq!(
+      parse("{ readonly [K in keyof T]: T[K] }").kind.name(),
+      "mapped"
+    );
+    assert_eq!(parse("Options[\"mode\"]").kind.name(), "indexedAccess");
+    assert_eq!(parse("`id-${number}`").kind.name(), "literal");
+    assert_eq!(parse("import(\"./mod.ts\").Foo").kind.name(), "importType");
+    assert_eq!(parse("{a(): void}").kind.name(), "typeLiteral");
+  }
+
+  #[test]
+  fn test_parse_js_doc_type_unsupported() {
+    assert_eq!(parse_js_doc_type("Array.<string"), None);
+    assert_eq!(parse_js_doc_type("string number"), None);
+    assert_eq!(parse_js_doc_type("string; export const a = 1"), None);
+  }
+}
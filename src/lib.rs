@@ -10,34 +10,73 @@ extern crate lazy_static;
 #[macro_use]
 extern crate serde_json;
 
+mod accessor;
+mod category;
 mod class;
 mod colors;
+mod custom_tag;
 mod decorators;
+mod deprecation;
 mod display;
+mod doc_test;
 mod r#enum;
 mod function;
+mod inheritance;
 mod interface;
 mod js_doc;
+mod js_doc_type;
+mod markdown;
 mod module;
 mod namespace;
 mod node;
+mod param_docs;
 mod params;
 mod parser;
 mod swc_util;
+mod tagged_json;
 mod ts_type;
 mod ts_type_param;
 mod type_alias;
+mod type_ref_resolver;
 mod variable;
+mod visibility;
+pub mod visit;
 
+pub use category::group_by_category;
+pub use category::Category;
+pub use category::GroupBy;
+pub use custom_tag::CustomTag;
+pub use custom_tag::TagParser;
+pub use custom_tag::TagShape;
+pub use deprecation::deprecations;
+pub use deprecation::resolve_deprecations;
+pub use deprecation::DeprecatedSymbol;
+pub use deprecation::Deprecation;
+pub use doc_test::example_modules;
+pub use doc_test::ExampleModule;
+pub use js_doc::CodeBlock;
+pub use js_doc::ExampleBlock;
+pub use js_doc_type::parse_js_doc_type;
+pub use js_doc_type::parse_js_doc_types;
+pub use markdown::parse_js_doc_markdown;
+pub use markdown::parse_markdown;
+pub use markdown::MarkdownBlock;
+pub use markdown::MarkdownInline;
 pub use node::DocNode;
 use node::ImportDef;
 use node::Location;
 use node::ReexportKind;
+pub use param_docs::merge_param_docs;
+pub use param_docs::UnknownParam;
 use params::ParamDef;
+pub use tagged_json::TaggedJson;
+pub use visit::DocVisitor;
+pub use visit::DocVisitorMut;
 
 cfg_if! {
   if #[cfg(feature = "rust")] {
     mod printer;
+    pub use doc_test::write_example_modules;
     pub use node::DocNodeKind;
     pub use parser::DocError;
     pub use parser::DocParser;
@@ -99,33 +138,57 @@ fn find_nodes_by_name(doc_nodes: Vec<DocNode>, name: String) -> Vec<DocNode> {
 
 #[cfg(feature = "rust")]
 fn get_children_of_node(node: DocNode) -> Vec<DocNode> {
-  match node.kind {
-    DocNodeKind::Namespace => {
-      let namespace_def = node.namespace_def.unwrap();
-      namespace_def.elements
+  use class::ClassAncestorDef;
+  use class::ClassMethodDef;
+  use class::ClassPropertyDef;
+  use interface::InterfaceAncestorDef;
+  use interface::InterfaceMethodDef;
+  use interface::InterfacePropertyDef;
+  use namespace::NamespaceDef;
+  use ts_type::TsTypeDef;
+
+  /// Collects the elements of a namespace, and the methods and properties of
+  /// a class or interface, as doc nodes.
+  #[derive(Default)]
+  struct ChildCollector(Vec<DocNode>);
+
+  impl DocVisitor for ChildCollector {
+    fn visit_namespace_def(&mut self, namespace_def: &NamespaceDef) {
+      self.0.extend(namespace_def.elements.iter().cloned());
     }
-    DocNodeKind::Interface => {
-      let interface_def = node.interface_def.unwrap();
-      let mut doc_nodes: Vec<DocNode> = vec![];
-      for method in interface_def.methods {
-        doc_nodes.push(method.into());
-      }
-      for property in interface_def.properties {
-        doc_nodes.push(property.into());
-      }
-      doc_nodes
+
+    fn visit_class_property_def(&mut self, property_def: &ClassPropertyDef) {
+      self.0.push(property_def.clone().into());
     }
-    DocNodeKind::Class => {
-      let class_def = node.class_def.unwrap();
-      let mut doc_nodes: Vec<DocNode> = vec![];
-      for method in class_def.methods {
-        doc_nodes.push(method.into());
-      }
-      for property in class_def.properties {
-        doc_nodes.push(property.into());
-      }
-      doc_nodes
+
+    fn visit_class_method_def(&mut self, method_def: &ClassMethodDef) {
+      self.0.push(method_def.clone().into());
     }
-    _ => vec![],
+
+    fn visit_interface_property_def(
+      &mut self,
+      property_def: &InterfacePropertyDef,
+    ) {
+      self.0.push(property_def.clone().into());
+    }
+
+    fn visit_interface_method_def(&mut self, method_def: &InterfaceMethodDef) {
+      self.0.push(method_def.clone().into());
+    }
+
+    // only the own members are children
+    fn visit_class_ancestor_def(&mut self, _ancestor_def: &ClassAncestorDef) {}
+
+    fn visit_interface_ancestor_def(
+      &mut self,
+      _ancestor_def: &InterfaceAncestorDef,
+    ) {
+    }
+
+    fn visit_ts_type_def(&mut self, _ts_type_def: &TsTypeDef) {}
   }
+
+  let mut collector = ChildCollector::default();
+  collector.visit_doc_node(&node);
+  collector.0
 }
@@ -0,0 +1,1327 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::leading_inline_tag;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocSegment;
+use crate::js_doc::LinkStyle;
+use crate::node::DocNode;
+use crate::ts_type::TypeRefResolution;
+use crate::visit::DocVisitorMut;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A block of documentation parsed as Markdown.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum MarkdownBlock {
+  Paragraph {
+    children: Vec<MarkdownInline>,
+  },
+  /// `# heading` to `###### heading`, or a heading underlined with `=`s or
+  /// `-`s
+  Heading {
+    level: u8,
+    children: Vec<MarkdownInline>,
+  },
+  /// A bullet list, or an ordered list if it has a `start`
+  List {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+    items: Vec<Vec<MarkdownBlock>>,
+  },
+  /// A fenced or indented code block
+  CodeBlock {
+    /// The first word of the info string of a fenced code block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lang: Option<String>,
+    code: String,
+  },
+  /// `> quote`
+  BlockQuote {
+    children: Vec<MarkdownBlock>,
+  },
+  /// `---`, `***` or `___`
+  ThematicBreak,
+}
+
+/// An inline part of a paragraph or heading parsed as Markdown.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum MarkdownInline {
+  /// Text, in which line breaks within the paragraph are kept as `\n`s
+  Text { value: String },
+  /// `` `code` ``
+  Code { value: String },
+  /// `*emphasis*` or `_emphasis_`
+  Emphasis { children: Vec<MarkdownInline> },
+  /// `**strong**` or `__strong__`
+  Strong { children: Vec<MarkdownInline> },
+  /// `[text](url)` or `<url>`
+  Link {
+    url: String,
+    children: Vec<MarkdownInline>,
+  },
+  /// `{@link target}`, `{@link target | label}` or `{@link target label}`,
+  /// and the same for `{@linkcode}` and `{@linkplain}`
+  JsDocLink {
+    target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    style: LinkStyle,
+    /// The symbol the target refers to, if resolved by
+    /// `DocParser::resolve_type_refs` before the docs were parsed as
+    /// Markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    resolution: Option<TypeRefResolution>,
+  },
+  /// A hard line break, written as two spaces or a `\` at the end of a line
+  LineBreak,
+}
+
+/// Parses documentation as Markdown, following CommonMark for the blocks and
+/// inlines of `MarkdownBlock` and `MarkdownInline`. Raw HTML, reference
+/// links and images are kept as text.
+pub fn parse_markdown(doc: &str) -> Vec<MarkdownBlock> {
+  let lines = doc.lines().map(expand_tabs).collect::<Vec<_>>();
+  parse_blocks(&lines)
+}
+
+/// Fills in the `markdown` of the docs of doc nodes, their members and their
+/// types, and of the tags of the docs, from their documentation.
+///
+/// Run it after `DocParser::resolve_type_refs`, if at all, so that the
+/// resolutions of the inline link tags are carried over.
+pub fn parse_js_doc_markdown(doc_nodes: &mut [DocNode]) {
+  let mut parser = JsDocMarkdownParser;
+  for doc_node in doc_nodes {
+    parser.visit_doc_node_mut(doc_node);
+  }
+}
+
+struct JsDocMarkdownParser;
+
+impl DocVisitorMut for JsDocMarkdownParser {
+  fn visit_js_doc_mut(&mut self, js_doc: &mut JsDoc) {
+    if let Some(doc) = &js_doc.doc {
+      js_doc.markdown = parse_doc_markdown(doc, &js_doc.segments);
+    }
+    for tag in &mut js_doc.tags {
+      if let Some((doc, segments, markdown)) = tag.markdown_mut() {
+        *markdown = parse_doc_markdown(doc, segments);
+      }
+    }
+  }
+}
+
+/// Parses documentation as Markdown, taking the resolutions of its inline link
+/// tags from its segments.
+pub(crate) fn parse_doc_markdown(
+  doc: &str,
+  segments: &[JsDocSegment],
+) -> Vec<MarkdownBlock> {
+  let mut blocks = parse_markdown(doc);
+  if segments.is_empty() {
+    return blocks;
+  }
+  for block in &mut blocks {
+    resolve_block_links(block, segments);
+  }
+  blocks
+}
+
+fn resolve_block_links(block: &mut MarkdownBlock, segments: &[JsDocSegment]) {
+  match block {
+    MarkdownBlock::Paragraph { children }
+    | MarkdownBlock::Heading { children, .. } => {
+      resolve_inline_links(children, segments)
+    }
+    MarkdownBlock::List { items, .. } => {
+      for block in items.iter_mut().flatten() {
+        resolve_block_links(block, segments);
+      }
+    }
+    MarkdownBlock::BlockQuote { children } => {
+      for block in children {
+        resolve_block_links(block, segments);
+      }
+    }
+    MarkdownBlock::CodeBlock { .. } | MarkdownBlock::ThematicBreak => {}
+  }
+}
+
+fn resolve_inline_links(
+  inlines: &mut [MarkdownInline],
+  segments: &[JsDocSegment],
+) {
+  for inline in inlines {
+    match inline {
+      MarkdownInline::Emphasis { children }
+      | MarkdownInline::Strong { children }
+      | MarkdownInline::Link { children, .. } => {
+        resolve_inline_links(children, segments)
+      }
+      MarkdownInline::JsDocLink {
+        target, resolution, ..
+      } => {
+        *resolution = segments.iter().find_map(|segment| match segment {
+          JsDocSegment::Link {
+            target: segment_target,
+            resolution,
+            ..
+          } if segment_target == target => resolution.clone(),
+          _ => None,
+        });
+      }
+      MarkdownInline::Text { .. }
+      | MarkdownInline::Code { .. }
+      | MarkdownInline::LineBreak => {}
+    }
+  }
+}
+
+/// Replaces the tabs of a line with spaces, up to the next tab stop of 4
+/// columns.
+fn expand_tabs(line: &str) -> String {
+  let mut expanded = String::with_capacity(line.len());
+  let mut column = 0;
+  for c in line.chars() {
+    if c == '\t' {
+      let spaces = 4 - column % 4;
+      expanded.extend(std::iter::repeat(' ').take(spaces));
+      column += spaces;
+    } else {
+      expanded.push(c);
+      column += 1;
+    }
+  }
+  expanded
+}
+
+fn indent_of(line: &str) -> usize {
+  line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_blank(line: &str) -> bool {
+  line.trim().is_empty()
+}
+
+/// `line` without up to `indent` leading spaces.
+fn strip_indent(line: &str, indent: usize) -> &str {
+  &line[indent_of(line).min(indent)..]
+}
+
+/// The character, length and info string of the code fence opening a fenced
+/// code block.
+fn code_fence(line: &str) -> Option<(char, usize, &str)> {
+  if indent_of(line) >= 4 {
+    return None;
+  }
+  let rest = line.trim_start();
+  let ch = rest.chars().next()?;
+  if ch != '`' && ch != '~' {
+    return None;
+  }
+  let len = rest.len() - rest.trim_start_matches(ch).len();
+  let info = rest[len..].trim();
+  if len < 3 || (ch == '`' && info.contains('`')) {
+    return None;
+  }
+  Some((ch, len, info))
+}
+
+fn is_closing_fence(line: &str, ch: char, len: usize) -> bool {
+  let rest = line.trim();
+  indent_of(line) < 4 && rest.len() >= len && rest.chars().all(|c| c == ch)
+}
+
+/// The level and text of a `#` heading.
+fn atx_heading(line: &str) -> Option<(u8, &str)> {
+  if indent_of(line) >= 4 {
+    return None;
+  }
+  let rest = line.trim_start();
+  let level = rest.len() - rest.trim_start_matches('#').len();
+  if level == 0 || level > 6 {
+    return None;
+  }
+  let text = &rest[level..];
+  if !text.is_empty() && !text.starts_with(' ') {
+    return None;
+  }
+  let text = text.trim();
+  // the optional closing `#`s
+  let without_closing = text.trim_end_matches('#');
+  let text = if without_closing.is_empty() {
+    without_closing
+  } else if without_closing.ends_with(' ') {
+    without_closing.trim_end()
+  } else {
+    text
+  };
+  Some((level as u8, text))
+}
+
+/// The level of the heading underlined by `line`, if it is made of `=`s or
+/// `-`s.
+fn setext_underline(line: &str) -> Option<u8> {
+  let rest = line.trim();
+  if indent_of(line) >= 4 || rest.is_empty() {
+    return None;
+  }
+  if rest.chars().all(|c| c == '=') {
+    Some(1)
+  } else if rest.chars().all(|c| c == '-') {
+    Some(2)
+  } else {
+    None
+  }
+}
+
+fn is_thematic_break(line: &str) -> bool {
+  let chars = line.chars().filter(|c| *c != ' ').collect::<Vec<_>>();
+  indent_of(line) < 4
+    && chars.len() >= 3
+    && matches!(chars[0], '*' | '-' | '_')
+    && chars.iter().all(|c| *c == chars[0])
+}
+
+fn block_quote(line: &str) -> Option<&str> {
+  if indent_of(line) >= 4 {
+    return None;
+  }
+  let rest = line.trim_start().strip_prefix('>')?;
+  Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+/// The marker of a list item.
+struct ListMarker {
+  /// The number of the item, if it is an item of an ordered list.
+  number: Option<u32>,
+  /// The bullet, or the `.` or `)` following the number.
+  ch: char,
+  /// The column the content of the item starts at.
+  content_offset: usize,
+  /// Whether there is no content on the line of the marker.
+  is_empty: bool,
+}
+
+impl ListMarker {
+  fn continues(&self, other: &ListMarker) -> bool {
+    self.ch == other.ch && self.number.is_some() == other.number.is_some()
+  }
+}
+
+fn list_marker(line: &str) -> Option<ListMarker> {
+  let indent = indent_of(line);
+  if indent >= 4 || is_thematic_break(line) {
+    return None;
+  }
+  let rest = &line[indent..];
+  let digits =
+    rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+  let (number, ch, marker_len) = if digits > 0 {
+    let ch = rest[digits..].chars().next()?;
+    if digits > 9 || (ch != '.' && ch != ')') {
+      return None;
+    }
+    (Some(rest[..digits].parse().ok()?), ch, digits + 1)
+  } else {
+    let ch = rest.chars().next()?;
+    if !matches!(ch, '-' | '+' | '*') {
+      return None;
+    }
+    (None, ch, 1)
+  };
+  let content = &rest[marker_len..];
+  if is_blank(content) {
+    return Some(ListMarker {
+      number,
+      ch,
+      content_offset: indent + marker_len + 1,
+      is_empty: true,
+    });
+  }
+  let spaces = indent_of(content);
+  if spaces == 0 {
+    return None;
+  }
+  // content indented further than that is an indented code block
+  let spaces = if spaces > 4 { 1 } else { spaces };
+  Some(ListMarker {
+    number,
+    ch,
+    content_offset: indent + marker_len + spaces,
+    is_empty: false,
+  })
+}
+
+/// Whether `line` starts a block which ends a paragraph before it.
+fn interrupts_paragraph(line: &str) -> bool {
+  code_fence(line).is_some()
+    || atx_heading(line).is_some()
+    || is_thematic_break(line)
+    || block_quote(line).is_some()
+    || list_marker(line).map_or(false, |marker| {
+      !marker.is_empty && matches!(marker.number, None | Some(1))
+    })
+}
+
+fn parse_blocks(lines: &[String]) -> Vec<MarkdownBlock> {
+  let mut blocks = Vec::new();
+  let mut i = 0;
+  while i < lines.len() {
+    let line = &lines[i];
+    if is_blank(line) {
+      i += 1;
+    } else if indent_of(line) >= 4 {
+      let mut code_lines = Vec::new();
+      while i < lines.len()
+        && (is_blank(&lines[i]) || indent_of(&lines[i]) >= 4)
+      {
+        code_lines.push(if is_blank(&lines[i]) {
+          ""
+        } else {
+          &lines[i][4..]
+        });
+        i += 1;
+      }
+      while code_lines.last().map_or(false, |line| line.is_empty()) {
+        code_lines.pop();
+      }
+      blocks.push(MarkdownBlock::CodeBlock {
+        lang: None,
+        code: code_lines.join("\n"),
+      });
+    } else if let Some((ch, len, info)) = code_fence(line) {
+      let indent = indent_of(line);
+      let mut code_lines = Vec::new();
+      i += 1;
+      while i < lines.len() {
+        let line = &lines[i];
+        i += 1;
+        if is_closing_fence(line, ch, len) {
+          break;
+        }
+        code_lines.push(strip_indent(line, indent));
+      }
+      blocks.push(MarkdownBlock::CodeBlock {
+        lang: info.split_whitespace().next().map(String::from),
+        code: code_lines.join("\n"),
+      });
+    } else if let Some((level, text)) = atx_heading(line) {
+      blocks.push(MarkdownBlock::Heading {
+        level,
+        children: parse_inlines(text),
+      });
+      i += 1;
+    } else if is_thematic_break(line) {
+      blocks.push(MarkdownBlock::ThematicBreak);
+      i += 1;
+    } else if block_quote(line).is_some() {
+      let mut quote_lines: Vec<String> = Vec::new();
+      while i < lines.len() {
+        let line = &lines[i];
+        if let Some(rest) = block_quote(line) {
+          quote_lines.push(rest.to_string());
+        } else if is_blank(line)
+          || quote_lines.last().map_or(true, |line| is_blank(line))
+          || interrupts_paragraph(line)
+        {
+          break;
+        } else {
+          // a lazy continuation line of a paragraph in the quote
+          quote_lines.push(line.to_string());
+        }
+        i += 1;
+      }
+      blocks.push(MarkdownBlock::BlockQuote {
+        children: parse_blocks(&quote_lines),
+      });
+    } else if let Some(marker) = list_marker(line) {
+      let (list, end) = parse_list(lines, i, marker);
+      blocks.push(list);
+      i = end;
+    } else {
+      let mut paragraph_lines = vec![line.trim_start()];
+      let mut heading_level = None;
+      i += 1;
+      while i < lines.len() {
+        let line = &lines[i];
+        if is_blank(line) {
+          break;
+        }
+        if let Some(level) = setext_underline(line) {
+          heading_level = Some(level);
+          i += 1;
+          break;
+        }
+        if interrupts_paragraph(line) {
+          break;
+        }
+        paragraph_lines.push(line.trim_start());
+        i += 1;
+      }
+      let text = paragraph_lines.join("\n");
+      let children = parse_inlines(text.trim_end());
+      blocks.push(match heading_level {
+        Some(level) => MarkdownBlock::Heading { level, children },
+        None => MarkdownBlock::Paragraph { children },
+      });
+    }
+  }
+  blocks
+}
+
+/// Parses the list starting with the item at line `start`, and returns it
+/// with the line following it.
+fn parse_list(
+  lines: &[String],
+  start: usize,
+  marker: ListMarker,
+) -> (MarkdownBlock, usize) {
+  let list_start = marker.number;
+  let mut marker = marker;
+  let mut items = Vec::new();
+  let mut i = start;
+  loop {
+    let mut item_lines = vec![lines[i]
+      .get(marker.content_offset..)
+      .unwrap_or("")
+      .to_string()];
+    i += 1;
+    while i < lines.len() {
+      let line = &lines[i];
+      if is_blank(line) {
+        item_lines.push(String::new());
+      } else if indent_of(line) >= marker.content_offset {
+        item_lines.push(line[marker.content_offset..].to_string());
+      } else if list_marker(line).is_some()
+        || interrupts_paragraph(line)
+        || item_lines.last().map_or(true, |line| is_blank(line))
+      {
+        break;
+      } else {
+        // a lazy continuation line of a paragraph in the item
+        item_lines.push(line.trim_start().to_string());
+      }
+      i += 1;
+    }
+    while item_lines.last().map_or(false, |line| line.is_empty()) {
+      item_lines.pop();
+    }
+    items.push(parse_blocks(&item_lines));
+    match lines.get(i).and_then(|line| list_marker(line)) {
+      Some(next) if next.continues(&marker) => marker = next,
+      _ => break,
+    }
+  }
+  let list = MarkdownBlock::List {
+    start: list_start,
+    items,
+  };
+  (list, i)
+}
+
+/// A part of a paragraph or heading, while its emphasis is being parsed.
+enum Piece {
+  Inline(MarkdownInline),
+  /// A run of `*`s or `_`s, which may open or close emphasis.
+  Delimiter {
+    ch: char,
+    /// The number of delimiters of the run which are left unmatched.
+    count: usize,
+    /// The number of delimiters the run had.
+    len: usize,
+    can_open: bool,
+    can_close: bool,
+  },
+}
+
+fn parse_inlines(text: &str) -> Vec<MarkdownInline> {
+  let mut pieces = Vec::new();
+  let mut buf = String::new();
+  let mut pos = 0;
+  while pos < text.len() {
+    let rest = &text[pos..];
+    let ch = rest.chars().next().unwrap();
+    match ch {
+      '\\' => match rest[1..].chars().next() {
+        Some('\n') => {
+          push_piece(&mut pieces, &mut buf, MarkdownInline::LineBreak);
+          pos += 2;
+          continue;
+        }
+        Some(escaped) if escaped.is_ascii_punctuation() => {
+          buf.push(escaped);
+          pos += 2;
+          continue;
+        }
+        _ => {}
+      },
+      '\n' => {
+        let trailing_spaces = buf.len() - buf.trim_end_matches(' ').len();
+        buf.truncate(buf.len() - trailing_spaces);
+        if trailing_spaces >= 2 {
+          push_piece(&mut pieces, &mut buf, MarkdownInline::LineBreak);
+        } else {
+          buf.push('\n');
+        }
+        pos += 1;
+        continue;
+      }
+      '`' => {
+        if let Some((value, len)) = code_span(rest) {
+          push_piece(&mut pieces, &mut buf, MarkdownInline::Code { value });
+          pos += len;
+        } else {
+          // a run of backticks without a matching run is text
+          let len = rest.len() - rest.trim_start_matches('`').len();
+          buf.push_str(&rest[..len]);
+          pos += len;
+        }
+        continue;
+      }
+      '{' => {
+        if let Some((segment, len)) = leading_inline_tag(rest) {
+          match segment {
+            JsDocSegment::Link {
+              target,
+              label,
+              style,
+              resolution,
+            } => push_piece(
+              &mut pieces,
+              &mut buf,
+              MarkdownInline::JsDocLink {
+                target,
+                label,
+                style,
+                resolution,
+              },
+            ),
+            JsDocSegment::Text { value } => buf.push_str(&value),
+            JsDocSegment::InheritDoc { .. } => {}
+          }
+          pos += len;
+          continue;
+        }
+      }
+      '[' => {
+        if let Some((link_text, url, len)) = link(rest) {
+          let link = MarkdownInline::Link {
+            url,
+            children: parse_inlines(link_text),
+          };
+          push_piece(&mut pieces, &mut buf, link);
+          pos += len;
+          continue;
+        }
+      }
+      '<' => {
+        if let Some((url, len)) = autolink(rest) {
+          let link = MarkdownInline::Link {
+            url: url.to_string(),
+            children: vec![MarkdownInline::Text {
+              value: url.to_string(),
+            }],
+          };
+          push_piece(&mut pieces, &mut buf, link);
+          pos += len;
+          continue;
+        }
+      }
+      '*' | '_' => {
+        let len = rest.len() - rest.trim_start_matches(ch).len();
+        let before = text[..pos].chars().next_back();
+        let after = rest[len..].chars().next();
+        let (can_open, can_close) = flanking(ch, before, after);
+        if !buf.is_empty() {
+          pieces.push(Piece::Inline(MarkdownInline::Text {
+            value: std::mem::take(&mut buf),
+          }));
+        }
+        pieces.push(Piece::Delimiter {
+          ch,
+          count: len,
+          len,
+          can_open,
+          can_close,
+        });
+        pos += len;
+        continue;
+      }
+      _ => {}
+    }
+    buf.push(ch);
+    pos += ch.len_utf8();
+  }
+  if !buf.is_empty() {
+    pieces.push(Piece::Inline(MarkdownInline::Text { value: buf }));
+  }
+  parse_emphasis(pieces)
+}
+
+/// Pushes the text gathered so far, followed by `inline`.
+fn push_piece(
+  pieces: &mut Vec<Piece>,
+  buf: &mut String,
+  inline: MarkdownInline,
+) {
+  if !buf.is_empty() {
+    pieces.push(Piece::Inline(MarkdownInline::Text {
+      value: std::mem::take(buf),
+    }));
+  }
+  pieces.push(Piece::Inline(inline));
+}
+
+/// The content and length of the code span at the start of `text`.
+fn code_span(text: &str) -> Option<(String, usize)> {
+  let len = text.len() - text.trim_start_matches('`').len();
+  let mut search = len;
+  while let Some(found) = text[search..].find('`') {
+    let start = search + found;
+    let closing_len =
+      text[start..].len() - text[start..].trim_start_matches('`').len();
+    if closing_len == len {
+      let value = text[len..start].replace('\n', " ");
+      // a space on both sides of the code is stripped
+      let value = if value.len() >= 2
+        && value.starts_with(' ')
+        && value.ends_with(' ')
+        && !is_blank(&value)
+      {
+        value[1..value.len() - 1].to_string()
+      } else {
+        value
+      };
+      return Some((value, start + closing_len));
+    }
+    search = start + closing_len;
+  }
+  None
+}
+
+/// The text, destination and length of the `[text](url "title")` link at the
+/// start of `text`. The brackets of code spans and escaped brackets don't end
+/// the text, and the destination can contain balanced parentheses, or be
+/// enclosed in `<>`.
+fn link(text: &str) -> Option<(&str, String, usize)> {
+  let close = link_text_end(text)?;
+  let rest = text[close + 1..].strip_prefix('(')?;
+  let mut pos = rest.len() - rest.trim_start().len();
+  let (url, len) = link_destination(&rest[pos..])?;
+  pos += len;
+  let spaces = rest[pos..].len() - rest[pos..].trim_start().len();
+  pos += spaces;
+  // the title of the link, if any, is left out
+  if spaces > 0 {
+    if let Some(len) = link_title(&rest[pos..]) {
+      pos += len;
+      pos += rest[pos..].len() - rest[pos..].trim_start().len();
+    }
+  }
+  if !rest[pos..].starts_with(')') {
+    return None;
+  }
+  Some((&text[1..close], url, close + 2 + pos + 1))
+}
+
+/// The offset of the `]` closing the text of a link at the start of `text`.
+fn link_text_end(text: &str) -> Option<usize> {
+  let mut depth = 0;
+  let mut pos = 0;
+  while let Some(c) = text[pos..].chars().next() {
+    match c {
+      '\\' => {
+        pos += 1 + text[pos + 1..].chars().next().map_or(0, char::len_utf8);
+        continue;
+      }
+      '`' => {
+        let rest = &text[pos..];
+        pos += code_span(rest).map_or_else(
+          || rest.len() - rest.trim_start_matches('`').len(),
+          |(_, len)| len,
+        );
+        continue;
+      }
+      '[' => depth += 1,
+      ']' => {
+        depth -= 1;
+        if depth == 0 {
+          return Some(pos);
+        }
+      }
+      _ => {}
+    }
+    pos += c.len_utf8();
+  }
+  None
+}
+
+/// The destination of a link at the start of `text`, with its backslash
+/// escapes resolved, and its length.
+fn link_destination(text: &str) -> Option<(String, usize)> {
+  let mut url = String::new();
+  if text.starts_with('<') {
+    let mut pos = 1;
+    while let Some(c) = text[pos..].chars().next() {
+      match c {
+        '>' => return Some((url, pos + 1)),
+        '<' | '\n' => return None,
+        '\\' => {
+          if let Some(escaped) = escaped_char(&text[pos + 1..]) {
+            url.push(escaped);
+            pos += 1 + escaped.len_utf8();
+            continue;
+          }
+        }
+        _ => {}
+      }
+      url.push(c);
+      pos += c.len_utf8();
+    }
+    return None;
+  }
+  let mut depth = 0;
+  let mut pos = 0;
+  while let Some(c) = text[pos..].chars().next() {
+    match c {
+      '\\' => {
+        if let Some(escaped) = escaped_char(&text[pos + 1..]) {
+          url.push(escaped);
+          pos += 1 + escaped.len_utf8();
+          continue;
+        }
+      }
+      '(' => depth += 1,
+      ')' if depth == 0 => break,
+      ')' => depth -= 1,
+      c if c.is_whitespace() || c.is_control() => break,
+      _ => {}
+    }
+    url.push(c);
+    pos += c.len_utf8();
+  }
+  if depth > 0 {
+    return None;
+  }
+  Some((url, pos))
+}
+
+/// The length of the `"title"`, `'title'` or `(title)` of a link at the start
+/// of `text`.
+fn link_title(text: &str) -> Option<usize> {
+  let close = match text.chars().next()? {
+    '"' => '"',
+    '\'' => '\'',
+    '(' => ')',
+    _ => return None,
+  };
+  let mut pos = 1;
+  while let Some(c) = text[pos..].chars().next() {
+    if c == '\\' {
+      pos += 1 + text[pos + 1..].chars().next().map_or(0, char::len_utf8);
+      continue;
+    }
+    if c == close {
+      return Some(pos + 1);
+    }
+    pos += c.len_utf8();
+  }
+  None
+}
+
+/// The ASCII punctuation character escaped by a backslash before `text`.
+fn escaped_char(text: &str) -> Option<char> {
+  text.chars().next().filter(|c| c.is_ascii_punctuation())
+}
+
+/// The URL and length of the `<url>` autolink at the start of `text`.
+fn autolink(text: &str) -> Option<(&str, usize)> {
+  let end = text.find('>')?;
+  let url = &text[1..end];
+  let (scheme, _) = url.split_once(':')?;
+  let is_scheme = scheme.len() >= 2
+    && scheme
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'));
+  if !is_scheme || url.contains(|c: char| c.is_whitespace() || c == '<') {
+    return None;
+  }
+  Some((url, end + 1))
+}
+
+/// Whether a run of `ch`s between `before` and `after` can open and close
+/// emphasis.
+fn flanking(
+  ch: char,
+  before: Option<char>,
+  after: Option<char>,
+) -> (bool, bool) {
+  let is_whitespace = |c: Option<char>| c.map_or(true, char::is_whitespace);
+  let is_punctuation =
+    |c: Option<char>| c.map_or(false, |c| c.is_ascii_punctuation());
+  let left_flanking = !is_whitespace(after)
+    && (!is_punctuation(after)
+      || is_whitespace(before)
+      || is_punctuation(before));
+  let right_flanking = !is_whitespace(before)
+    && (!is_punctuation(before)
+      || is_whitespace(after)
+      || is_punctuation(after));
+  if ch == '*' {
+    (left_flanking, right_flanking)
+  } else {
+    // `_`s don't emphasize parts of words
+    (
+      left_flanking && (!right_flanking || is_punctuation(before)),
+      right_flanking && (!left_flanking || is_punctuation(after)),
+    )
+  }
+}
+
+/// Matches the delimiter runs of pieces into emphasis, and turns the
+/// unmatched delimiters into text.
+fn parse_emphasis(mut pieces: Vec<Piece>) -> Vec<MarkdownInline> {
+  let mut closer = 0;
+  while closer < pieces.len() {
+    let (ch, closer_len, closer_can_open) = match &pieces[closer] {
+      Piece::Delimiter {
+        ch,
+        count,
+        len,
+        can_open,
+        can_close: true,
+      } if *count > 0 => (*ch, *len, *can_open),
+      _ => {
+        closer += 1;
+        continue;
+      }
+    };
+    let opener = (0..closer).rev().find(|&index| match &pieces[index] {
+      Piece::Delimiter {
+        ch: opener_ch,
+        count,
+        len,
+        can_open: true,
+        can_close,
+      } => {
+        // a run which can both open and close doesn't match a run when their
+        // lengths add up to a multiple of 3, unless both lengths are
+        let is_odd_match = (*can_close || closer_can_open)
+          && (len + closer_len) % 3 == 0
+          && !(len % 3 == 0 && closer_len % 3 == 0);
+        *opener_ch == ch && *count > 0 && !is_odd_match
+      }
+      _ => false,
+    });
+    let opener = match opener {
+      Some(opener) => opener,
+      None => {
+        closer += 1;
+        continue;
+      }
+    };
+    let used = if delimiter_count(&pieces[opener]) >= 2
+      && delimiter_count(&pieces[closer]) >= 2
+    {
+      2
+    } else {
+      1
+    };
+    let children = into_inlines(pieces.drain(opener + 1..closer).collect());
+    let emphasis = if used == 2 {
+      MarkdownInline::Strong { children }
+    } else {
+      MarkdownInline::Emphasis { children }
+    };
+    pieces.insert(opener + 1, Piece::Inline(emphasis));
+    closer = opener + 2;
+    for index in [opener, closer] {
+      if let Piece::Delimiter { count, .. } = &mut pieces[index] {
+        *count -= used;
+      }
+    }
+    if delimiter_count(&pieces[closer]) == 0 {
+      pieces.remove(closer);
+    }
+    if delimiter_count(&pieces[opener]) == 0 {
+      pieces.remove(opener);
+      closer -= 1;
+    }
+  }
+  into_inlines(pieces)
+}
+
+fn delimiter_count(piece: &Piece) -> usize {
+  match piece {
+    Piece::Delimiter { count, .. } => *count,
+    Piece::Inline(_) => 0,
+  }
+}
+
+fn into_inlines(pieces: Vec<Piece>) -> Vec<MarkdownInline> {
+  let mut inlines = Vec::new();
+  for piece in pieces {
+    let inline = match piece {
+      Piece::Inline(inline) => inline,
+      Piece::Delimiter { count: 0, .. } => continue,
+      Piece::Delimiter { ch, count, .. } => MarkdownInline::Text {
+        value: ch.to_string().repeat(count),
+      },
+    };
+    // adjacent text is merged
+    if let MarkdownInline::Text { value: text } = &inline {
+      if let Some(MarkdownInline::Text { value }) = inlines.last_mut() {
+        value.push_str(text);
+        continue;
+      }
+    }
+    inlines.push(inline);
+  }
+  inlines
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn text(value: &str) -> MarkdownInline {
+    MarkdownInline::Text {
+      value: value.to_string(),
+    }
+  }
+
+  fn link_to(url: &str, children: Vec<MarkdownInline>) -> MarkdownInline {
+    MarkdownInline::Link {
+      url: url.to_string(),
+      children,
+    }
+  }
+
+  fn paragraph(value: &str) -> MarkdownBlock {
+    MarkdownBlock::Paragraph {
+      children: vec![text(value)],
+    }
+  }
+
+  fn list(start: Option<u32>, items: Vec<Vec<MarkdownBlock>>) -> MarkdownBlock {
+    MarkdownBlock::List { start, items }
+  }
+
+  #[test]
+  fn test_parse_markdown_blocks() {
+    let blocks = parse_markdown(
+      "# Title\n\nSome text\nacross lines.\n\n- one\n- two\n  continued\n\n\
+       2. second\n3. third\n\n```ts\nconst a = 1;\n```\n\n    indented\n\n\
+       > quoted\n\n---\n\nSetext\n===",
+    );
+    assert_eq!(
+      serde_json::to_value(&blocks).unwrap(),
+      json!([
+        {
+          "kind": "heading",
+          "level": 1,
+          "children": [{ "kind": "text", "value": "Title" }],
+        },
+        {
+          "kind": "paragraph",
+          "children": [{ "kind": "text", "value": "Some text\nacross lines." }],
+        },
+        {
+          "kind": "list",
+          "items": [
+            [{
+              "kind": "paragraph",
+              "children": [{ "kind": "text", "value": "one" }],
+            }],
+            [{
+              "kind": "paragraph",
+              "children": [{ "kind": "text", "value": "two\ncontinued" }],
+            }],
+          ],
+        },
+        {
+          "kind": "list",
+          "start": 2,
+          "items": [
+            [{
+              "kind": "paragraph",
+              "children": [{ "kind": "text", "value": "second" }],
+            }],
+            [{
+              "kind": "paragraph",
+              "children": [{ "kind": "text", "value": "third" }],
+            }],
+          ],
+        },
+        { "kind": "codeBlock", "lang": "ts", "code": "const a = 1;" },
+        { "kind": "codeBlock", "code": "indented" },
+        {
+          "kind": "blockQuote",
+          "children": [{
+            "kind": "paragraph",
+            "children": [{ "kind": "text", "value": "quoted" }],
+          }],
+        },
+        { "kind": "thematicBreak" },
+        {
+          "kind": "heading",
+          "level": 1,
+          "children": [{ "kind": "text", "value": "Setext" }],
+        },
+      ])
+    );
+  }
+
+  #[test]
+  fn test_parse_markdown_inlines() {
+    assert_eq!(
+      parse_inlines("a *b* __c__ ***d*** `e*f*`"),
+      vec![
+        text("a "),
+        MarkdownInline::Emphasis {
+          children: vec![text("b")],
+        },
+        text(" "),
+        MarkdownInline::Strong {
+          children: vec![text("c")],
+        },
+        text(" "),
+        MarkdownInline::Emphasis {
+          children: vec![MarkdownInline::Strong {
+            children: vec![text("d")],
+          }],
+        },
+        text(" "),
+        MarkdownInline::Code {
+          value: "e*f*".to_string(),
+        },
+      ]
+    );
+    assert_eq!(
+      parse_inlines("snake_case_name and 2 * 3 * 4 and \\*not\\*"),
+      vec![text("snake_case_name and 2 * 3 * 4 and *not*")]
+    );
+    assert_eq!(
+      parse_inlines(
+        "see [the *docs*](https://deno.land \"Deno\") or <https://deno.land>"
+      ),
+      vec![
+        text("see "),
+        MarkdownInline::Link {
+          url: "https://deno.land".to_string(),
+          children: vec![
+            text("the "),
+            MarkdownInline::Emphasis {
+              children: vec![text("docs")],
+            },
+          ],
+        },
+        text(" or "),
+        MarkdownInline::Link {
+          url: "https://deno.land".to_string(),
+          children: vec![text("https://deno.land")],
+        },
+      ]
+    );
+    assert_eq!(
+      parse_inlines("line  \nbreak\\\nand {@link foo_bar | the *bar*}"),
+      vec![
+        text("line"),
+        MarkdownInline::LineBreak,
+        text("break"),
+        MarkdownInline::LineBreak,
+        text("and "),
+        MarkdownInline::JsDocLink {
+          target: "foo_bar".to_string(),
+          label: Some("the *bar*".to_string()),
+          style: LinkStyle::Link,
+          resolution: None,
+        },
+      ]
+    );
+  }
+
+  // the examples of the CommonMark spec for links
+  #[test]
+  fn test_parse_markdown_commonmark_links() {
+    let cases = [
+      ("[link](/uri \"title\")", "/uri"),
+      ("[link]()", ""),
+      ("[link](<>)", ""),
+      ("[link](\\(foo\\))", "(foo)"),
+      ("[link](foo(and(bar)))", "foo(and(bar))"),
+      ("[link](<foo(and(bar)>)", "foo(and(bar)"),
+      ("[link](foo\\)\\:)", "foo):"),
+      ("[link](/url 'title')", "/url"),
+      ("[link](/url (title))", "/url"),
+      (
+        "[link](https://en.wikipedia.org/wiki/Foo_(bar))",
+        "https://en.wikipedia.org/wiki/Foo_(bar)",
+      ),
+    ];
+    for (markdown, url) in cases {
+      assert_eq!(
+        parse_inlines(markdown),
+        vec![link_to(url, vec![text("link")])],
+        "{}",
+        markdown
+      );
+    }
+    for markdown in [
+      "[link](/my uri)",
+      "[link](<foo\nbar>)",
+      "[link](foo(and(bar))",
+      "[link] bar](/uri)",
+    ] {
+      assert_eq!(
+        parse_inlines(markdown),
+        vec![text(markdown)],
+        "{}",
+        markdown
+      );
+    }
+    assert_eq!(
+      parse_inlines("[link [foo [bar]]](/uri)"),
+      vec![link_to("/uri", vec![text("link [foo [bar]]")])]
+    );
+    assert_eq!(
+      parse_inlines("[link \\[bar](/uri)"),
+      vec![link_to("/uri", vec![text("link [bar")])]
+    );
+    // brackets in code spans don't end the text of links
+    assert_eq!(
+      parse_inlines("[foo`](/uri)`"),
+      vec![
+        text("[foo"),
+        MarkdownInline::Code {
+          value: "](/uri)".to_string(),
+        },
+      ]
+    );
+    assert_eq!(
+      parse_inlines("[a `]` b](/uri)"),
+      vec![link_to(
+        "/uri",
+        vec![
+          text("a "),
+          MarkdownInline::Code {
+            value: "]".to_string(),
+          },
+          text(" b"),
+        ]
+      )]
+    );
+  }
+
+  // the examples of the CommonMark spec for emphasis
+  #[test]
+  fn test_parse_markdown_commonmark_emphasis() {
+    let emphasis = |children| MarkdownInline::Emphasis { children };
+    let strong = |children| MarkdownInline::Strong { children };
+    assert_eq!(
+      parse_inlines("*foo bar*"),
+      vec![emphasis(vec![text("foo bar")])]
+    );
+    assert_eq!(parse_inlines("a * foo bar*"), vec![text("a * foo bar*")]);
+    assert_eq!(
+      parse_inlines("foo*bar*"),
+      vec![text("foo"), emphasis(vec![text("bar")])]
+    );
+    assert_eq!(parse_inlines("_foo_bar"), vec![text("_foo_bar")]);
+    assert_eq!(
+      parse_inlines("**foo bar**"),
+      vec![strong(vec![text("foo bar")])]
+    );
+    assert_eq!(
+      parse_inlines("*foo**bar*"),
+      vec![emphasis(vec![text("foo**bar")])]
+    );
+    assert_eq!(
+      parse_inlines("*foo**bar**baz*"),
+      vec![emphasis(vec![
+        text("foo"),
+        strong(vec![text("bar")]),
+        text("baz"),
+      ])]
+    );
+    assert_eq!(
+      parse_inlines("foo***bar***baz"),
+      vec![
+        text("foo"),
+        emphasis(vec![strong(vec![text("bar")])]),
+        text("baz"),
+      ]
+    );
+    assert_eq!(
+      parse_inlines("__foo, __bar__, baz__"),
+      vec![strong(vec![
+        text("foo, "),
+        strong(vec![text("bar")]),
+        text(", baz"),
+      ])]
+    );
+  }
+
+  // the examples of the CommonMark spec for lists
+  #[test]
+  fn test_parse_markdown_commonmark_lists() {
+    assert_eq!(
+      parse_markdown("- foo\n- bar\n+ baz"),
+      vec![
+        list(None, vec![vec![paragraph("foo")], vec![paragraph("bar")]]),
+        list(None, vec![vec![paragraph("baz")]]),
+      ]
+    );
+    assert_eq!(
+      parse_markdown("1. foo\n2. bar\n3) baz"),
+      vec![
+        list(
+          Some(1),
+          vec![vec![paragraph("foo")], vec![paragraph("bar")]]
+        ),
+        list(Some(3), vec![vec![paragraph("baz")]]),
+      ]
+    );
+    assert_eq!(
+      parse_markdown("- a\n- b\n\n- c"),
+      vec![list(
+        None,
+        vec![
+          vec![paragraph("a")],
+          vec![paragraph("b")],
+          vec![paragraph("c")],
+        ]
+      )]
+    );
+    assert_eq!(
+      parse_markdown("- a\n  - b\n    - c"),
+      vec![list(
+        None,
+        vec![vec![
+          paragraph("a"),
+          list(
+            None,
+            vec![vec![paragraph("b"), list(None, vec![vec![paragraph("c")]]),]]
+          ),
+        ]]
+      )]
+    );
+    // only a list starting at 1 interrupts a paragraph
+    assert_eq!(
+      parse_markdown(
+        "The number of windows in my house is\n14.  The number of doors is 6."
+      ),
+      vec![paragraph(
+        "The number of windows in my house is\n14.  The number of doors is 6."
+      )]
+    );
+    assert_eq!(
+      parse_markdown("-one\n\n2.two"),
+      vec![paragraph("-one"), paragraph("2.two")]
+    );
+  }
+}
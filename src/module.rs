@@ -17,13 +17,18 @@ pub fn get_doc_node_for_export_decl(
   let export_span = export_decl.span();
   use deno_ast::swc::ast::Decl;
 
-  let js_doc = js_doc_for_span(parsed_source, &export_span);
+  let js_doc =
+    js_doc_for_span(parsed_source, &export_span, doc_parser.js_doc_options());
   let location = get_location(parsed_source, export_span.lo());
 
   match &export_decl.decl {
     Decl::Class(class_decl) => {
       let (name, class_def, decorator_js_doc) =
-        super::class::get_doc_for_class_decl(parsed_source, class_decl);
+        super::class::get_doc_for_class_decl(
+          parsed_source,
+          class_decl,
+          doc_parser.js_doc_options(),
+        );
       let js_doc = if js_doc.is_empty() {
         decorator_js_doc
       } else {
@@ -37,7 +42,11 @@ pub fn get_doc_node_for_export_decl(
       DocNode::function(name, location, DeclarationKind::Export, js_doc, fn_def)
     }
     Decl::Var(var_decl) => {
-      let (name, var_def) = super::variable::get_doc_for_var_decl(var_decl);
+      let (name, var_def) = super::variable::get_doc_for_var_decl(
+        parsed_source,
+        &doc_parser.module_symbols(parsed_source),
+        var_decl,
+      );
       DocNode::variable(
         name,
         location,
@@ -51,6 +60,7 @@ pub fn get_doc_node_for_export_decl(
         super::interface::get_doc_for_ts_interface_decl(
           parsed_source,
           ts_interface_decl,
+          doc_parser.js_doc_options(),
         );
       DocNode::interface(
         name,
@@ -65,6 +75,7 @@ pub fn get_doc_node_for_export_decl(
         super::type_alias::get_doc_for_ts_type_alias_decl(
           parsed_source,
           ts_type_alias,
+          doc_parser.js_doc_options(),
         );
       DocNode::type_alias(
         name,
@@ -75,8 +86,11 @@ pub fn get_doc_node_for_export_decl(
       )
     }
     Decl::TsEnum(ts_enum) => {
-      let (name, enum_def) =
-        super::r#enum::get_doc_for_ts_enum_decl(parsed_source, ts_enum);
+      let (name, enum_def) = super::r#enum::get_doc_for_ts_enum_decl(
+        parsed_source,
+        ts_enum,
+        doc_parser.js_doc_options(),
+      );
       DocNode::r#enum(name, location, DeclarationKind::Export, js_doc, enum_def)
     }
     Decl::TsModule(ts_module) => {
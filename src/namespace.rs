@@ -19,7 +19,11 @@ pub fn get_doc_for_ts_namespace_decl(
   parsed_source: &ParsedSource,
   ts_namespace_decl: &deno_ast::swc::ast::TsNamespaceDecl,
 ) -> DocNode {
-  let js_doc = js_doc_for_span(parsed_source, &ts_namespace_decl.span);
+  let js_doc = js_doc_for_span(
+    parsed_source,
+    &ts_namespace_decl.span,
+    doc_parser.js_doc_options(),
+  );
   let location = get_location(parsed_source, ts_namespace_decl.span.lo());
   let namespace_name = ts_namespace_decl.id.sym.to_string();
 
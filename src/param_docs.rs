@@ -0,0 +1,336 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassAncestorDef;
+use crate::class::ClassConstructorDef;
+use crate::class::ClassMethodDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceCallSignatureDef;
+use crate::interface::InterfaceMethodDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::params::ObjectPatPropDef;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::visit::walk_class_ancestor_def_mut;
+use crate::visit::walk_doc_node_mut;
+use crate::visit::walk_interface_ancestor_def_mut;
+use crate::visit::DocVisitorMut;
+use crate::Location;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A `@param` tag naming a parameter, or a property of a parameter, which
+/// doesn't exist.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownParam {
+  /// The location of the documented function.
+  pub location: Location,
+  /// The name of the parameter as written in the tag, e.g. `options.timeout`.
+  pub name: String,
+}
+
+/// Attaches the descriptions of the `@param` and `@returns` tags of the
+/// functions, methods, constructors and call signatures of doc nodes to their
+/// parameters and to their `return_doc`.
+///
+/// A tag named with a dotted path such as `options.timeout` describes a
+/// property of a destructured parameter, or of a parameter typed with a type
+/// literal. Destructured parameters are matched in order to the tags naming no
+/// other parameter.
+/// Returns the tags naming parameters which don't exist.
+pub fn merge_param_docs(doc_nodes: &mut [DocNode]) -> Vec<UnknownParam> {
+  let mut merger = ParamDocsMerger::default();
+  for doc_node in doc_nodes {
+    merger.visit_doc_node_mut(doc_node);
+  }
+  merger.unknown_params
+}
+
+/// The name of a parameter which isn't destructured.
+fn param_name(param: &ParamDef) -> Option<&str> {
+  match &param.pattern {
+    ParamPatternDef::Identifier { name, .. } => Some(name),
+    ParamPatternDef::Assign { left, .. } => param_name(left),
+    ParamPatternDef::Rest { arg } => param_name(arg),
+    ParamPatternDef::Array { .. } | ParamPatternDef::Object { .. } => None,
+  }
+}
+
+/// Attaches `doc` to the property at `path` of a parameter, or to the
+/// parameter itself if `path` is empty. Returns whether the property exists.
+fn attach_param_doc(
+  param: &mut ParamDef,
+  path: &[&str],
+  doc: &Option<String>,
+) -> bool {
+  let (name, rest) = match path.split_first() {
+    Some(split) => split,
+    None => {
+      if doc.is_some() {
+        param.doc = doc.clone();
+      }
+      return true;
+    }
+  };
+
+  let mut found = false;
+  if let Some(ts_type) = &mut param.ts_type {
+    found |= attach_property_doc(ts_type, path, doc);
+  }
+  match &mut param.pattern {
+    ParamPatternDef::Assign { left, .. } => {
+      found |= attach_param_doc(left, path, doc)
+    }
+    ParamPatternDef::Rest { arg } => found |= attach_param_doc(arg, path, doc),
+    ParamPatternDef::Object { props, .. } => {
+      for prop in props {
+        match prop {
+          ObjectPatPropDef::Assign {
+            key,
+            ts_type,
+            doc: prop_doc,
+            ..
+          } if key.as_str() == *name => {
+            if rest.is_empty() {
+              if doc.is_some() {
+                *prop_doc = doc.clone();
+              }
+              found = true;
+            } else if let Some(ts_type) = ts_type {
+              found |= attach_property_doc(ts_type, rest, doc);
+            }
+          }
+          ObjectPatPropDef::KeyValue { key, value }
+            if key.as_str() == *name =>
+          {
+            found |= attach_param_doc(value, rest, doc);
+          }
+          _ => {}
+        }
+      }
+    }
+    ParamPatternDef::Array { .. } | ParamPatternDef::Identifier { .. } => {}
+  }
+  found
+}
+
+/// Attaches `doc` to the property at `path` of a type literal. Returns whether
+/// the property exists.
+fn attach_property_doc(
+  ts_type: &mut TsTypeDef,
+  path: &[&str],
+  doc: &Option<String>,
+) -> bool {
+  let (name, rest) = match path.split_first() {
+    Some(split) => split,
+    None => return false,
+  };
+  match &mut ts_type.kind {
+    TsTypeDefKind::TypeLiteral(type_literal) => {
+      let mut found = false;
+      for property in &mut type_literal.properties {
+        if property.name != *name {
+          continue;
+        }
+        if rest.is_empty() {
+          if doc.is_some() {
+            property.doc = doc.clone();
+          }
+          found = true;
+        } else if let Some(ts_type) = &mut property.ts_type {
+          found |= attach_property_doc(ts_type, rest, doc);
+        }
+      }
+      found
+    }
+    TsTypeDefKind::Parenthesized(ts_type)
+    | TsTypeDefKind::Optional(ts_type) => {
+      attach_property_doc(ts_type, path, doc)
+    }
+    TsTypeDefKind::Union(ts_types) | TsTypeDefKind::Intersection(ts_types) => {
+      ts_types.iter_mut().fold(false, |found, ts_type| {
+        attach_property_doc(ts_type, path, doc) || found
+      })
+    }
+    _ => false,
+  }
+}
+
+#[derive(Default)]
+struct ParamDocsMerger {
+  unknown_params: Vec<UnknownParam>,
+  /// Whether the members inherited from an ancestor are being visited, whose
+  /// unknown parameters are reported at the ancestor.
+  in_inherited: bool,
+}
+
+impl ParamDocsMerger {
+  fn merge(
+    &mut self,
+    js_doc: &JsDoc,
+    location: &Location,
+    params: &mut [ParamDef],
+    return_doc: Option<&mut Option<String>>,
+  ) {
+    let param_tags = js_doc
+      .tags
+      .iter()
+      .filter_map(|tag| match tag {
+        JsDocTag::Param { name, doc, .. } => Some((name, doc)),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    // the roots of the names of the tags which name no parameter, in order,
+    // so the destructured parameters can be matched by their position
+    let destructured = params
+      .iter()
+      .enumerate()
+      .filter(|(_, param)| param_name(param).is_none())
+      .map(|(index, _)| index)
+      .collect::<Vec<_>>();
+    let mut unnamed_roots: Vec<&str> = Vec::new();
+    for (name, _) in &param_tags {
+      let root_name =
+        name.split_once('.').map_or(name.as_str(), |(root, _)| root);
+      if !unnamed_roots.contains(&root_name)
+        && !params
+          .iter()
+          .any(|param| param_name(param) == Some(root_name))
+      {
+        unnamed_roots.push(root_name);
+      }
+    }
+
+    for (name, doc) in param_tags {
+      let mut path = name.split('.').collect::<Vec<_>>();
+      let root_name = path.remove(0);
+      let index = params
+        .iter()
+        .position(|param| param_name(param) == Some(root_name))
+        .or_else(|| {
+          let position = unnamed_roots.iter().position(|n| *n == root_name)?;
+          destructured.get(position).copied()
+        });
+      let found = match index {
+        Some(index) => attach_param_doc(&mut params[index], &path, doc),
+        None => false,
+      };
+      if !found && !self.in_inherited {
+        self.unknown_params.push(UnknownParam {
+          location: location.clone(),
+          name: name.clone(),
+        });
+      }
+    }
+
+    if let Some(return_doc) = return_doc {
+      let doc = js_doc.tags.iter().find_map(|tag| match tag {
+        JsDocTag::Return { doc: Some(doc), .. } => Some(doc),
+        _ => None,
+      });
+      if let Some(doc) = doc {
+        *return_doc = Some(doc.clone());
+      }
+    }
+  }
+}
+
+impl DocVisitorMut for ParamDocsMerger {
+  fn visit_doc_node_mut(&mut self, doc_node: &mut DocNode) {
+    if let Some(function_def) = &mut doc_node.function_def {
+      self.merge(
+        &doc_node.js_doc,
+        &doc_node.location,
+        &mut function_def.params,
+        Some(&mut function_def.return_doc),
+      );
+    }
+    // functions assigned to variables
+    if let Some(TsTypeDefKind::FnOrConstructor(fn_def)) = doc_node
+      .variable_def
+      .as_mut()
+      .and_then(|variable_def| variable_def.ts_type.as_mut())
+      .map(|ts_type| &mut ts_type.kind)
+    {
+      self.merge(
+        &doc_node.js_doc,
+        &doc_node.location,
+        &mut fn_def.params,
+        Some(&mut fn_def.return_doc),
+      );
+    }
+    walk_doc_node_mut(self, doc_node);
+  }
+
+  fn visit_class_constructor_def_mut(
+    &mut self,
+    constructor_def: &mut ClassConstructorDef,
+  ) {
+    self.merge(
+      &constructor_def.js_doc,
+      &constructor_def.location,
+      &mut constructor_def.params,
+      None,
+    );
+  }
+
+  fn visit_class_method_def_mut(&mut self, method_def: &mut ClassMethodDef) {
+    self.merge(
+      &method_def.js_doc,
+      &method_def.location,
+      &mut method_def.function_def.params,
+      Some(&mut method_def.function_def.return_doc),
+    );
+  }
+
+  fn visit_interface_method_def_mut(
+    &mut self,
+    method_def: &mut InterfaceMethodDef,
+  ) {
+    self.merge(
+      &method_def.js_doc,
+      &method_def.location,
+      &mut method_def.params,
+      Some(&mut method_def.return_doc),
+    );
+  }
+
+  fn visit_interface_call_signature_def_mut(
+    &mut self,
+    call_signature_def: &mut InterfaceCallSignatureDef,
+  ) {
+    self.merge(
+      &call_signature_def.js_doc,
+      &call_signature_def.location,
+      &mut call_signature_def.params,
+      Some(&mut call_signature_def.return_doc),
+    );
+  }
+
+  fn visit_class_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut ClassAncestorDef,
+  ) {
+    let in_inherited = std::mem::replace(&mut self.in_inherited, true);
+    walk_class_ancestor_def_mut(self, ancestor_def);
+    self.in_inherited = in_inherited;
+  }
+
+  fn visit_interface_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut InterfaceAncestorDef,
+  ) {
+    let in_inherited = std::mem::replace(&mut self.in_inherited, true);
+    walk_interface_ancestor_def_mut(self, ancestor_def);
+    self.in_inherited = in_inherited;
+  }
+
+  // the parameters of types are documented by their own tags
+  fn visit_ts_type_def_mut(&mut self, _ts_type_def: &mut TsTypeDef) {}
+}
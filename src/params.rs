@@ -4,6 +4,7 @@ use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
 use crate::display::display_optional;
 use crate::display::SliceDisplayer;
+use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::ts_type_ann_to_def;
 use crate::ts_type::TsTypeDef;
 
@@ -20,7 +21,7 @@ use std::fmt::Result as FmtResult;
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "kind")]
-enum ParamPatternDef {
+pub(crate) enum ParamPatternDef {
   Array {
     elements: Vec<Option<ParamDef>>,
     optional: bool,
@@ -46,10 +47,19 @@ enum ParamPatternDef {
 #[serde(rename_all = "camelCase")]
 pub struct ParamDef {
   #[serde(flatten)]
-  pattern: ParamPatternDef,
+  pub(crate) pattern: ParamPatternDef,
   #[serde(skip_serializing_if = "Vec::is_empty")]
-  decorators: Vec<DecoratorDef>,
-  ts_type: Option<TsTypeDef>,
+  pub(crate) decorators: Vec<DecoratorDef>,
+  pub(crate) ts_type: Option<TsTypeDef>,
+  /// The description of the parameter from its `@param` tag.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) doc: Option<String>,
+}
+
+impl ParamDef {
+  pub(crate) fn ts_type(&self) -> Option<&TsTypeDef> {
+    self.ts_type.as_ref()
+  }
 }
 
 impl Display for ParamDef {
@@ -121,9 +131,27 @@ impl Display for ParamDef {
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "kind")]
 pub enum ObjectPatPropDef {
-  Assign { key: String, value: Option<String> },
-  KeyValue { key: String, value: Box<ParamDef> },
-  Rest { arg: Box<ParamDef> },
+  Assign {
+    key: String,
+    value: Option<String>,
+    /// The type inferred from the default value, if any.
+    #[serde(
+      rename = "tsType",
+      default,
+      skip_serializing_if = "Option::is_none"
+    )]
+    ts_type: Option<TsTypeDef>,
+    /// The description of the property from its `@param` tag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+  },
+  KeyValue {
+    key: String,
+    value: Box<ParamDef>,
+  },
+  Rest {
+    arg: Box<ParamDef>,
+  },
 }
 
 impl Display for ObjectPatPropDef {
@@ -155,6 +183,7 @@ pub fn ident_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -170,6 +199,7 @@ fn rest_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -181,6 +211,11 @@ fn object_pat_prop_to_def(
     ObjectPatProp::Assign(assign) => ObjectPatPropDef::Assign {
       key: assign.key.sym.to_string(),
       value: assign.value.as_ref().map(|_| "[UNSUPPORTED]".to_string()),
+      ts_type: assign
+        .value
+        .as_ref()
+        .and_then(|value| infer_ts_type_from_default(value)),
+      doc: None,
     },
     ObjectPatProp::KeyValue(keyvalue) => ObjectPatPropDef::KeyValue {
       key: prop_name_to_string(parsed_source, &keyvalue.key),
@@ -210,6 +245,7 @@ fn object_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -231,6 +267,7 @@ fn array_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -238,18 +275,48 @@ pub fn assign_pat_to_param_def(
   parsed_source: Option<&ParsedSource>,
   assign_pat: &deno_ast::swc::ast::AssignPat,
 ) -> ParamDef {
-  let ts_type = assign_pat.type_ann.as_ref().map(ts_type_ann_to_def);
+  let mut left = pat_to_param_def(parsed_source, &*assign_pat.left);
+  // parameters with a default value can be omitted
+  match &mut left.pattern {
+    ParamPatternDef::Array { optional, .. }
+    | ParamPatternDef::Identifier { optional, .. }
+    | ParamPatternDef::Object { optional, .. } => *optional = true,
+    ParamPatternDef::Assign { .. } | ParamPatternDef::Rest { .. } => {}
+  }
+  let ts_type = assign_pat
+    .type_ann
+    .as_ref()
+    .map(ts_type_ann_to_def)
+    .or_else(|| match (&left.pattern, &left.ts_type) {
+      // destructured parameters get their types from the defaults of their
+      // properties instead
+      (ParamPatternDef::Identifier { .. }, None) => {
+        infer_ts_type_from_default(&assign_pat.right)
+      }
+      _ => None,
+    });
 
   ParamDef {
     pattern: ParamPatternDef::Assign {
-      left: Box::new(pat_to_param_def(parsed_source, &*assign_pat.left)),
+      left: Box::new(left),
       right: "[UNSUPPORTED]".to_string(),
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
+/// Infers the type of a parameter or a destructured property from its default
+/// value.
+fn infer_ts_type_from_default(
+  default: &deno_ast::swc::ast::Expr,
+) -> Option<TsTypeDef> {
+  let mut ts_type = infer_ts_type_from_expr(default, false)?;
+  ts_type.inferred = true;
+  Some(ts_type)
+}
+
 pub fn param_to_param_def(
   parsed_source: &ParsedSource,
   param: &deno_ast::swc::ast::Param,
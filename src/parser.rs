@@ -1,5 +1,9 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use crate::custom_tag::CustomTagParser;
+use crate::custom_tag::TagParser;
+use crate::custom_tag::TagShape;
+use crate::inheritance::InheritedMembersResolver;
 use crate::js_doc::JsDoc;
 use crate::namespace::NamespaceDef;
 use crate::node;
@@ -10,6 +14,11 @@ use crate::swc_util::get_location;
 use crate::swc_util::js_doc_for_span;
 use crate::swc_util::module_export_name_value;
 use crate::swc_util::module_js_doc_for_source;
+use crate::swc_util::JsDocOptions;
+use crate::type_ref_resolver::TypeRefResolver;
+use crate::variable::ModuleSymbols;
+use crate::visibility::VisibilityFilter;
+use crate::visit::DocVisitorMut;
 use crate::ImportDef;
 use crate::Location;
 use crate::ReexportKind;
@@ -29,9 +38,11 @@ use deno_graph::ModuleSpecifier;
 use deno_graph::Resolved;
 use deno_graph::SourceParser;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::rc::Rc;
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -62,21 +73,35 @@ impl From<deno_ast::Diagnostic> for DocError {
 }
 
 #[derive(Clone)]
-enum ImportKind {
+pub(crate) enum ImportKind {
   Namespace(String),
   Named(String, Option<String>),
 }
 
 #[derive(Clone)]
-struct Import {
-  src: String,
-  kind: ImportKind,
+pub(crate) struct Import {
+  pub src: String,
+  pub kind: ImportKind,
 }
 
 pub struct DocParser<'a> {
   pub ast_parser: &'a dyn SourceParser,
   pub graph: ModuleGraph,
   pub private: bool,
+  /// Hide symbols and members tagged with `@internal` from the docs, as is
+  /// always done for the ones tagged with `@ignore`.
+  pub strip_internal: bool,
+  /// Attach the last JSDoc comment before a declaration to it even when a
+  /// blank line separates them or when it is the module doc.
+  pub loose_js_doc: bool,
+  /// Keep the comments which the docs of symbols and members are parsed
+  /// from in their `JsDoc`, along with their spans and the lines of their
+  /// tags.
+  pub js_doc_comments: bool,
+  /// The parsers of custom tags, by the names of the tags.
+  tag_parsers: HashMap<String, TagParser<'a>>,
+  /// The symbols declared in the modules parsed so far, by specifier.
+  module_symbols: RefCell<HashMap<String, Rc<ModuleSymbols>>>,
 }
 
 impl<'a> DocParser<'a> {
@@ -89,6 +114,55 @@ impl<'a> DocParser<'a> {
       ast_parser,
       graph,
       private,
+      strip_internal: false,
+      loose_js_doc: false,
+      js_doc_comments: false,
+      tag_parsers: HashMap::new(),
+      module_symbols: RefCell::new(HashMap::new()),
+    }
+  }
+
+  /// Registers a parser for the tag `@name`, which turns the tags of that
+  /// name into `JsDocTag::Custom` tags holding the value `parser` parses from
+  /// the text following the name. Tags the parser returns `None` for, and
+  /// tags which are supported, such as `@see`, are left as they are.
+  pub fn register_tag_parser(
+    &mut self,
+    name: impl Into<String>,
+    parser: impl Fn(&str) -> Option<serde_json::Value> + 'a,
+  ) {
+    self.tag_parsers.insert(name.into(), Box::new(parser));
+  }
+
+  /// Registers a parser for the tag `@name` which parses the text following
+  /// the name according to `shape`.
+  pub fn register_tag_shape(
+    &mut self,
+    name: impl Into<String>,
+    shape: TagShape,
+  ) {
+    self.register_tag_parser(name, move |text| shape.parse(text));
+  }
+
+  /// Turns the tags of doc nodes which have a parser registered into custom
+  /// tags.
+  pub(crate) fn parse_custom_tags<'n>(
+    &self,
+    doc_nodes: impl IntoIterator<Item = &'n mut DocNode>,
+  ) {
+    if self.tag_parsers.is_empty() {
+      return;
+    }
+    let mut parser = CustomTagParser::new(&self.tag_parsers);
+    for doc_node in doc_nodes {
+      parser.visit_doc_node_mut(doc_node);
+    }
+  }
+
+  pub(crate) fn js_doc_options(&self) -> JsDocOptions {
+    JsDocOptions {
+      loose: self.loose_js_doc,
+      comments: self.js_doc_comments,
     }
   }
 
@@ -113,6 +187,8 @@ impl<'a> DocParser<'a> {
       specifier,
     )?;
     definitions.extend(import_doc_entries);
+    self.parse_custom_tags(&mut definitions);
+    VisibilityFilter::new(self.strip_internal).filter(&mut definitions);
     let reexports = self.get_reexports_for_module_body(&module.body);
     let module_doc = ModuleDoc {
       definitions,
@@ -301,6 +377,38 @@ impl<'a> DocParser<'a> {
     }
   }
 
+  /// Resolves the type references and the targets of inline links of doc
+  /// nodes returned by `parse_with_reexports` to the symbols they refer to,
+  /// marking the ones which could not be resolved.
+  pub fn resolve_type_refs(&self, doc_nodes: &mut [DocNode]) {
+    let mut resolver = TypeRefResolver::new(self);
+    for doc_node in doc_nodes {
+      resolver.visit_doc_node_mut(doc_node);
+    }
+  }
+
+  /// Fills in the members which the classes and interfaces of doc nodes
+  /// returned by `parse_with_reexports` inherit from their ancestors.
+  pub fn resolve_inherited_members(&self, doc_nodes: &mut [DocNode]) {
+    let mut resolver = InheritedMembersResolver::new(self);
+    for doc_node in doc_nodes {
+      resolver.visit_doc_node_mut(doc_node);
+    }
+  }
+
+  /// Gives the undocumented members of the classes and interfaces of doc
+  /// nodes returned by `parse_with_reexports` the docs of the members they
+  /// override or implement, searching the `extends` chain first and the
+  /// implemented interfaces next. Symbols and members tagged with
+  /// `@inheritDoc` get the docs of its target, or else of their ancestors.
+  /// The inherited docs are marked with `inherited_from`.
+  pub fn resolve_inherited_docs(&self, doc_nodes: &mut [DocNode]) {
+    let mut resolver = InheritedMembersResolver::for_docs(self);
+    for doc_node in doc_nodes {
+      resolver.visit_doc_node_mut(doc_node);
+    }
+  }
+
   fn get_doc_nodes_for_module_imports(
     &self,
     parsed_source: &ParsedSource,
@@ -314,7 +422,11 @@ impl<'a> DocParser<'a> {
         import_decl,
       )) = node
       {
-        let js_doc = js_doc_for_span(parsed_source, &import_decl.span);
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &import_decl.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, import_decl.span.lo);
         for specifier in &import_decl.specifiers {
           use deno_ast::swc::ast::ImportSpecifier::*;
@@ -375,7 +487,11 @@ impl<'a> DocParser<'a> {
         )]
       }
       ModuleDecl::ExportDefaultDecl(export_default_decl) => {
-        let js_doc = js_doc_for_span(parsed_source, &export_default_decl.span);
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &export_default_decl.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, export_default_decl.span.lo);
         let name = "default".to_string();
 
@@ -385,6 +501,7 @@ impl<'a> DocParser<'a> {
               crate::class::class_to_class_def(
                 parsed_source,
                 &class_expr.class,
+                self.js_doc_options(),
               );
             let js_doc = if js_doc.is_empty() {
               decorator_js_doc
@@ -417,6 +534,7 @@ impl<'a> DocParser<'a> {
               crate::interface::get_doc_for_ts_interface_decl(
                 parsed_source,
                 interface_decl,
+                self.js_doc_options(),
               );
             DocNode::interface(
               name,
@@ -434,6 +552,20 @@ impl<'a> DocParser<'a> {
     }
   }
 
+  /// The symbols declared in the module of `parsed_source`, which are
+  /// collected once per module.
+  pub(crate) fn module_symbols(
+    &self,
+    parsed_source: &ParsedSource,
+  ) -> Rc<ModuleSymbols> {
+    self
+      .module_symbols
+      .borrow_mut()
+      .entry(parsed_source.specifier().to_string())
+      .or_insert_with(|| Rc::new(ModuleSymbols::new(parsed_source)))
+      .clone()
+  }
+
   pub fn get_doc_node_for_decl(
     &self,
     parsed_source: &ParsedSource,
@@ -442,9 +574,16 @@ impl<'a> DocParser<'a> {
     match decl {
       Decl::Class(class_decl) => {
         // declared classes cannot have decorators, so we ignore that return
-        let (name, class_def, _) =
-          super::class::get_doc_for_class_decl(parsed_source, class_decl);
-        let js_doc = js_doc_for_span(parsed_source, &class_decl.class.span);
+        let (name, class_def, _) = super::class::get_doc_for_class_decl(
+          parsed_source,
+          class_decl,
+          self.js_doc_options(),
+        );
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &class_decl.class.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, class_decl.class.span.lo);
         Some(DocNode::class(
           name,
@@ -457,7 +596,11 @@ impl<'a> DocParser<'a> {
       Decl::Fn(fn_decl) => {
         let (name, function_def) =
           super::function::get_doc_for_fn_decl(parsed_source, fn_decl);
-        let js_doc = js_doc_for_span(parsed_source, &fn_decl.function.span);
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &fn_decl.function.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, fn_decl.function.span.lo);
         Some(DocNode::function(
           name,
@@ -468,8 +611,13 @@ impl<'a> DocParser<'a> {
         ))
       }
       Decl::Var(var_decl) => {
-        let (name, var_def) = super::variable::get_doc_for_var_decl(var_decl);
-        let js_doc = js_doc_for_span(parsed_source, &var_decl.span);
+        let (name, var_def) = super::variable::get_doc_for_var_decl(
+          parsed_source,
+          &self.module_symbols(parsed_source),
+          var_decl,
+        );
+        let js_doc =
+          js_doc_for_span(parsed_source, &var_decl.span, self.js_doc_options());
         let location = get_location(parsed_source, var_decl.span.lo);
         Some(DocNode::variable(
           name,
@@ -484,8 +632,13 @@ impl<'a> DocParser<'a> {
           super::interface::get_doc_for_ts_interface_decl(
             parsed_source,
             ts_interface_decl,
+            self.js_doc_options(),
           );
-        let js_doc = js_doc_for_span(parsed_source, &ts_interface_decl.span);
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &ts_interface_decl.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, ts_interface_decl.span.lo);
         Some(DocNode::interface(
           name,
@@ -500,8 +653,13 @@ impl<'a> DocParser<'a> {
           super::type_alias::get_doc_for_ts_type_alias_decl(
             parsed_source,
             ts_type_alias,
+            self.js_doc_options(),
           );
-        let js_doc = js_doc_for_span(parsed_source, &ts_type_alias.span);
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &ts_type_alias.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, ts_type_alias.span.lo);
         Some(DocNode::type_alias(
           name,
@@ -512,9 +670,13 @@ impl<'a> DocParser<'a> {
         ))
       }
       Decl::TsEnum(ts_enum) => {
-        let (name, enum_def) =
-          super::r#enum::get_doc_for_ts_enum_decl(parsed_source, ts_enum);
-        let js_doc = js_doc_for_span(parsed_source, &ts_enum.span);
+        let (name, enum_def) = super::r#enum::get_doc_for_ts_enum_decl(
+          parsed_source,
+          ts_enum,
+          self.js_doc_options(),
+        );
+        let js_doc =
+          js_doc_for_span(parsed_source, &ts_enum.span, self.js_doc_options());
         let location = get_location(parsed_source, ts_enum.span.lo);
         Some(DocNode::r#enum(
           name,
@@ -530,7 +692,11 @@ impl<'a> DocParser<'a> {
           parsed_source,
           ts_module,
         );
-        let js_doc = js_doc_for_span(parsed_source, &ts_module.span);
+        let js_doc = js_doc_for_span(
+          parsed_source,
+          &ts_module.span,
+          self.js_doc_options(),
+        );
         let location = get_location(parsed_source, ts_module.span.lo);
         Some(DocNode::namespace(
           name,
@@ -543,7 +709,7 @@ impl<'a> DocParser<'a> {
     }
   }
 
-  fn get_imports_for_module_body(
+  pub(crate) fn get_imports_for_module_body(
     &self,
     module_body: &[deno_ast::swc::ast::ModuleItem],
   ) -> HashMap<String, Import> {
@@ -705,7 +871,7 @@ impl<'a> DocParser<'a> {
     reexports
   }
 
-  fn get_symbols_for_module_body(
+  pub(crate) fn get_symbols_for_module_body(
     &self,
     parsed_source: &ParsedSource,
     module_body: &[deno_ast::swc::ast::ModuleItem],
@@ -748,7 +914,9 @@ impl<'a> DocParser<'a> {
     let mut is_ambient = true;
 
     // check to see if there is a module level JSDoc for the source file
-    if let Some((js_doc, span)) = module_js_doc_for_source(parsed_source) {
+    if let Some((js_doc, span)) =
+      module_js_doc_for_source(parsed_source, self.js_doc_options())
+    {
       let doc_node =
         DocNode::module_doc(get_location(parsed_source, span.lo), js_doc);
       doc_entries.push(doc_node);
@@ -817,7 +985,11 @@ impl<'a> DocParser<'a> {
                   });
                 }
               } else {
-                let js_doc = js_doc_for_span(parsed_source, &export_expr.span);
+                let js_doc = js_doc_for_span(
+                  parsed_source,
+                  &export_expr.span,
+                  self.js_doc_options(),
+                );
                 let location = get_location(parsed_source, export_expr.span.lo);
                 doc_entries.push(DocNode::variable(
                   String::from("default"),
@@ -10,25 +10,64 @@
 // unnecessary and can result in unnecessary copying. Instead they should take
 // references.
 
+use crate::accessor::AccessorDef;
+use crate::category::group_by_category;
+use crate::category::GroupBy;
+use crate::class::ClassAncestorDef;
+use crate::class::ClassConstructorDef;
+use crate::class::ClassIndexSignatureDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
 use crate::colors;
 use crate::display::display_abstract;
 use crate::display::display_async;
 use crate::display::display_generator;
 use crate::display::Indent;
 use crate::display::SliceDisplayer;
+use crate::inheritance::InheritedMemberDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceCallSignatureDef;
+use crate::interface::InterfaceIndexSignatureDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::js_doc::parse_inline_tags;
+use crate::js_doc::ExampleBlock;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocSegment;
 use crate::js_doc::JsDocTag;
+use crate::js_doc::LinkStyle;
+use crate::markdown::parse_markdown;
+use crate::markdown::MarkdownBlock;
+use crate::markdown::MarkdownInline;
 use crate::node::DocNode;
 use crate::node::DocNodeKind;
+use crate::r#enum::EnumMemberDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::visit::DocVisitor;
+
+use deno_ast::swc::ast::Accessibility;
 
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
+/// The width documentation is wrapped to, including its indentation.
+const WRAP_WIDTH: usize = 80;
+
 pub struct DocPrinter<'a> {
   doc_nodes: &'a [DocNode],
   use_color: bool,
   private: bool,
+  /// Print getter/setter pairs of classes and interfaces as a single property
+  /// instead of as separate `get`/`set` methods.
+  pub merge_accessors: bool,
+  /// Print the members classes and interfaces inherit from their ancestors,
+  /// as filled in by `DocParser::resolve_inherited_members`.
+  pub show_inherited: bool,
+  /// Print the doc nodes grouped by category, each category under a header
+  /// with its name. The doc nodes of each category are ordered by kind.
+  pub group_by: Option<GroupBy>,
 }
 
 impl<'a> DocPrinter<'a> {
@@ -41,11 +80,26 @@ impl<'a> DocPrinter<'a> {
       doc_nodes,
       use_color,
       private,
+      merge_accessors: false,
+      show_inherited: false,
+      group_by: None,
     }
   }
 
   pub fn format(&self, w: &mut Formatter<'_>) -> FmtResult {
-    self.format_(w, self.doc_nodes, 0)
+    let group_by = match self.group_by {
+      Some(group_by) => group_by,
+      None => return self.format_(w, self.doc_nodes, 0),
+    };
+    for category in group_by_category(self.doc_nodes, group_by) {
+      if self.use_color {
+        colors::enable_color();
+      }
+      let name = category.name.as_deref().unwrap_or("Uncategorized");
+      writeln!(w, "{}\n", colors::bold(name))?;
+      self.format_(w, &category.doc_nodes, 0)?;
+    }
+    Ok(())
   }
 
   fn format_(
@@ -88,6 +142,7 @@ impl<'a> DocPrinter<'a> {
         DocNodeKind::Enum => self.format_enum(w, node)?,
         DocNodeKind::Interface => self.format_interface(w, node)?,
         DocNodeKind::Namespace => self.format_namespace(w, node)?,
+        DocNodeKind::TypeAlias => self.format_type_alias(w, node)?,
         _ => {}
       }
     }
@@ -145,9 +200,7 @@ impl<'a> DocPrinter<'a> {
     indent: i64,
   ) -> FmtResult {
     if let Some(doc) = &js_doc.doc {
-      for line in doc.lines() {
-        writeln!(w, "{}{}", Indent(indent), colors::gray(line))?;
-      }
+      self.format_markdown(w, doc, &js_doc.markdown, indent)?;
     }
     if !js_doc.tags.is_empty() {
       writeln!(w)?;
@@ -158,16 +211,44 @@ impl<'a> DocPrinter<'a> {
     Ok(())
   }
 
+  /// Prints documentation as Markdown, with its paragraphs wrapped to fit
+  /// `WRAP_WIDTH` along with the indentation. The Markdown filled in by
+  /// `parse_js_doc_markdown` is used if there is any, or else the
+  /// documentation is parsed.
+  fn format_markdown(
+    &self,
+    w: &mut Formatter<'_>,
+    doc: &str,
+    markdown: &[MarkdownBlock],
+    indent: i64,
+  ) -> FmtResult {
+    let width = WRAP_WIDTH
+      .saturating_sub(indent as usize * 2)
+      .max(WRAP_WIDTH / 2);
+    let lines = if markdown.is_empty() {
+      render_markdown(&parse_markdown(doc), width)
+    } else {
+      render_markdown(markdown, width)
+    };
+    for line in lines {
+      if line.is_empty() {
+        writeln!(w)?;
+      } else {
+        writeln!(w, "{}{}", Indent(indent), line)?;
+      }
+    }
+    Ok(())
+  }
+
   fn format_jsdoc_tag_maybe_doc(
     &self,
     w: &mut Formatter<'_>,
     maybe_doc: &Option<String>,
+    markdown: &[MarkdownBlock],
     indent: i64,
   ) -> FmtResult {
     if let Some(doc) = maybe_doc {
-      for line in doc.lines() {
-        writeln!(w, "{}{}", Indent(indent + 2), colors::gray(line))?;
-      }
+      self.format_markdown(w, doc, markdown, indent + 2)?;
       writeln!(w)
     } else {
       Ok(())
@@ -181,7 +262,15 @@ impl<'a> DocPrinter<'a> {
     indent: i64,
   ) -> FmtResult {
     match tag {
-      JsDocTag::Callback { name, doc } => {
+      JsDocTag::Async => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("async"))
+      }
+      JsDocTag::Callback {
+        name,
+        doc,
+        markdown,
+        ..
+      } => {
         writeln!(
           w,
           "{}@{} {}",
@@ -189,16 +278,42 @@ impl<'a> DocPrinter<'a> {
           colors::magenta("callback"),
           colors::bold(name)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
+      }
+      JsDocTag::Category { name } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("category"),
+          colors::bold(name)
+        )
       }
       JsDocTag::Constructor => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("constructor"))
       }
-      JsDocTag::Deprecated { doc } => {
+      JsDocTag::Custom { name, value } => {
+        writeln!(w, "{}@{} {}", Indent(indent), colors::magenta(name), value)
+      }
+      JsDocTag::Default { value } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("default"),
+          value
+        )
+      }
+      JsDocTag::Deprecated { doc, markdown, .. } => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("deprecated"))?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
-      JsDocTag::Enum { type_ref, doc } => {
+      JsDocTag::Enum {
+        type_ref,
+        doc,
+        markdown,
+        ..
+      } => {
         writeln!(
           w,
           "{}@{} {{{}}}",
@@ -206,13 +321,41 @@ impl<'a> DocPrinter<'a> {
           colors::magenta("enum"),
           colors::italic_cyan(type_ref)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
+      }
+      JsDocTag::Example {
+        caption, blocks, ..
+      } => {
+        write!(w, "{}@{}", Indent(indent), colors::magenta("example"))?;
+        if let Some(caption) = caption {
+          write!(w, " {}", colors::bold(caption))?;
+        }
+        writeln!(w)?;
+        for block in blocks {
+          match block {
+            ExampleBlock::Prose { doc } => {
+              self.format_markdown(w, doc, &[], indent + 2)?;
+            }
+            ExampleBlock::Code(code_block) => {
+              for line in code_block.code.lines() {
+                writeln!(w, "{}{}", Indent(indent + 3), line)?;
+              }
+            }
+          }
+          writeln!(w)?;
+        }
+        Ok(())
       }
-      JsDocTag::Example { doc } => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("example"))?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      JsDocTag::Experimental { doc, markdown, .. } => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("experimental"))?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
-      JsDocTag::Extends { type_ref, doc } => {
+      JsDocTag::Extends {
+        type_ref,
+        doc,
+        markdown,
+        ..
+      } => {
         writeln!(
           w,
           "{}@{} {{{}}}",
@@ -220,22 +363,57 @@ impl<'a> DocPrinter<'a> {
           colors::magenta("extends"),
           colors::italic_cyan(type_ref)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
+      }
+      JsDocTag::Group { name } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("group"),
+          colors::bold(name)
+        )
+      }
+      JsDocTag::Ignore => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("ignore"))
+      }
+      JsDocTag::InheritDoc { target } => {
+        write!(w, "{}@{}", Indent(indent), colors::magenta("inheritDoc"))?;
+        if let Some(target) = target {
+          write!(w, " {}", target)?;
+        }
+        writeln!(w)
+      }
+      JsDocTag::Internal => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("internal"))
       }
       JsDocTag::Module => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("module"))
       }
+      JsDocTag::Override => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("override"))
+      }
       JsDocTag::Param {
         name,
+        optional,
+        default,
         type_ref,
         doc,
+        markdown,
+        ..
       } => {
         write!(w, "{}@{}", Indent(indent), colors::magenta("param"))?;
         if let Some(type_ref) = type_ref {
           write!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
         }
-        writeln!(w, " {}", colors::bold(name))?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        match (optional, default) {
+          (_, Some(default)) => {
+            writeln!(w, " [{}={}]", colors::bold(name), default)?
+          }
+          (true, None) => writeln!(w, " [{}]", colors::bold(name))?,
+          (false, None) => writeln!(w, " {}", colors::bold(name))?,
+        }
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
       JsDocTag::Public => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("public"))
@@ -247,6 +425,8 @@ impl<'a> DocPrinter<'a> {
         name,
         type_ref,
         doc,
+        markdown,
+        ..
       } => {
         writeln!(
           w,
@@ -256,7 +436,7 @@ impl<'a> DocPrinter<'a> {
           colors::italic_cyan(type_ref),
           colors::bold(name)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
       JsDocTag::Protected => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("protected"))
@@ -264,16 +444,53 @@ impl<'a> DocPrinter<'a> {
       JsDocTag::ReadOnly => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("readonly"))
       }
-      JsDocTag::Return { type_ref, doc } => {
+      JsDocTag::Return {
+        type_ref,
+        doc,
+        markdown,
+        ..
+      } => {
         write!(w, "{}@{}", Indent(indent), colors::magenta("return"))?;
         if let Some(type_ref) = type_ref {
           writeln!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
         } else {
           writeln!(w)?;
         }
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
-      JsDocTag::Template { name, doc } => {
+      JsDocTag::See { doc, .. } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("see"),
+          render_inline_tags(doc)
+        )
+      }
+      JsDocTag::Since { version } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("since"),
+          version
+        )
+      }
+      JsDocTag::Tags { tags } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("tags"),
+          tags.join(", ")
+        )
+      }
+      JsDocTag::Template {
+        name,
+        doc,
+        markdown,
+        ..
+      } => {
         writeln!(
           w,
           "{}@{} {}",
@@ -281,9 +498,14 @@ impl<'a> DocPrinter<'a> {
           colors::magenta("template"),
           colors::bold(name)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
-      JsDocTag::This { type_ref, doc } => {
+      JsDocTag::This {
+        type_ref,
+        doc,
+        markdown,
+        ..
+      } => {
         writeln!(
           w,
           "{}@{} {{{}}}",
@@ -291,12 +513,28 @@ impl<'a> DocPrinter<'a> {
           colors::magenta("this"),
           colors::italic_cyan(type_ref)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
+      }
+      JsDocTag::Throws {
+        type_ref,
+        doc,
+        markdown,
+        ..
+      } => {
+        write!(w, "{}@{}", Indent(indent), colors::magenta("throws"))?;
+        if let Some(type_ref) = type_ref {
+          writeln!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
+        } else {
+          writeln!(w)?;
+        }
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
       JsDocTag::TypeDef {
         name,
         type_ref,
         doc,
+        markdown,
+        ..
       } => {
         writeln!(
           w,
@@ -306,9 +544,14 @@ impl<'a> DocPrinter<'a> {
           colors::italic_cyan(type_ref),
           colors::bold(name)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
-      JsDocTag::TypeRef { type_ref, doc } => {
+      JsDocTag::TypeRef {
+        type_ref,
+        doc,
+        markdown,
+        ..
+      } => {
         writeln!(
           w,
           "{}@{} {{{}}}",
@@ -316,7 +559,7 @@ impl<'a> DocPrinter<'a> {
           colors::magenta("typeref"),
           colors::italic_cyan(type_ref)
         )?;
-        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+        self.format_jsdoc_tag_maybe_doc(w, doc, markdown, indent)
       }
       JsDocTag::Unsupported { value } => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta(value))
@@ -326,48 +569,16 @@ impl<'a> DocPrinter<'a> {
 
   fn format_class(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
     let class_def = node.class_def.as_ref().unwrap();
-    for node in &class_def.constructors {
-      writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
-    }
-    for node in class_def.properties.iter().filter(|node| {
-      self.private
-        || node
-          .accessibility
-          .unwrap_or(deno_ast::swc::ast::Accessibility::Public)
-          != deno_ast::swc::ast::Accessibility::Private
-    }) {
-      for d in &node.decorators {
-        writeln!(w, "{}{}", Indent(1), d)?;
-      }
-      writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
-    }
-    for index_sign_def in &class_def.index_signatures {
-      writeln!(w, "{}{}", Indent(1), index_sign_def)?;
-    }
-    for node in class_def.methods.iter().filter(|node| {
-      self.private
-        || node
-          .accessibility
-          .unwrap_or(deno_ast::swc::ast::Accessibility::Public)
-          != deno_ast::swc::ast::Accessibility::Private
-    }) {
-      for d in &node.function_def.decorators {
-        writeln!(w, "{}{}", Indent(1), d)?;
-      }
-      writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
-    }
+    let mut members = MemberPrinter::new(self, w);
+    members.visit_class_def(class_def);
+    members.result?;
     writeln!(w)
   }
 
   fn format_enum(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
-    let enum_def = node.enum_def.as_ref().unwrap();
-    for member in &enum_def.members {
-      writeln!(w, "{}{}", Indent(1), colors::bold(&member.name))?;
-      self.format_jsdoc(w, &member.js_doc, 2)?;
-    }
+    let mut members = MemberPrinter::new(self, w);
+    members.visit_enum_def(node.enum_def.as_ref().unwrap());
+    members.result?;
     writeln!(w)
   }
 
@@ -377,17 +588,46 @@ impl<'a> DocPrinter<'a> {
     node: &DocNode,
   ) -> FmtResult {
     let interface_def = node.interface_def.as_ref().unwrap();
+    let mut members = MemberPrinter::new(self, w);
+    members.visit_interface_def(interface_def);
+    members.result?;
+    writeln!(w)
+  }
 
-    for property_def in &interface_def.properties {
-      writeln!(w, "{}{}", Indent(1), property_def)?;
-      self.format_jsdoc(w, &property_def.js_doc, 2)?;
+  /// Lists the union or intersection members and tuple elements of a type
+  /// alias which have documentation of their own.
+  fn format_type_alias(
+    &self,
+    w: &mut Formatter<'_>,
+    node: &DocNode,
+  ) -> FmtResult {
+    let mut ts_type = &node.type_alias_def.as_ref().unwrap().ts_type;
+    while let TsTypeDefKind::Parenthesized(inner) = &ts_type.kind {
+      ts_type = &**inner;
     }
-    for method_def in &interface_def.methods {
-      writeln!(w, "{}{}", Indent(1), method_def)?;
-      self.format_jsdoc(w, &method_def.js_doc, 2)?;
+    let documented: Vec<(&dyn Display, &JsDoc)> = match &ts_type.kind {
+      TsTypeDefKind::Union(members) | TsTypeDefKind::Intersection(members) => {
+        members
+          .iter()
+          .filter_map(|member| {
+            Some((member as &dyn Display, member.js_doc.as_deref()?))
+          })
+          .collect()
+      }
+      TsTypeDefKind::Tuple(elems) => elems
+        .iter()
+        .filter_map(|elem| {
+          Some((elem as &dyn Display, elem.ts_type.js_doc.as_deref()?))
+        })
+        .collect(),
+      _ => Vec::new(),
+    };
+    if documented.is_empty() {
+      return Ok(());
     }
-    for index_sign_def in &interface_def.index_signatures {
-      writeln!(w, "{}{}", Indent(1), index_sign_def)?;
+    for (member, js_doc) in documented {
+      writeln!(w, "{}{}", Indent(1), member)?;
+      self.format_jsdoc(w, js_doc, 2)?;
     }
     writeln!(w)
   }
@@ -615,6 +855,463 @@ impl<'a> DocPrinter<'a> {
   }
 }
 
+/// Prints the members of a class, enum or interface, one per line and followed
+/// by their JSDoc.
+struct MemberPrinter<'p, 'a, 'f> {
+  printer: &'p DocPrinter<'a>,
+  w: &'p mut Formatter<'f>,
+  result: FmtResult,
+  /// The ancestor whose members are printed next, announced before the first
+  /// of them which is printed.
+  inherited_from: Option<String>,
+}
+
+impl<'p, 'a, 'f> MemberPrinter<'p, 'a, 'f> {
+  fn new(printer: &'p DocPrinter<'a>, w: &'p mut Formatter<'f>) -> Self {
+    Self {
+      printer,
+      w,
+      result: Ok(()),
+      inherited_from: None,
+    }
+  }
+
+  fn print(
+    &mut self,
+    f: impl FnOnce(&DocPrinter, &mut Formatter<'_>) -> FmtResult,
+  ) {
+    if let (Ok(()), Some(name)) = (self.result, self.inherited_from.take()) {
+      self.result = writeln!(
+        self.w,
+        "\n{}{}",
+        Indent(1),
+        colors::italic_gray(&format!("inherited from {}", name))
+      );
+    }
+    if self.result.is_ok() {
+      self.result = f(self.printer, &mut *self.w);
+    }
+  }
+
+  fn is_visible(&self, accessibility: Option<Accessibility>) -> bool {
+    self.printer.private
+      || accessibility.unwrap_or(Accessibility::Public)
+        != Accessibility::Private
+  }
+}
+
+impl DocVisitor for MemberPrinter<'_, '_, '_> {
+  fn visit_enum_member_def(&mut self, member_def: &EnumMemberDef) {
+    self.print(|printer, w| {
+      writeln!(w, "{}{}", Indent(1), colors::bold(&member_def.name))?;
+      printer.format_jsdoc(w, &member_def.js_doc, 2)
+    });
+  }
+
+  fn visit_class_constructor_def(
+    &mut self,
+    constructor_def: &ClassConstructorDef,
+  ) {
+    self.print(|printer, w| {
+      writeln!(w, "{}{}", Indent(1), constructor_def)?;
+      printer.format_jsdoc(w, &constructor_def.js_doc, 2)
+    });
+  }
+
+  fn visit_class_property_def(&mut self, property_def: &ClassPropertyDef) {
+    if !self.is_visible(property_def.accessibility) {
+      return;
+    }
+    self.print(|printer, w| {
+      for d in &property_def.decorators {
+        writeln!(w, "{}{}", Indent(1), d)?;
+      }
+      writeln!(w, "{}{}", Indent(1), property_def)?;
+      printer.format_jsdoc(w, &property_def.js_doc, 2)
+    });
+  }
+
+  fn visit_accessor_def(&mut self, accessor_def: &AccessorDef) {
+    if !self.printer.merge_accessors
+      || !self.is_visible(accessor_def.accessibility)
+    {
+      return;
+    }
+    self.print(|printer, w| {
+      writeln!(w, "{}{}", Indent(1), accessor_def)?;
+      printer.format_jsdoc(w, &accessor_def.js_doc, 2)
+    });
+  }
+
+  fn visit_class_index_signature_def(
+    &mut self,
+    index_signature_def: &ClassIndexSignatureDef,
+  ) {
+    self.print(|_, w| writeln!(w, "{}{}", Indent(1), index_signature_def));
+  }
+
+  fn visit_class_method_def(&mut self, method_def: &ClassMethodDef) {
+    if !self.is_visible(method_def.accessibility)
+      || (self.printer.merge_accessors && is_accessor(method_def.kind))
+    {
+      return;
+    }
+    self.print(|printer, w| {
+      for d in &method_def.function_def.decorators {
+        writeln!(w, "{}{}", Indent(1), d)?;
+      }
+      writeln!(w, "{}{}", Indent(1), method_def)?;
+      printer.format_jsdoc(w, &method_def.js_doc, 2)
+    });
+  }
+
+  fn visit_interface_property_def(
+    &mut self,
+    property_def: &InterfacePropertyDef,
+  ) {
+    self.print(|printer, w| {
+      writeln!(w, "{}{}", Indent(1), property_def)?;
+      printer.format_jsdoc(w, &property_def.js_doc, 2)
+    });
+  }
+
+  fn visit_interface_method_def(&mut self, method_def: &InterfaceMethodDef) {
+    if self.printer.merge_accessors && is_accessor(method_def.kind) {
+      return;
+    }
+    self.print(|printer, w| {
+      writeln!(w, "{}{}", Indent(1), method_def)?;
+      printer.format_jsdoc(w, &method_def.js_doc, 2)
+    });
+  }
+
+  // call signatures are not printed
+  fn visit_interface_call_signature_def(
+    &mut self,
+    _call_signature_def: &InterfaceCallSignatureDef,
+  ) {
+  }
+
+  fn visit_interface_index_signature_def(
+    &mut self,
+    index_signature_def: &InterfaceIndexSignatureDef,
+  ) {
+    self.print(|_, w| writeln!(w, "{}{}", Indent(1), index_signature_def));
+  }
+
+  // the inherited members which are not overridden are printed after the own
+  // members, if they are shown at all
+  fn visit_class_ancestor_def(&mut self, ancestor_def: &ClassAncestorDef) {
+    if !self.printer.show_inherited {
+      return;
+    }
+    self.inherited_from = Some(ancestor_def.name.clone());
+    for property in inherited(&ancestor_def.properties) {
+      self.visit_class_property_def(property);
+    }
+    for accessor in inherited(&ancestor_def.accessors) {
+      self.visit_accessor_def(accessor);
+    }
+    for method in inherited(&ancestor_def.methods) {
+      self.visit_class_method_def(method);
+    }
+  }
+
+  fn visit_interface_ancestor_def(
+    &mut self,
+    ancestor_def: &InterfaceAncestorDef,
+  ) {
+    if !self.printer.show_inherited {
+      return;
+    }
+    self.inherited_from = Some(ancestor_def.name.clone());
+    for property in inherited(&ancestor_def.properties) {
+      self.visit_interface_property_def(property);
+    }
+    for accessor in inherited(&ancestor_def.accessors) {
+      self.visit_accessor_def(accessor);
+    }
+    for method in inherited(&ancestor_def.methods) {
+      self.visit_interface_method_def(method);
+    }
+  }
+
+  // types are part of the signatures, which are printed as a whole
+  fn visit_ts_type_def(&mut self, _ts_type_def: &TsTypeDef) {}
+}
+
+/// Replaces the inline tags of documentation with the bold names of their
+/// targets.
+fn render_inline_tags(doc: &str) -> String {
+  let segments = parse_inline_tags(doc);
+  if segments.is_empty() {
+    return doc.to_string();
+  }
+  let mut rendered = String::new();
+  for segment in segments {
+    match segment {
+      JsDocSegment::Text { value } => rendered.push_str(&value),
+      JsDocSegment::Link { target, .. } => {
+        rendered.push_str(&colors::bold(&target).to_string())
+      }
+      JsDocSegment::InheritDoc { .. } => {}
+    }
+  }
+  rendered
+}
+
+/// Renders Markdown blocks into lines, with the text of paragraphs and
+/// headings wrapped to `width` columns. The blocks are separated by blank
+/// lines.
+fn render_markdown(blocks: &[MarkdownBlock], width: usize) -> Vec<String> {
+  let mut lines = Vec::new();
+  for block in blocks {
+    if !lines.is_empty() {
+      lines.push(String::new());
+    }
+    match block {
+      MarkdownBlock::Paragraph { children } => {
+        lines.extend(wrap_inlines(children, width, TextStyle::default()));
+      }
+      MarkdownBlock::Heading { children, .. } => {
+        let style = TextStyle {
+          strong: true,
+          ..Default::default()
+        };
+        lines.extend(wrap_inlines(children, width, style));
+      }
+      MarkdownBlock::List { start, items } => {
+        for (index, item) in items.iter().enumerate() {
+          let marker = match start {
+            Some(start) => format!("{}. ", *start as usize + index),
+            None => "- ".to_string(),
+          };
+          let item_width = width.saturating_sub(marker.len()).max(width / 2);
+          let item_lines = render_markdown(item, item_width);
+          if item_lines.is_empty() {
+            lines.push(colors::gray(marker.trim_end()).to_string());
+          }
+          for (line_index, line) in item_lines.into_iter().enumerate() {
+            if line_index == 0 {
+              lines.push(format!("{}{}", colors::gray(&marker), line));
+            } else if line.is_empty() {
+              lines.push(line);
+            } else {
+              lines.push(format!("{}{}", " ".repeat(marker.len()), line));
+            }
+          }
+        }
+      }
+      MarkdownBlock::CodeBlock { code, .. } => {
+        for line in code.lines() {
+          if line.is_empty() {
+            lines.push(String::new());
+          } else {
+            lines.push(format!("{}{}", Indent(1), line));
+          }
+        }
+      }
+      MarkdownBlock::BlockQuote { children } => {
+        let quote_width = width.saturating_sub(2).max(width / 2);
+        for line in render_markdown(children, quote_width) {
+          lines.push(format!("{} {}", colors::gray(">"), line));
+        }
+      }
+      MarkdownBlock::ThematicBreak => {
+        lines.push(colors::gray("---").to_string());
+      }
+    }
+  }
+  lines
+}
+
+/// How a part of the text of documentation is printed.
+#[derive(Debug, Default, Clone, Copy)]
+struct TextStyle {
+  strong: bool,
+  emphasis: bool,
+  code: bool,
+}
+
+impl TextStyle {
+  fn paint(self, text: &str) -> String {
+    if self.code {
+      return colors::cyan(text).to_string();
+    }
+    match (self.strong, self.emphasis) {
+      (true, true) => colors::bold_italic(text).to_string(),
+      (true, false) => colors::bold(text).to_string(),
+      (false, true) => colors::italic_gray(text).to_string(),
+      (false, false) => colors::gray(text).to_string(),
+    }
+  }
+}
+
+/// Lays out the words of text into lines of at most `width` columns, except
+/// for words which are longer than that.
+struct LineWrapper {
+  width: usize,
+  lines: Vec<String>,
+  line: String,
+  line_width: usize,
+  word: String,
+  word_width: usize,
+}
+
+impl LineWrapper {
+  fn new(width: usize) -> Self {
+    Self {
+      width,
+      lines: Vec::new(),
+      line: String::new(),
+      line_width: 0,
+      word: String::new(),
+      word_width: 0,
+    }
+  }
+
+  /// Adds text, breaking it into words at its whitespace.
+  fn push_text(&mut self, text: &str, style: TextStyle) {
+    for (index, part) in text.split(char::is_whitespace).enumerate() {
+      if index > 0 {
+        self.end_word();
+      }
+      self.push_unbroken(part, style);
+    }
+  }
+
+  /// Adds text to the current word, without breaking it.
+  fn push_unbroken(&mut self, text: &str, style: TextStyle) {
+    if !text.is_empty() {
+      self.word.push_str(&style.paint(text));
+      self.word_width += text.chars().count();
+    }
+  }
+
+  fn end_word(&mut self) {
+    if self.word_width == 0 {
+      return;
+    }
+    if self.line_width > 0 && self.line_width + 1 + self.word_width > self.width
+    {
+      self.end_line();
+    }
+    if self.line_width > 0 {
+      self.line.push(' ');
+      self.line_width += 1;
+    }
+    self.line.push_str(&std::mem::take(&mut self.word));
+    self.line_width += self.word_width;
+    self.word_width = 0;
+  }
+
+  fn end_line(&mut self) {
+    self.lines.push(std::mem::take(&mut self.line));
+    self.line_width = 0;
+  }
+
+  fn finish(mut self) -> Vec<String> {
+    self.end_word();
+    if self.line_width > 0 {
+      self.end_line();
+    }
+    self.lines
+  }
+}
+
+fn wrap_inlines(
+  inlines: &[MarkdownInline],
+  width: usize,
+  style: TextStyle,
+) -> Vec<String> {
+  let mut wrapper = LineWrapper::new(width);
+  push_inlines(&mut wrapper, inlines, style);
+  wrapper.finish()
+}
+
+fn push_inlines(
+  wrapper: &mut LineWrapper,
+  inlines: &[MarkdownInline],
+  style: TextStyle,
+) {
+  for inline in inlines {
+    match inline {
+      MarkdownInline::Text { value } => wrapper.push_text(value, style),
+      MarkdownInline::Code { value } => wrapper.push_unbroken(
+        value,
+        TextStyle {
+          code: true,
+          ..style
+        },
+      ),
+      MarkdownInline::Emphasis { children } => {
+        let style = TextStyle {
+          emphasis: true,
+          ..style
+        };
+        push_inlines(wrapper, children, style);
+      }
+      MarkdownInline::Strong { children } => {
+        let style = TextStyle {
+          strong: true,
+          ..style
+        };
+        push_inlines(wrapper, children, style);
+      }
+      MarkdownInline::Link { url, children } => {
+        push_inlines(wrapper, children, style);
+        // the URL is printed unless it is the text of the link
+        if !matches!(
+          children.as_slice(),
+          [MarkdownInline::Text { value }] if value == url
+        ) {
+          wrapper.end_word();
+          wrapper.push_unbroken(&format!("({})", url), style);
+        }
+      }
+      MarkdownInline::JsDocLink {
+        target,
+        label,
+        style: link_style,
+        ..
+      } => {
+        let style = match link_style {
+          LinkStyle::Link => TextStyle {
+            strong: true,
+            ..style
+          },
+          LinkStyle::Code => TextStyle {
+            code: true,
+            ..style
+          },
+          LinkStyle::Plain => style,
+        };
+        wrapper.push_text(label.as_deref().unwrap_or(target), style);
+      }
+      MarkdownInline::LineBreak => {
+        wrapper.end_word();
+        wrapper.end_line();
+      }
+    }
+  }
+}
+
+/// The inherited members which are not overridden.
+fn inherited<T>(members: &[InheritedMemberDef<T>]) -> impl Iterator<Item = &T> {
+  members
+    .iter()
+    .filter(|member| !member.overridden)
+    .map(|member| &member.member)
+}
+
+fn is_accessor(kind: deno_ast::swc::ast::MethodKind) -> bool {
+  matches!(
+    kind,
+    deno_ast::swc::ast::MethodKind::Getter
+      | deno_ast::swc::ast::MethodKind::Setter
+  )
+}
+
 impl<'a> Display for DocPrinter<'a> {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     self.format(f)
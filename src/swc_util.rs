@@ -10,44 +10,132 @@ use deno_ast::ParsedSource;
 use regex::Regex;
 
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocComment;
 use crate::js_doc::JsDocTag;
+use crate::js_doc::JsDocTagLine;
 use crate::node::Location;
 
 lazy_static! {
   static ref JS_DOC_RE: Regex = Regex::new(r#"\s*\* ?"#).unwrap();
 }
 
+/// How JSDoc comments are found and parsed, as set on the `DocParser`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsDocOptions {
+  /// See `DocParser::loose_js_doc`.
+  pub loose: bool,
+  /// See `DocParser::js_doc_comments`.
+  pub comments: bool,
+}
+
 pub(crate) fn is_false(b: &bool) -> bool {
   !b
 }
 
-fn parse_js_doc(js_doc_comment: &Comment) -> JsDoc {
+fn parse_js_doc(
+  parsed_source: &ParsedSource,
+  js_doc_comment: &Comment,
+  options: JsDocOptions,
+) -> JsDoc {
   let txt = js_doc_comment
     .text
     .split('\n')
     .map(|line| JS_DOC_RE.replace(line, "").to_string())
     .collect::<Vec<String>>()
-    .join("\n")
-    .trim()
-    .to_string();
-  txt.into()
+    .join("\n");
+  let trimmed = txt.trim();
+  // the lines of the tags are counted from the `/**`, rather than from the
+  // first line which isn't blank
+  let leading_lines = txt[..txt.len() - txt.trim_start().len()]
+    .matches('\n')
+    .count();
+  let (mut js_doc, tag_lines) = JsDoc::parse_with_tag_lines(trimmed);
+  if !options.comments {
+    return js_doc;
+  }
+  let source_start = parsed_source.source().span().lo;
+  js_doc.comment = Some(JsDocComment {
+    location: get_location(parsed_source, js_doc_comment.span.lo),
+    start: (js_doc_comment.span.lo - source_start).0 as usize,
+    end: (js_doc_comment.span.hi - source_start).0 as usize,
+    text: parsed_source
+      .source()
+      .span_text(&js_doc_comment.span)
+      .to_string(),
+    tag_lines: tag_lines
+      .into_iter()
+      .map(|tag_line| JsDocTagLine {
+        line: tag_line.line + leading_lines,
+        ..tag_line
+      })
+      .collect(),
+  });
+  js_doc
 }
 
 pub(crate) fn js_doc_for_span(
   parsed_source: &ParsedSource,
   span: &Span,
+  options: JsDocOptions,
+) -> JsDoc {
+  js_doc_for_pos(parsed_source, span.lo(), options)
+}
+
+/// Returns the JSDoc comment among the leading comments of the token starting
+/// at `pos`.
+///
+/// The comment must be the last JSDoc comment before the token, separated from
+/// it by no blank lines, only by other comments, and must not be the module
+/// doc of the source. If `options.loose` is set, the last JSDoc comment is
+/// returned regardless.
+pub(crate) fn js_doc_for_pos(
+  parsed_source: &ParsedSource,
+  pos: BytePos,
+  options: JsDocOptions,
 ) -> JsDoc {
   let comments = parsed_source
     .comments()
-    .get_leading(span.lo())
+    .get_leading(pos)
     .unwrap_or_default();
-  if let Some(js_doc_comment) = comments.iter().rev().find(|comment| {
-    comment.kind == CommentKind::Block && comment.text.starts_with('*')
-  }) {
-    parse_js_doc(js_doc_comment)
-  } else {
-    JsDoc::default()
+  if options.loose {
+    return match comments.iter().rev().find(|comment| is_js_doc(comment)) {
+      Some(js_doc_comment) => {
+        parse_js_doc(parsed_source, js_doc_comment, options)
+      }
+      None => JsDoc::default(),
+    };
   }
+
+  let mut end = pos;
+  for comment in comments.iter().rev() {
+    let between = parsed_source.source().span_text(&Span::new(
+      comment.span.hi,
+      end,
+      Default::default(),
+    ));
+    if between.matches('\n').count() > 1 {
+      break;
+    }
+    if is_js_doc(comment) {
+      // only the leading comments of the source can be the module doc
+      let is_module_doc = parsed_source
+        .get_leading_comments()
+        .iter()
+        .any(|leading| leading.span == comment.span)
+        && module_js_doc_for_source(parsed_source, JsDocOptions::default())
+          .map_or(false, |(_, span)| span == comment.span);
+      if is_module_doc {
+        break;
+      }
+      return parse_js_doc(parsed_source, comment, options);
+    }
+    end = comment.span.lo;
+  }
+  JsDoc::default()
+}
+
+fn is_js_doc(comment: &Comment) -> bool {
+  comment.kind == CommentKind::Block && comment.text.starts_with('*')
 }
 
 /// Inspects leading comments in the source and returns the first JSDoc comment
@@ -55,12 +143,13 @@ pub(crate) fn js_doc_for_span(
 /// `None`.
 pub(crate) fn module_js_doc_for_source(
   parsed_source: &ParsedSource,
+  options: JsDocOptions,
 ) -> Option<(JsDoc, Span)> {
   let comments = parsed_source.get_leading_comments();
-  if let Some(js_doc_comment) = comments.iter().find(|comment| {
-    comment.kind == CommentKind::Block && comment.text.starts_with('*')
-  }) {
-    let leading_js_doc = parse_js_doc(js_doc_comment);
+  if let Some(js_doc_comment) =
+    comments.iter().find(|comment| is_js_doc(comment))
+  {
+    let leading_js_doc = parse_js_doc(parsed_source, js_doc_comment, options);
     if leading_js_doc
       .tags
       .iter()
@@ -0,0 +1,391 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use serde::ser;
+use serde::Serialize;
+use serde::Serializer;
+
+/// The name `TsTypeDef` serializes its struct with, which the tagged JSON
+/// serializer recognizes type definitions by.
+pub(crate) const TS_TYPE_DEF_NAME: &str = "TsTypeDef";
+
+/// The fields of a type definition other than the payload of its kind.
+const TS_TYPE_DEF_FIELDS: &[&str] = &["repr", "kind", "jsDoc", "inferred"];
+
+/// Serializes a value with its type definitions in the tagged JSON shape,
+/// where the payload of every kind is found under `value`:
+///
+/// ```json
+/// { "repr": "string", "kind": "keyword", "value": "string" }
+/// ```
+///
+/// Type definitions otherwise serialize to the flat JSON shape, where the
+/// payload is found under a property named after the kind:
+///
+/// ```json
+/// { "repr": "string", "kind": "keyword", "keyword": "string" }
+/// ```
+///
+/// Both shapes are accepted when deserializing.
+pub struct TaggedJson<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Serialize + ?Sized> Serialize for TaggedJson<'_, T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.0.serialize(TaggedSerializer(serializer))
+  }
+}
+
+/// Forwards to the wrapped serializer, serializing the nested values with a
+/// `TaggedSerializer` in turn and the payloads of type definitions under
+/// `value`.
+struct TaggedSerializer<S>(S);
+
+/// Forwards to a wrapped compound serializer, serializing the nested values
+/// with a `TaggedSerializer`.
+struct Tagged<C>(C);
+
+/// Forwards to a wrapped struct serializer, renaming the payload of the kind
+/// if the struct is a type definition.
+struct TaggedStruct<C> {
+  inner: C,
+  is_ts_type_def: bool,
+}
+
+impl<S: Serializer> Serializer for TaggedSerializer<S> {
+  type Ok = S::Ok;
+  type Error = S::Error;
+  type SerializeSeq = Tagged<S::SerializeSeq>;
+  type SerializeTuple = Tagged<S::SerializeTuple>;
+  type SerializeTupleStruct = Tagged<S::SerializeTupleStruct>;
+  type SerializeTupleVariant = Tagged<S::SerializeTupleVariant>;
+  type SerializeMap = Tagged<S::SerializeMap>;
+  type SerializeStruct = TaggedStruct<S::SerializeStruct>;
+  type SerializeStructVariant = Tagged<S::SerializeStructVariant>;
+
+  fn serialize_bool(self, v: bool) -> Result<S::Ok, S::Error> {
+    self.0.serialize_bool(v)
+  }
+
+  fn serialize_i8(self, v: i8) -> Result<S::Ok, S::Error> {
+    self.0.serialize_i8(v)
+  }
+
+  fn serialize_i16(self, v: i16) -> Result<S::Ok, S::Error> {
+    self.0.serialize_i16(v)
+  }
+
+  fn serialize_i32(self, v: i32) -> Result<S::Ok, S::Error> {
+    self.0.serialize_i32(v)
+  }
+
+  fn serialize_i64(self, v: i64) -> Result<S::Ok, S::Error> {
+    self.0.serialize_i64(v)
+  }
+
+  fn serialize_i128(self, v: i128) -> Result<S::Ok, S::Error> {
+    self.0.serialize_i128(v)
+  }
+
+  fn serialize_u8(self, v: u8) -> Result<S::Ok, S::Error> {
+    self.0.serialize_u8(v)
+  }
+
+  fn serialize_u16(self, v: u16) -> Result<S::Ok, S::Error> {
+    self.0.serialize_u16(v)
+  }
+
+  fn serialize_u32(self, v: u32) -> Result<S::Ok, S::Error> {
+    self.0.serialize_u32(v)
+  }
+
+  fn serialize_u64(self, v: u64) -> Result<S::Ok, S::Error> {
+    self.0.serialize_u64(v)
+  }
+
+  fn serialize_u128(self, v: u128) -> Result<S::Ok, S::Error> {
+    self.0.serialize_u128(v)
+  }
+
+  fn serialize_f32(self, v: f32) -> Result<S::Ok, S::Error> {
+    self.0.serialize_f32(v)
+  }
+
+  fn serialize_f64(self, v: f64) -> Result<S::Ok, S::Error> {
+    self.0.serialize_f64(v)
+  }
+
+  fn serialize_char(self, v: char) -> Result<S::Ok, S::Error> {
+    self.0.serialize_char(v)
+  }
+
+  fn serialize_str(self, v: &str) -> Result<S::Ok, S::Error> {
+    self.0.serialize_str(v)
+  }
+
+  fn serialize_bytes(self, v: &[u8]) -> Result<S::Ok, S::Error> {
+    self.0.serialize_bytes(v)
+  }
+
+  fn serialize_none(self) -> Result<S::Ok, S::Error> {
+    self.0.serialize_none()
+  }
+
+  fn serialize_some<T: Serialize + ?Sized>(
+    self,
+    value: &T,
+  ) -> Result<S::Ok, S::Error> {
+    self.0.serialize_some(&TaggedJson(value))
+  }
+
+  fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+    self.0.serialize_unit()
+  }
+
+  fn serialize_unit_struct(
+    self,
+    name: &'static str,
+  ) -> Result<S::Ok, S::Error> {
+    self.0.serialize_unit_struct(name)
+  }
+
+  fn serialize_unit_variant(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+  ) -> Result<S::Ok, S::Error> {
+    self.0.serialize_unit_variant(name, variant_index, variant)
+  }
+
+  fn serialize_newtype_struct<T: Serialize + ?Sized>(
+    self,
+    name: &'static str,
+    value: &T,
+  ) -> Result<S::Ok, S::Error> {
+    self.0.serialize_newtype_struct(name, &TaggedJson(value))
+  }
+
+  fn serialize_newtype_variant<T: Serialize + ?Sized>(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+    value: &T,
+  ) -> Result<S::Ok, S::Error> {
+    self.0.serialize_newtype_variant(
+      name,
+      variant_index,
+      variant,
+      &TaggedJson(value),
+    )
+  }
+
+  fn serialize_seq(
+    self,
+    len: Option<usize>,
+  ) -> Result<Self::SerializeSeq, S::Error> {
+    Ok(Tagged(self.0.serialize_seq(len)?))
+  }
+
+  fn serialize_tuple(
+    self,
+    len: usize,
+  ) -> Result<Self::SerializeTuple, S::Error> {
+    Ok(Tagged(self.0.serialize_tuple(len)?))
+  }
+
+  fn serialize_tuple_struct(
+    self,
+    name: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeTupleStruct, S::Error> {
+    Ok(Tagged(self.0.serialize_tuple_struct(name, len)?))
+  }
+
+  fn serialize_tuple_variant(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeTupleVariant, S::Error> {
+    Ok(Tagged(self.0.serialize_tuple_variant(
+      name,
+      variant_index,
+      variant,
+      len,
+    )?))
+  }
+
+  fn serialize_map(
+    self,
+    len: Option<usize>,
+  ) -> Result<Self::SerializeMap, S::Error> {
+    Ok(Tagged(self.0.serialize_map(len)?))
+  }
+
+  fn serialize_struct(
+    self,
+    name: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeStruct, S::Error> {
+    Ok(TaggedStruct {
+      inner: self.0.serialize_struct(name, len)?,
+      is_ts_type_def: name == TS_TYPE_DEF_NAME,
+    })
+  }
+
+  fn serialize_struct_variant(
+    self,
+    name: &'static str,
+    variant_index: u32,
+    variant: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeStructVariant, S::Error> {
+    Ok(Tagged(self.0.serialize_struct_variant(
+      name,
+      variant_index,
+      variant,
+      len,
+    )?))
+  }
+
+  fn is_human_readable(&self) -> bool {
+    self.0.is_human_readable()
+  }
+}
+
+impl<C: ser::SerializeSeq> ser::SerializeSeq for Tagged<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_element<T: Serialize + ?Sized>(
+    &mut self,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_element(&TaggedJson(value))
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.0.end()
+  }
+}
+
+impl<C: ser::SerializeTuple> ser::SerializeTuple for Tagged<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_element<T: Serialize + ?Sized>(
+    &mut self,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_element(&TaggedJson(value))
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.0.end()
+  }
+}
+
+impl<C: ser::SerializeTupleStruct> ser::SerializeTupleStruct for Tagged<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_field<T: Serialize + ?Sized>(
+    &mut self,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_field(&TaggedJson(value))
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.0.end()
+  }
+}
+
+impl<C: ser::SerializeTupleVariant> ser::SerializeTupleVariant for Tagged<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_field<T: Serialize + ?Sized>(
+    &mut self,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_field(&TaggedJson(value))
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.0.end()
+  }
+}
+
+impl<C: ser::SerializeMap> ser::SerializeMap for Tagged<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_key<T: Serialize + ?Sized>(
+    &mut self,
+    key: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_key(&TaggedJson(key))
+  }
+
+  fn serialize_value<T: Serialize + ?Sized>(
+    &mut self,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_value(&TaggedJson(value))
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.0.end()
+  }
+}
+
+impl<C: ser::SerializeStructVariant> ser::SerializeStructVariant for Tagged<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_field<T: Serialize + ?Sized>(
+    &mut self,
+    key: &'static str,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    self.0.serialize_field(key, &TaggedJson(value))
+  }
+
+  fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+    self.0.skip_field(key)
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.0.end()
+  }
+}
+
+impl<C: ser::SerializeStruct> ser::SerializeStruct for TaggedStruct<C> {
+  type Ok = C::Ok;
+  type Error = C::Error;
+
+  fn serialize_field<T: Serialize + ?Sized>(
+    &mut self,
+    key: &'static str,
+    value: &T,
+  ) -> Result<(), C::Error> {
+    if self.is_ts_type_def && !TS_TYPE_DEF_FIELDS.contains(&key) {
+      // the tagged shape has no payload for `this`
+      if key == "this" {
+        return Ok(());
+      }
+      return self.inner.serialize_field("value", &TaggedJson(value));
+    }
+    self.inner.serialize_field(key, &TaggedJson(value))
+  }
+
+  fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+    self.inner.skip_field(key)
+  }
+
+  fn end(self) -> Result<C::Ok, C::Error> {
+    self.inner.end()
+  }
+}
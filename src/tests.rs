@@ -1,7 +1,21 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use crate::category::GroupBy;
+use crate::custom_tag::TagShape;
+use crate::js_doc::JsDocSegment;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::param_docs::UnknownParam;
 use crate::parser::DocParser;
 use crate::printer::DocPrinter;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TypeRefResolution;
+use crate::visit::walk_ts_type_def;
+use crate::visit::walk_ts_type_def_mut;
+use crate::visit::DocVisitor;
+use crate::visit::DocVisitorMut;
+use deno_ast::swc::ast::Accessibility;
 use deno_graph::create_type_graph;
 use deno_graph::source::MemoryLoader;
 use deno_graph::source::Source;
@@ -582,6 +596,1900 @@ export namespace Deno {
   assert_eq!(found.len(), 0);
 }
 
+#[tokio::test]
+async fn ts_type_tagged_json() {
+  let source_code = r#"
+export type A = string[] | this;
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+  let ts_type = &entries[0].type_alias_def.as_ref().unwrap().ts_type;
+
+  let flat = serde_json::to_value(ts_type).unwrap();
+  assert_eq!(
+    flat,
+    json!({
+      "repr": "",
+      "kind": "union",
+      "union": [
+        {
+          "repr": "",
+          "kind": "array",
+          "array": {
+            "repr": "string",
+            "kind": "keyword",
+            "keyword": "string"
+          }
+        },
+        {
+          "repr": "this",
+          "kind": "this",
+          "this": true
+        }
+      ]
+    })
+  );
+
+  let tagged = serde_json::to_value(crate::TaggedJson(ts_type)).unwrap();
+  assert_eq!(
+    tagged,
+    json!({
+      "repr": "",
+      "kind": "union",
+      "value": [
+        {
+          "repr": "",
+          "kind": "array",
+          "value": {
+            "repr": "string",
+            "kind": "keyword",
+            "value": "string"
+          }
+        },
+        {
+          "repr": "this",
+          "kind": "this"
+        }
+      ]
+    })
+  );
+  assert_eq!(serde_json::to_value(ts_type).unwrap(), flat);
+
+  for value in [flat, tagged] {
+    let ts_type_def: TsTypeDef = serde_json::from_value(value).unwrap();
+    assert_eq!(&ts_type_def, ts_type);
+  }
+
+  // values which only look like type definitions are left as they are
+  let custom_tag = JsDocTag::Custom {
+    name: "shape".to_string(),
+    value: json!({ "repr": "", "kind": "keyword", "keyword": "string" }),
+  };
+  assert_eq!(
+    serde_json::to_value(crate::TaggedJson(&custom_tag)).unwrap(),
+    serde_json::to_value(&custom_tag).unwrap()
+  );
+}
+
+#[tokio::test]
+async fn visit_doc_nodes() {
+  struct TypeRefCollector(Vec<String>);
+
+  impl DocVisitor for TypeRefCollector {
+    fn visit_ts_type_def(&mut self, ts_type_def: &TsTypeDef) {
+      if let TsTypeDefKind::TypeRef(type_ref) = &ts_type_def.kind {
+        self.0.push(type_ref.type_name.clone());
+      }
+      walk_ts_type_def(self, ts_type_def);
+    }
+  }
+
+  struct TypeRefRenamer;
+
+  impl DocVisitorMut for TypeRefRenamer {
+    fn visit_ts_type_def_mut(&mut self, ts_type_def: &mut TsTypeDef) {
+      if let TsTypeDefKind::TypeRef(type_ref) = &mut ts_type_def.kind {
+        type_ref.type_name = format!("Renamed{}", type_ref.type_name);
+      }
+      walk_ts_type_def_mut(self, ts_type_def);
+    }
+  }
+
+  let source_code = r#"
+export class A<T extends B = C> implements D<E> {
+  prop: G | [H];
+  constructor(a: I, { b }: { b: J }) {}
+  method(...args: K[]): Promise<L> {}
+}
+
+export interface N {
+  call<O>(o: O): (p: P) => Q;
+  [key: string]: R;
+}
+
+export namespace S {
+  export type T = { u: U } extends V ? W : X;
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+
+  let mut collector = TypeRefCollector(Vec::new());
+  for doc_node in &entries {
+    collector.visit_doc_node(doc_node);
+  }
+  assert_eq!(
+    collector.0,
+    vec![
+      "B", "C", "D", "E", "I", "J", "G", "H", "K", "Promise", "L", "O", "P",
+      "Q", "R", "U", "V", "W", "X",
+    ]
+  );
+
+  for doc_node in &mut entries {
+    TypeRefRenamer.visit_doc_node_mut(doc_node);
+  }
+  let mut collector = TypeRefCollector(Vec::new());
+  for doc_node in &entries {
+    collector.visit_doc_node(doc_node);
+  }
+  assert!(collector.0.iter().all(|name| name.starts_with("Renamed")));
+  assert_eq!(collector.0.len(), 19);
+}
+
+#[tokio::test]
+async fn resolve_type_refs() {
+  let test_source_code = r#"
+import { Foo } from "./foo.ts";
+import * as ns from "./ns.ts";
+
+export interface A<T> {
+  foo: Foo;
+  bar: ns.Bar;
+  own: B;
+  param: T;
+  global: Promise<string>;
+}
+
+export interface B {}
+
+export namespace N {
+  export interface C {}
+  export type D = C;
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![
+      ("file:///test.ts", None, test_source_code),
+      (
+        "file:///foo.ts",
+        None,
+        r#"export { Foo } from "./impl.ts";"#,
+      ),
+      ("file:///impl.ts", None, "export class Foo {}"),
+      ("file:///ns.ts", None, "export type Bar = string;"),
+    ],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_type_refs(&mut entries);
+
+  let resolution = |ts_type: &TsTypeDef| match &ts_type.kind {
+    TsTypeDefKind::TypeRef(type_ref) => type_ref.resolution.clone().unwrap(),
+    _ => unreachable!(),
+  };
+  let symbol =
+    |specifier: &str, symbol_path: &[&str]| TypeRefResolution::Symbol {
+      specifier: specifier.to_string(),
+      symbol_path: symbol_path.iter().map(|s| s.to_string()).collect(),
+      hidden: false,
+    };
+
+  let interface_def = entries
+    .iter()
+    .find(|node| node.name == "A")
+    .unwrap()
+    .interface_def
+    .as_ref()
+    .unwrap();
+  let property_resolution = |name: &str| {
+    let property = interface_def
+      .properties
+      .iter()
+      .find(|property| property.name == name)
+      .unwrap();
+    resolution(property.ts_type.as_ref().unwrap())
+  };
+  assert_eq!(
+    property_resolution("foo"),
+    symbol("file:///impl.ts", &["Foo"])
+  );
+  assert_eq!(
+    property_resolution("bar"),
+    symbol("file:///ns.ts", &["Bar"])
+  );
+  assert_eq!(
+    property_resolution("own"),
+    symbol("file:///test.ts", &["B"])
+  );
+  assert_eq!(property_resolution("param"), TypeRefResolution::TypeParam);
+  assert_eq!(property_resolution("global"), TypeRefResolution::Unresolved);
+
+  let namespace_def = entries
+    .iter()
+    .find(|node| node.name == "N")
+    .unwrap()
+    .namespace_def
+    .as_ref()
+    .unwrap();
+  let type_alias_def = namespace_def
+    .elements
+    .iter()
+    .find(|node| node.name == "D")
+    .unwrap()
+    .type_alias_def
+    .as_ref()
+    .unwrap();
+  assert_eq!(
+    resolution(&type_alias_def.ts_type),
+    symbol("file:///test.ts", &["N", "C"])
+  );
+
+  assert_eq!(
+    serde_json::to_value(property_resolution("foo")).unwrap(),
+    json!({
+      "kind": "symbol",
+      "specifier": "file:///impl.ts",
+      "symbolPath": ["Foo"]
+    })
+  );
+}
+
+#[tokio::test]
+async fn inherited_members() {
+  let base_source_code = r#"
+export class Base {
+  /** The base name. */
+  name: string;
+  greet(): string {}
+  static create(): Base {}
+}
+
+export interface BaseOptions {
+  verbose: boolean;
+  depth: number;
+}
+"#;
+  let test_source_code = r#"
+import { Base, BaseOptions } from "./base.ts";
+
+class Middle extends Base {
+  greet(): string {}
+}
+
+export class Derived extends Middle {
+  extra: number;
+}
+
+export interface Options extends BaseOptions {
+  depth: number;
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![
+      ("file:///test.ts", None, test_source_code),
+      ("file:///base.ts", None, base_source_code),
+    ],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_inherited_members(&mut entries);
+
+  let class_def = entries
+    .iter()
+    .find(|node| node.name == "Derived")
+    .unwrap()
+    .class_def
+    .as_ref()
+    .unwrap();
+  assert_eq!(
+    serde_json::to_value(&class_def.inherited).unwrap(),
+    json!([
+      {
+        "name": "Middle",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 4,
+          "col": 0
+        },
+        "properties": [],
+        "methods": [
+          {
+            "accessibility": null,
+            "optional": false,
+            "isAbstract": false,
+            "isStatic": false,
+            "name": "greet",
+            "kind": "method",
+            "functionDef": {
+              "params": [],
+              "returnType": {
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string"
+              },
+              "isAsync": false,
+              "isGenerator": false,
+              "typeParams": []
+            },
+            "location": {
+              "filename": "file:///test.ts",
+              "line": 5,
+              "col": 2
+            }
+          }
+        ]
+      },
+      {
+        "name": "Base",
+        "location": {
+          "filename": "file:///base.ts",
+          "line": 2,
+          "col": 0
+        },
+        "properties": [
+          {
+            "jsDoc": {
+              "doc": "The base name."
+            },
+            "tsType": {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string"
+            },
+            "readonly": false,
+            "accessibility": null,
+            "optional": false,
+            "isAbstract": false,
+            "isStatic": false,
+            "name": "name",
+            "location": {
+              "filename": "file:///base.ts",
+              "line": 4,
+              "col": 2
+            }
+          }
+        ],
+        "methods": [
+          {
+            "accessibility": null,
+            "optional": false,
+            "isAbstract": false,
+            "isStatic": false,
+            "name": "greet",
+            "kind": "method",
+            "functionDef": {
+              "params": [],
+              "returnType": {
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string"
+              },
+              "isAsync": false,
+              "isGenerator": false,
+              "typeParams": []
+            },
+            "location": {
+              "filename": "file:///base.ts",
+              "line": 5,
+              "col": 2
+            },
+            "overridden": true
+          },
+          {
+            "accessibility": null,
+            "optional": false,
+            "isAbstract": false,
+            "isStatic": true,
+            "name": "create",
+            "kind": "method",
+            "functionDef": {
+              "params": [],
+              "returnType": {
+                "repr": "Base",
+                "kind": "typeRef",
+                "typeRef": {
+                  "typeParams": null,
+                  "typeName": "Base"
+                }
+              },
+              "isAsync": false,
+              "isGenerator": false,
+              "typeParams": []
+            },
+            "location": {
+              "filename": "file:///base.ts",
+              "line": 6,
+              "col": 2
+            }
+          }
+        ]
+      }
+    ])
+  );
+
+  let interface_def = entries
+    .iter()
+    .find(|node| node.name == "Options")
+    .unwrap()
+    .interface_def
+    .as_ref()
+    .unwrap();
+  assert_eq!(interface_def.inherited.len(), 1);
+  let ancestor = &interface_def.inherited[0];
+  assert_eq!(ancestor.name, "BaseOptions");
+  let properties = ancestor
+    .properties
+    .iter()
+    .map(|property| (property.member.name.as_str(), property.overridden))
+    .collect::<Vec<_>>();
+  assert_eq!(properties, vec![("verbose", false), ("depth", true)]);
+
+  let mut printer = DocPrinter::new(&entries, false, false);
+  let doc = printer.to_string();
+  assert!(!doc.contains("inherited from"));
+
+  printer.show_inherited = true;
+  let doc = printer.to_string();
+  assert!(doc.contains("inherited from Middle"));
+  assert!(doc.contains("inherited from Base"));
+  assert!(doc.contains("The base name."));
+  assert!(doc.contains("static create(): Base"));
+  assert!(doc.contains("inherited from BaseOptions"));
+  assert!(doc.contains("verbose: boolean"));
+}
+
+#[tokio::test]
+async fn inline_links() {
+  let source_code = r#"
+/**
+ * A {@link Foo} built with {@link https://deno.land | Deno} and
+ * {@linkcode Unknown}.
+ */
+export class Bar {}
+
+export class Foo {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_type_refs(&mut entries);
+
+  let bar = entries.iter().find(|node| node.name == "Bar").unwrap();
+  let links = bar
+    .js_doc
+    .segments
+    .iter()
+    .filter_map(|segment| match segment {
+      JsDocSegment::Link {
+        target, resolution, ..
+      } => Some((target.as_str(), resolution.clone())),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    links,
+    vec![
+      (
+        "Foo",
+        Some(TypeRefResolution::Symbol {
+          specifier: specifier.to_string(),
+          symbol_path: vec!["Foo".to_string()],
+          hidden: false,
+        })
+      ),
+      ("https://deno.land", None),
+      ("Unknown", Some(TypeRefResolution::Unresolved)),
+    ]
+  );
+
+  let doc = DocPrinter::new(&entries, false, false).to_string();
+  assert!(doc.contains("A Foo built with https://deno.land and Unknown.\n"));
+  assert!(!doc.contains("{@link"));
+}
+
+#[tokio::test]
+async fn hidden_symbols() {
+  let source_code = r#"
+export { Reexported, Internal as Aliased } from "./other.ts";
+
+/** @ignore */
+export class Ignored {}
+
+/** @internal */
+export interface Secret {}
+
+export type Alias = Secret;
+
+export interface Options {
+  /** @internal */
+  secret: string;
+  visible: string;
+}
+
+export class Client {
+  /** @ignore */
+  debug(): void {}
+  connect(): void {}
+}
+
+export namespace ns {
+  /** @internal */
+  export function helper(): void {}
+  export function run(): void {}
+}
+"#;
+  let other_source_code = r#"
+/** @ignore */
+export class Reexported {}
+
+/** @internal */
+export class Internal {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![
+      ("file:///test.ts", None, source_code),
+      ("file:///other.ts", None, other_source_code),
+    ],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut doc_parser = DocParser::new(graph, false, &source_parser);
+
+  let names = |entries: &[DocNode]| {
+    entries
+      .iter()
+      .map(|node| node.name.clone())
+      .collect::<Vec<_>>()
+  };
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(
+    names(&entries),
+    vec!["Aliased", "Secret", "Alias", "Options", "Client", "ns"]
+  );
+  let client = entries.iter().find(|node| node.name == "Client").unwrap();
+  let methods = &client.class_def.as_ref().unwrap().methods;
+  assert_eq!(methods.len(), 1);
+  assert_eq!(methods[0].name, "connect");
+  let options = entries.iter().find(|node| node.name == "Options").unwrap();
+  assert_eq!(options.interface_def.as_ref().unwrap().properties.len(), 2);
+
+  doc_parser.strip_internal = true;
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_type_refs(&mut entries);
+  assert_eq!(names(&entries), vec!["Alias", "Options", "Client", "ns"]);
+  let options = entries.iter().find(|node| node.name == "Options").unwrap();
+  let properties = &options.interface_def.as_ref().unwrap().properties;
+  assert_eq!(properties.len(), 1);
+  assert_eq!(properties[0].name, "visible");
+  let ns = entries.iter().find(|node| node.name == "ns").unwrap();
+  assert_eq!(
+    names(&ns.namespace_def.as_ref().unwrap().elements),
+    vec!["run"]
+  );
+
+  // references to hidden symbols are kept, but flagged
+  let alias = entries.iter().find(|node| node.name == "Alias").unwrap();
+  match &alias.type_alias_def.as_ref().unwrap().ts_type.kind {
+    TsTypeDefKind::TypeRef(type_ref) => assert_eq!(
+      type_ref.resolution,
+      Some(TypeRefResolution::Symbol {
+        specifier: specifier.to_string(),
+        symbol_path: vec!["Secret".to_string()],
+        hidden: true,
+      })
+    ),
+    kind => panic!("unexpected type: {:?}", kind),
+  }
+}
+
+#[tokio::test]
+async fn strip_internal_enum_members() {
+  let source_code = r#"
+export enum Mode {
+  Release,
+  /** @internal */
+  Debug,
+  /** @ignore */
+  Legacy,
+}
+
+export type DebugMode = Mode.Debug;
+export type ReleaseMode = Mode.Release;
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut doc_parser = DocParser::new(graph, false, &source_parser);
+  doc_parser.strip_internal = true;
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_type_refs(&mut entries);
+
+  let mode = entries.iter().find(|node| node.name == "Mode").unwrap();
+  let members = &mode.enum_def.as_ref().unwrap().members;
+  assert_eq!(members.len(), 1);
+  assert_eq!(members[0].name, "Release");
+
+  let hidden = |name: &str| {
+    let alias = entries.iter().find(|node| node.name == name).unwrap();
+    match &alias.type_alias_def.as_ref().unwrap().ts_type.kind {
+      TsTypeDefKind::TypeRef(type_ref) => match &type_ref.resolution {
+        Some(TypeRefResolution::Symbol { hidden, .. }) => *hidden,
+        resolution => panic!("unexpected resolution: {:?}", resolution),
+      },
+      kind => panic!("unexpected type: {:?}", kind),
+    }
+  };
+  assert!(hidden("DebugMode"));
+  assert!(!hidden("ReleaseMode"));
+}
+
+#[tokio::test]
+async fn strip_internal_accessor_halves() {
+  let source_code = r#"
+export class Client {
+  /** @internal */
+  get timeout(): number {}
+  set timeout(value: number) {}
+  /** @internal */
+  get secret(): string {}
+}
+
+export interface Options {
+  get name(): string;
+  /** @internal */
+  set name(value: string);
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut doc_parser = DocParser::new(graph, false, &source_parser);
+  doc_parser.strip_internal = true;
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+
+  let client = entries.iter().find(|node| node.name == "Client").unwrap();
+  let accessors = &client.class_def.as_ref().unwrap().accessors;
+  assert_eq!(accessors.len(), 1);
+  assert_eq!(accessors[0].name, "timeout");
+  assert!(accessors[0].write_only);
+  assert!(!accessors[0].readonly);
+
+  let options = entries.iter().find(|node| node.name == "Options").unwrap();
+  let accessors = &options.interface_def.as_ref().unwrap().accessors;
+  assert_eq!(accessors.len(), 1);
+  assert_eq!(accessors[0].name, "name");
+  assert!(accessors[0].readonly);
+  assert!(!accessors[0].write_only);
+}
+
+#[tokio::test]
+async fn group_by_category() {
+  let source_code = r#"
+/**
+ * @category Streams
+ * @tags unstable
+ */
+export class Writer {}
+
+/** @category IO */
+export function read(): void {}
+
+export const version = "1.0.0";
+
+/**
+ * @category Streams
+ * @tags allow-read, unstable
+ */
+export function pipe(): void {}
+
+/**
+ * @category IO
+ * @category Streams
+ * @category IO
+ */
+export function copy(): void {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+
+  let groups = |group_by| {
+    crate::group_by_category(&entries, group_by)
+      .into_iter()
+      .map(|category| {
+        let names = category
+          .doc_nodes
+          .iter()
+          .map(|node| node.name.clone())
+          .collect::<Vec<_>>();
+        (category.name, names)
+      })
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(
+    groups(GroupBy::Category),
+    vec![
+      (
+        Some("IO".to_string()),
+        vec!["read".to_string(), "copy".to_string()]
+      ),
+      (
+        Some("Streams".to_string()),
+        vec!["Writer".to_string(), "pipe".to_string(), "copy".to_string()]
+      ),
+      (None, vec!["version".to_string()]),
+    ]
+  );
+  assert_eq!(
+    groups(GroupBy::Tags),
+    vec![
+      (Some("allow-read".to_string()), vec!["pipe".to_string()]),
+      (
+        Some("unstable".to_string()),
+        vec!["Writer".to_string(), "pipe".to_string()]
+      ),
+      (
+        None,
+        vec![
+          "read".to_string(),
+          "version".to_string(),
+          "copy".to_string()
+        ]
+      ),
+    ]
+  );
+
+  let mut printer = DocPrinter::new(&entries, false, false);
+  printer.group_by = Some(GroupBy::Category);
+  let doc = printer.to_string();
+  let io = doc.find("IO\n").unwrap();
+  let streams = doc.find("Streams\n").unwrap();
+  let uncategorized = doc.find("Uncategorized\n").unwrap();
+  assert!(io < streams && streams < uncategorized);
+  // functions are printed before classes within a category
+  let pipe = doc.find("function pipe").unwrap();
+  let writer = doc.find("class Writer").unwrap();
+  assert!(streams < pipe && pipe < writer && writer < uncategorized);
+}
+
+#[tokio::test]
+async fn example_modules() {
+  let source_code = r#"
+/**
+ * Adds numbers.
+ *
+ * @example
+ * ```ts
+ * add(1, 2);
+ * ```
+ *
+ * @example Shell
+ * ```sh
+ * deno run add.ts
+ * ```
+ */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+export class Counter {
+  /**
+   * @example
+   * ```ts
+   * import { Counter } from "https://deno.land/x/counter/mod.ts";
+   * new Counter().increment();
+   * ```
+   */
+  increment(): void {}
+}
+
+/**
+ * @example
+ * ```ts
+ * import { $store } from "./mod.ts";
+ * $store.clear();
+ * ```
+ */
+export const $store = new Map();
+
+export { multiply as times } from "./math.ts";
+"#;
+  let math_source_code = r#"
+/**
+ * @example
+ * ```ts
+ * times(2, 3);
+ * ```
+ */
+export function multiply(a: number, b: number): number {
+  return a * b;
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![
+      ("file:///test.ts", None, source_code),
+      ("file:///math.ts", None, math_source_code),
+    ],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let entries = DocParser::new(graph, false, &source_parser)
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let modules = crate::example_modules(&entries, &specifier);
+  let sources = modules
+    .iter()
+    .map(|(name, module)| (name.as_str(), module.source.as_str()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    sources,
+    vec![
+      // re-exported symbols are imported by their exported name from the
+      // documented module
+      (
+        "math.ts$times$1.ts",
+        "import { times } from \"file:///test.ts\";\n\ntimes(2, 3);\n"
+      ),
+      (
+        "test.ts$$store$1.ts",
+        "import { $store } from \"./mod.ts\";\n$store.clear();\n"
+      ),
+      (
+        "test.ts$Counter.increment$1.ts",
+        "import { Counter } from \"https://deno.land/x/counter/mod.ts\";\n\
+         new Counter().increment();\n"
+      ),
+      (
+        "test.ts$add$1.ts",
+        "import { add } from \"file:///test.ts\";\n\nadd(1, 2);\n"
+      ),
+    ]
+  );
+  let location = &modules["test.ts$Counter.increment$1.ts"].location;
+  assert_eq!((location.line, location.col), (27, 2));
+
+  let dir = std::env::temp_dir()
+    .join(format!("deno_doc_example_modules_{}", std::process::id()));
+  crate::write_example_modules(&modules, &dir).unwrap();
+  let written = std::fs::read_to_string(dir.join("test.ts$add$1.ts")).unwrap();
+  std::fs::remove_dir_all(&dir).unwrap();
+  assert_eq!(written, modules["test.ts$add$1.ts"].source);
+}
+
+#[tokio::test]
+async fn merge_param_docs() {
+  let source_code = r#"
+/**
+ * Waits for a label.
+ *
+ * @param label the label
+ * @param opts the options
+ * @param [opts.timeout=1000] ms to wait
+ * @param [opts.retry.count] the number of retries
+ * @param missing not a parameter
+ * @param [extra=1] not a parameter either
+ * @returns whether it finished
+ */
+export function wait(
+  label: string,
+  { timeout = 1000, retry }: { timeout?: number; retry: { count: number } },
+): boolean {
+  return true;
+}
+
+/**
+ * @param key the key
+ * @param opts.fallback the fallback
+ */
+export function lookup(key: string, { fallback }: { fallback: string }) {}
+
+export interface Api {
+  /** @param req.url the url */
+  fetch(req: { url: string }): void;
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+  let unknown_params = crate::merge_param_docs(&mut entries);
+  let wait = entries.iter().find(|node| node.name == "wait").unwrap();
+  assert_eq!(
+    unknown_params,
+    vec![
+      UnknownParam {
+        location: wait.location.clone(),
+        name: "missing".to_string(),
+      },
+      UnknownParam {
+        location: wait.location.clone(),
+        name: "extra".to_string(),
+      },
+    ]
+  );
+
+  let function_def = wait.function_def.as_ref().unwrap();
+  assert_eq!(function_def.params[0].doc.as_deref(), Some("the label"));
+  let opts = serde_json::to_value(&function_def.params[1]).unwrap();
+  assert_eq!(opts["doc"], json!("the options"));
+  assert_eq!(opts["props"][0]["doc"], json!("ms to wait"));
+  let properties = &opts["tsType"]["typeLiteral"]["properties"];
+  assert_eq!(properties[0]["doc"], json!("ms to wait"));
+  assert_eq!(
+    properties[1]["tsType"]["typeLiteral"]["properties"][0]["doc"],
+    json!("the number of retries")
+  );
+  assert_eq!(
+    function_def.return_doc.as_deref(),
+    Some("whether it finished")
+  );
+  assert!(function_def.return_type.as_ref().unwrap().js_doc.is_none());
+
+  // destructured parameters are matched to the tags naming no parameter
+  let lookup = entries.iter().find(|node| node.name == "lookup").unwrap();
+  let params = &lookup.function_def.as_ref().unwrap().params;
+  assert_eq!(params[0].doc.as_deref(), Some("the key"));
+  let opts = serde_json::to_value(&params[1]).unwrap();
+  assert_eq!(opts["props"][0]["doc"], json!("the fallback"));
+
+  let api = entries.iter().find(|node| node.name == "Api").unwrap();
+  let method_def = &api.interface_def.as_ref().unwrap().methods[0];
+  let req = serde_json::to_value(&method_def.params[0]).unwrap();
+  assert_eq!(
+    req["tsType"]["typeLiteral"]["properties"][0]["doc"],
+    json!("the url")
+  );
+}
+
+#[tokio::test]
+async fn parse_js_doc_types() {
+  let source_code = r#"
+/**
+ * @param {?string} name
+ * @param {function(number): boolean} predicate
+ * @param {{a: number}} opts the options
+ * @returns {Array.<string>}
+ */
+export function filter(name, predicate, opts) {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.js",
+    vec![("file:///test.js", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+  crate::parse_js_doc_types(&mut entries);
+
+  assert!(matches!(
+    &entries[0].js_doc.tags[2],
+    JsDocTag::Param { name, type_ref: Some(type_ref), doc: Some(doc), .. }
+      if name == "opts" && type_ref == "{a: number}" && doc == "the options"
+  ));
+  let ts_types = entries[0]
+    .js_doc
+    .tags
+    .iter()
+    .map(|tag| match tag {
+      JsDocTag::Param { ts_type, .. } | JsDocTag::Return { ts_type, .. } => {
+        ts_type.clone().unwrap()
+      }
+      _ => unreachable!(),
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    ts_types,
+    vec![
+      crate::parse_js_doc_type("string | null").unwrap(),
+      crate::parse_js_doc_type("(arg0: number) => boolean").unwrap(),
+      crate::parse_js_doc_type("{a: number}").unwrap(),
+      crate::parse_js_doc_type("Array<string>").unwrap(),
+    ]
+  );
+}
+
+#[tokio::test]
+async fn resolve_inherited_docs() {
+  let base_source_code = r#"
+export class Base {
+  /** Greets someone. */
+  greet(name: string): string {
+    return name;
+  }
+
+  /** Counts something. */
+  static count(): number {
+    return 1;
+  }
+}
+
+export interface Closer {
+  /**
+   * Closes the resource.
+   *
+   * @param force whether to close immediately
+   */
+  close(force: boolean): void;
+}
+"#;
+  let test_source_code = r#"
+import { Base, Closer } from "./base.ts";
+
+export class Derived extends Base implements Closer {
+  greet(name: string): string {
+    return name;
+  }
+
+  /** Counts other things. */
+  static count(): number {
+    return 2;
+  }
+
+  /**
+   * @inheritDoc
+   * @param force whether to flush first
+   */
+  close(force: boolean): void {}
+
+  /**
+   * Says hello: {@inheritDoc Base.greet} Or not.
+   * @deprecated use greet
+   */
+  hello(): void {}
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![
+      ("file:///test.ts", None, test_source_code),
+      ("file:///base.ts", None, base_source_code),
+    ],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_inherited_docs(&mut entries);
+
+  let class_def = entries
+    .iter()
+    .find(|node| node.name == "Derived")
+    .unwrap()
+    .class_def
+    .as_ref()
+    .unwrap();
+  let js_docs = class_def
+    .methods
+    .iter()
+    .map(|method| {
+      (
+        method.name.as_str(),
+        serde_json::to_value(&method.js_doc).unwrap(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    js_docs,
+    vec![
+      (
+        "greet",
+        json!({
+          "doc": "Greets someone.",
+          "inheritedFrom": "Base.greet",
+        })
+      ),
+      (
+        "count",
+        json!({
+          "doc": "Counts other things.",
+        })
+      ),
+      (
+        "close",
+        json!({
+          "doc": "Closes the resource.\n",
+          "tags": [{
+            "kind": "param",
+            "name": "force",
+            "doc": "whether to flush first",
+          }],
+          "inheritedFrom": "Closer.close",
+        })
+      ),
+      // the own documentation around an inline tag is kept
+      (
+        "hello",
+        json!({
+          "doc": "Says hello: Greets someone. Or not.",
+          "tags": [{
+            "kind": "deprecated",
+            "doc": "use greet",
+          }],
+          "inheritedFrom": "Base.greet",
+        })
+      ),
+    ]
+  );
+}
+
+async fn js_docs(
+  source_code: &str,
+  loose_js_doc: bool,
+) -> Vec<(String, Option<String>)> {
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut doc_parser = DocParser::new(graph, false, &source_parser);
+  doc_parser.loose_js_doc = loose_js_doc;
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  let mut docs = Vec::new();
+  for entry in entries {
+    if let Some(class_def) = &entry.class_def {
+      for method in &class_def.methods {
+        docs.push((
+          format!("{}.{}", entry.name, method.name),
+          method.js_doc.doc.clone(),
+        ));
+      }
+    }
+    docs.push((entry.name, entry.js_doc.doc));
+  }
+  docs
+}
+
+#[tokio::test]
+async fn js_doc_attachment() {
+  let source_code = r#"/**
+ * Copyright 2022 the authors. All rights reserved. MIT license.
+ */
+
+export function licensed() {}
+
+/** Documented. */
+// deno-lint-ignore no-explicit-any
+export function lineComment(_: any) {}
+
+/** Documented. */
+/* not a JSDoc comment */
+export function blockComment() {}
+
+/** First. */
+/** Second. */
+export function twoComments() {}
+
+/** Separated. */
+
+// a line comment
+export function separated() {}
+
+/** Not exported. */
+function hidden() {}
+export function afterCode() {}
+
+export class A {
+  /** Separated. */
+
+  undocumented() {}
+  /** Documented. */
+  documented() {}
+}
+"#;
+  let doc = |doc: &str| Some(doc.to_string());
+  let expected = vec![
+    ("licensed".to_string(), None),
+    ("lineComment".to_string(), doc("Documented.")),
+    ("blockComment".to_string(), doc("Documented.")),
+    ("twoComments".to_string(), doc("Second.")),
+    ("separated".to_string(), None),
+    ("afterCode".to_string(), None),
+    ("A.undocumented".to_string(), None),
+    ("A.documented".to_string(), doc("Documented.")),
+    ("A".to_string(), None),
+  ];
+  assert_eq!(js_docs(source_code, false).await, expected);
+
+  // the last JSDoc comment is attached regardless when loose
+  let expected = vec![
+    (
+      "licensed".to_string(),
+      doc("Copyright 2022 the authors. All rights reserved. MIT license."),
+    ),
+    ("lineComment".to_string(), doc("Documented.")),
+    ("blockComment".to_string(), doc("Documented.")),
+    ("twoComments".to_string(), doc("Second.")),
+    ("separated".to_string(), doc("Separated.")),
+    ("afterCode".to_string(), None),
+    ("A.undocumented".to_string(), doc("Separated.")),
+    ("A.documented".to_string(), doc("Documented.")),
+    ("A".to_string(), None),
+  ];
+  assert_eq!(js_docs(source_code, true).await, expected);
+}
+
+#[tokio::test]
+async fn js_doc_attachment_module_doc() {
+  let source_code = r#"/**
+ * The module.
+ * @module
+ */
+export function first() {}
+"#;
+  let expected = vec![
+    ("".to_string(), Some("The module.".to_string())),
+    ("first".to_string(), None),
+  ];
+  assert_eq!(js_docs(source_code, false).await, expected);
+
+  let expected = vec![
+    ("".to_string(), Some("The module.".to_string())),
+    ("first".to_string(), Some("The module.".to_string())),
+  ];
+  assert_eq!(js_docs(source_code, true).await, expected);
+}
+
+#[tokio::test]
+async fn js_doc_comments() {
+  let source_code = r#"export const a = 1;
+
+/**
+ * Adds the numbers.
+ *
+ * @param a the first number
+ * @param b the second number,
+ *   which is wrapped
+ * @returns the sum
+ */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut doc_parser = DocParser::new(graph, false, &source_parser);
+
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  let add = entries.iter().find(|node| node.name == "add").unwrap();
+  assert_eq!(add.js_doc.comment, None);
+  assert!(serde_json::to_value(&add.js_doc)
+    .unwrap()
+    .get("comment")
+    .is_none());
+
+  doc_parser.js_doc_comments = true;
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  let a = entries.iter().find(|node| node.name == "a").unwrap();
+  assert_eq!(a.js_doc.comment, None);
+  let add = entries.iter().find(|node| node.name == "add").unwrap();
+  assert_eq!(
+    serde_json::to_value(add.js_doc.comment.as_ref().unwrap()).unwrap(),
+    json!({
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 3,
+        "col": 0,
+      },
+      "start": 21,
+      "end": 154,
+      "text": &source_code[21..154],
+      "tagLines": [
+        { "tag": 0, "line": 3 },
+        { "tag": 1, "line": 4 },
+        { "tag": 2, "line": 6 },
+      ],
+    })
+  );
+  assert_eq!(add.js_doc.tags.len(), 3);
+}
+
+#[tokio::test]
+async fn resolve_deprecations() {
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![(
+      "file:///test.ts",
+      None,
+      r#"/** @deprecated Use `Client` instead. */
+export namespace legacy {
+  export function connect() {}
+  /** @deprecated Connects twice. */
+  export function reconnect() {}
+}
+
+export class Client {
+  /** @deprecated */
+  connect() {}
+  get size(): number {
+    return 0;
+  }
+}
+
+/** @deprecated Use `Client`. */
+export class OldClient {
+  private secret() {}
+  get size(): number {
+    return 0;
+  }
+}
+
+/** @deprecated Use `Mode` instead. */
+export enum OldMode {
+  A,
+  B,
+}
+
+export interface Options {
+  /** @deprecated Use `timeout` instead. */
+  delay?: number;
+  timeout?: number;
+}
+"#,
+    )],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut entries = DocParser::new(graph, false, &source_parser)
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let report = crate::deprecations(&entries)
+    .into_iter()
+    .map(|symbol| {
+      (
+        symbol.name,
+        symbol.deprecation.reason,
+        symbol.deprecation.source,
+      )
+    })
+    .collect::<Vec<_>>();
+  let reason = |reason: &str| Some(reason.to_string());
+  assert_eq!(
+    report,
+    vec![
+      (
+        "legacy".to_string(),
+        reason("Use `Client` instead."),
+        "legacy".to_string()
+      ),
+      (
+        "legacy.connect".to_string(),
+        reason("Use `Client` instead."),
+        "legacy".to_string()
+      ),
+      (
+        "legacy.reconnect".to_string(),
+        reason("Connects twice."),
+        "legacy.reconnect".to_string()
+      ),
+      (
+        "Client.connect".to_string(),
+        None,
+        "Client.connect".to_string()
+      ),
+      (
+        "OldClient".to_string(),
+        reason("Use `Client`."),
+        "OldClient".to_string()
+      ),
+      (
+        "OldClient.size".to_string(),
+        reason("Use `Client`."),
+        "OldClient".to_string()
+      ),
+      (
+        "OldMode".to_string(),
+        reason("Use `Mode` instead."),
+        "OldMode".to_string()
+      ),
+      (
+        "OldMode.A".to_string(),
+        reason("Use `Mode` instead."),
+        "OldMode".to_string()
+      ),
+      (
+        "OldMode.B".to_string(),
+        reason("Use `Mode` instead."),
+        "OldMode".to_string()
+      ),
+      (
+        "Options.delay".to_string(),
+        reason("Use `timeout` instead."),
+        "Options.delay".to_string()
+      ),
+    ]
+  );
+  // the report doesn't fill in the deprecations of the doc nodes
+  assert!(entries.iter().all(|node| node.js_doc.deprecation.is_none()));
+
+  crate::resolve_deprecations(&mut entries);
+  let legacy = entries.iter().find(|node| node.name == "legacy").unwrap();
+  let connect = &legacy.namespace_def.as_ref().unwrap().elements[0];
+  assert_eq!(
+    serde_json::to_value(&connect.js_doc).unwrap(),
+    json!({
+      "deprecation": {
+        "reason": "Use `Client` instead.",
+        "source": "legacy",
+      }
+    })
+  );
+  let old_client = entries
+    .iter()
+    .find(|node| node.name == "OldClient")
+    .unwrap();
+  let methods = &old_client.class_def.as_ref().unwrap().methods;
+  let secret = methods
+    .iter()
+    .find(|method| method.name == "secret")
+    .unwrap();
+  assert_eq!(
+    secret.js_doc.deprecation.as_ref().unwrap().source,
+    "OldClient"
+  );
+  let client = entries.iter().find(|node| node.name == "Client").unwrap();
+  let accessors = &client.class_def.as_ref().unwrap().accessors;
+  assert_eq!(accessors[0].js_doc.deprecation, None);
+}
+
+#[tokio::test]
+async fn custom_tags() {
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![(
+      "file:///test.ts",
+      None,
+      r#"/**
+ * Reads a file.
+ *
+ * @permission read Reads the file
+ * @stability 2
+ * @stability unstable
+ * @platform linux
+ * @platform
+ * @see https://example.com
+ * @unknown tag
+ * @publicKey abc
+ * @internalApi since v2
+ * @classified secret
+ * @inheritdocs Base
+ */
+export function readFile() {}
+"#,
+    )],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let mut doc_parser = DocParser::new(graph, false, &source_parser);
+
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(
+    serde_json::to_value(&entries[0].js_doc.tags[0]).unwrap(),
+    json!({
+      "kind": "unsupported",
+      "value": "@permission read Reads the file",
+    })
+  );
+
+  doc_parser.register_tag_shape("permission", TagShape::Name);
+  doc_parser.register_tag_shape("platform", TagShape::Text);
+  doc_parser.register_tag_shape("see", TagShape::Text);
+  // names which start with the name of a built-in tag
+  for name in ["publicKey", "internalApi", "classified", "inheritdocs"] {
+    doc_parser.register_tag_shape(name, TagShape::Text);
+  }
+  doc_parser.register_tag_parser("stability", |text| {
+    text.parse::<u64>().ok().map(serde_json::Value::from)
+  });
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(
+    serde_json::to_value(&entries[0].js_doc.tags).unwrap(),
+    json!([
+      {
+        "kind": "custom",
+        "name": "permission",
+        "value": { "name": "read", "doc": "Reads the file" },
+      },
+      { "kind": "custom", "name": "stability", "value": 2 },
+      { "kind": "unsupported", "value": "@stability unstable" },
+      { "kind": "custom", "name": "platform", "value": { "doc": "linux" } },
+      { "kind": "unsupported", "value": "@platform" },
+      { "kind": "see", "doc": "https://example.com" },
+      { "kind": "unsupported", "value": "@unknown tag" },
+      { "kind": "custom", "name": "publicKey", "value": { "doc": "abc" } },
+      {
+        "kind": "custom",
+        "name": "internalApi",
+        "value": { "doc": "since v2" },
+      },
+      { "kind": "custom", "name": "classified", "value": { "doc": "secret" } },
+      { "kind": "custom", "name": "inheritdocs", "value": { "doc": "Base" } },
+    ])
+  );
+}
+
+#[tokio::test]
+async fn js_doc_markdown() {
+  let source_code = r#"
+/**
+ * Reads **all** of a {@link Reader}, as in
+ * [the guide](https://deno.land/manual).
+ *
+ * ```ts
+ * await readAll(reader);
+ * ```
+ *
+ * @param reader The _source_ to read
+ */
+export function readAll(reader: Reader) {}
+
+/** Writes all of the bytes of the given array to the writer, retrying until every byte is written or an error is thrown. */
+export function writeAll() {}
+
+/** Copies {@link Reader | a reader} to a writer. */
+export function copy() {}
+
+export interface Reader {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_type_refs(&mut entries);
+  crate::parse_js_doc_markdown(&mut entries);
+
+  let read_all = entries.iter().find(|node| node.name == "readAll").unwrap();
+  assert_eq!(
+    serde_json::to_value(&read_all.js_doc.markdown).unwrap(),
+    json!([
+      {
+        "kind": "paragraph",
+        "children": [
+          { "kind": "text", "value": "Reads " },
+          { "kind": "strong", "children": [{ "kind": "text", "value": "all" }] },
+          { "kind": "text", "value": " of a " },
+          {
+            "kind": "jsDocLink",
+            "target": "Reader",
+            "style": "link",
+            "resolution": {
+              "kind": "symbol",
+              "specifier": "file:///test.ts",
+              "symbolPath": ["Reader"],
+            },
+          },
+          { "kind": "text", "value": ", as in\n" },
+          {
+            "kind": "link",
+            "url": "https://deno.land/manual",
+            "children": [{ "kind": "text", "value": "the guide" }],
+          },
+          { "kind": "text", "value": "." },
+        ],
+      },
+      { "kind": "codeBlock", "lang": "ts", "code": "await readAll(reader);" },
+    ])
+  );
+  assert_eq!(
+    serde_json::to_value(&read_all.js_doc.tags[0]).unwrap()["markdown"],
+    json!([{
+      "kind": "paragraph",
+      "children": [
+        { "kind": "text", "value": "The " },
+        {
+          "kind": "emphasis",
+          "children": [{ "kind": "text", "value": "source" }],
+        },
+        { "kind": "text", "value": " to read" },
+      ],
+    }])
+  );
+
+  // the Markdown is printed rather than the doc parsed again
+  let copy = entries.iter_mut().find(|node| node.name == "copy").unwrap();
+  copy.js_doc.doc = Some("Unused.".to_string());
+
+  let doc = DocPrinter::new(&entries, false, false).to_string();
+  assert!(doc.contains("  Copies a reader to a writer.\n"));
+  assert!(!doc.contains("Unused."));
+  assert!(doc.contains(
+    "  Reads all of a Reader, as in the guide (https://deno.land/manual).\n\n"
+  ));
+  assert!(doc.contains("\n    await readAll(reader);\n"));
+  assert!(doc.contains("      The source to read\n"));
+  assert!(doc.contains(
+    "  Writes all of the bytes of the given array to the writer, retrying until every\n  byte is written or an error is thrown.\n"
+  ));
+}
+
+#[tokio::test]
+async fn visit_inherited_members() {
+  let source_code = r#"
+export class Base {
+  /**
+   * @param name the name
+   * @param missing
+   */
+  greet(name: string) {}
+  /** @deprecated use greet */
+  hello() {}
+}
+
+export class Derived extends Base {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_inherited_members(&mut entries);
+
+  // the unknown parameters and deprecations of inherited members are only
+  // reported at their ancestors
+  let unknown_params = crate::merge_param_docs(&mut entries);
+  assert_eq!(
+    unknown_params
+      .iter()
+      .map(|param| param.name.as_str())
+      .collect::<Vec<_>>(),
+    vec!["missing"]
+  );
+  crate::resolve_deprecations(&mut entries);
+  assert_eq!(
+    crate::deprecations(&entries)
+      .iter()
+      .map(|deprecated| deprecated.name.as_str())
+      .collect::<Vec<_>>(),
+    vec!["Base.hello"]
+  );
+
+  let derived = entries.iter().find(|node| node.name == "Derived").unwrap();
+  let methods = &derived.class_def.as_ref().unwrap().inherited[0].methods;
+  assert_eq!(methods[0].member.name, "greet");
+  assert_eq!(
+    methods[0].member.function_def.params[0].doc,
+    Some("the name".to_string())
+  );
+  assert_eq!(methods[1].member.name, "hello");
+  assert_eq!(
+    methods[1]
+      .member
+      .js_doc
+      .deprecation
+      .as_ref()
+      .unwrap()
+      .reason,
+    Some("use greet".to_string())
+  );
+}
+
+#[tokio::test]
+async fn inherited_members_of_siblings() {
+  let source_code = r#"
+interface A {
+  name: string;
+}
+
+interface B {
+  name: string;
+  size: number;
+}
+
+export interface C extends A, B {
+  size: number;
+}
+
+export namespace ns {
+  export class Base {
+    run(): void {}
+  }
+}
+
+export class Derived extends ns.Base {}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let doc_parser = DocParser::new(graph, false, &source_parser);
+  let mut entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  doc_parser.resolve_inherited_members(&mut entries);
+
+  let interface_def = entries
+    .iter()
+    .find(|node| node.name == "C")
+    .unwrap()
+    .interface_def
+    .as_ref()
+    .unwrap();
+  let overridden = interface_def
+    .inherited
+    .iter()
+    .map(|ancestor| {
+      let properties = ancestor
+        .properties
+        .iter()
+        .map(|property| (property.member.name.as_str(), property.overridden))
+        .collect::<Vec<_>>();
+      (ancestor.name.as_str(), properties)
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    overridden,
+    vec![
+      ("A", vec![("name", false)]),
+      ("B", vec![("name", false), ("size", true)]),
+    ]
+  );
+
+  let class_def = entries
+    .iter()
+    .find(|node| node.name == "Derived")
+    .unwrap()
+    .class_def
+    .as_ref()
+    .unwrap();
+  assert_eq!(class_def.extends.as_deref(), Some("ns.Base"));
+  assert_eq!(class_def.inherited.len(), 1);
+  assert_eq!(class_def.inherited[0].name, "ns.Base");
+  assert_eq!(class_def.inherited[0].methods[0].member.name, "run");
+}
+
+#[tokio::test]
+async fn merge_accessors_accessibility() {
+  let source_code = r#"
+export class A {
+  get x(): number {
+    return 1;
+  }
+  private set x(value: number) {}
+  protected get y(): number {
+    return 1;
+  }
+  protected set y(value: number) {}
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+
+  let accessors = &entries[0].class_def.as_ref().unwrap().accessors;
+  assert_eq!(accessors[0].name, "x");
+  assert_eq!(accessors[0].accessibility, None);
+  assert_eq!(
+    accessors[0].getter_accessibility,
+    Some(Accessibility::Public)
+  );
+  assert_eq!(
+    accessors[0].setter_accessibility,
+    Some(Accessibility::Private)
+  );
+  assert_eq!(accessors[1].name, "y");
+  assert_eq!(accessors[1].accessibility, Some(Accessibility::Protected));
+  assert_eq!(accessors[1].getter_accessibility, None);
+  assert_eq!(accessors[1].setter_accessibility, None);
+}
+
+#[tokio::test]
+async fn printer_merge_accessors() {
+  let source_code = r#"
+export class A {
+  /** The size. */
+  get size(): number {
+    return 1;
+  }
+  set size(value: number | string) {}
+  get id(): string {
+    return "a";
+  }
+  set label(value: string) {}
+  method(): void {}
+}
+"#;
+  let (graph, specifier) = setup(
+    "file:///test.ts",
+    vec![("file:///test.ts", None, source_code)],
+  )
+  .await;
+  let source_parser = deno_graph::DefaultSourceParser::new();
+  let entries = DocParser::new(graph, false, &source_parser)
+    .parse(&specifier)
+    .unwrap();
+
+  let mut printer = DocPrinter::new(&entries, false, false);
+  let doc = printer.to_string();
+  assert!(doc.contains("get size(): number"));
+  assert!(doc.contains("set size(value: number | string)"));
+
+  printer.merge_accessors = true;
+  let doc = printer.to_string();
+  assert!(doc.contains("size: number | string"));
+  assert!(doc.contains("The size."));
+  assert!(doc.contains("readonly id: string"));
+  assert!(doc.contains("writeonly label: string"));
+  assert!(doc.contains("method(): void"));
+  assert!(!doc.contains("get size"));
+  assert!(!doc.contains("set label"));
+}
+
 #[tokio::test]
 async fn exports_imported_earlier() {
   let foo_source_code = r#"export const foo: string = "foo";"#;
@@ -1034,7 +2942,12 @@ export class A {
           "kind": "method",
           "functionDef": {
             "params": [],
-            "returnType": null,
+            "returnType": {
+              "repr": "void",
+              "kind": "keyword",
+              "keyword": "void",
+              "inferred": true
+            },
             "isAsync": false,
             "isGenerator": false,
             "typeParams": [],
@@ -1534,7 +3447,12 @@ export class A {
             "kind": "method",
             "functionDef": {
               "params": [],
-              "returnType": null,
+              "returnType": {
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string",
+                "inferred": true
+              },
               "isAsync": false,
               "isGenerator": false,
               "typeParams": [],
@@ -1587,7 +3505,12 @@ export class A {
                   }
                 }
               ],
-              "returnType": null,
+              "returnType": {
+                "repr": "void",
+                "kind": "keyword",
+                "keyword": "void",
+                "inferred": true
+              },
               "isAsync": false,
               "isGenerator": false,
               "typeParams": [],
@@ -1612,6 +3535,22 @@ export class A {
               "col": 1,
             }
           }
+        ],
+        "accessors": [
+          {
+            "name": "x",
+            "location": {
+              "filename": "file:///test.ts",
+              "line": 9,
+              "col": 2,
+            },
+            "tsType": null,
+            "readonly": true,
+            "writeOnly": false,
+            "optional": false,
+            "accessibility": null,
+            "declaredAs": ["getter"]
+          }
         ]
       }
     }]
@@ -2620,7 +4559,13 @@ export function foo([e,,f, ...g]: number[], { c, d: asdf, i = "asdf", ...rest},
             {
               "kind": "assign",
               "key": "i",
-              "value": "[UNSUPPORTED]"
+              "value": "[UNSUPPORTED]",
+              "tsType": {
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string",
+                "inferred": true
+              }
             },
             {
               "arg": {
@@ -2639,7 +4584,7 @@ export function foo([e,,f, ...g]: number[], { c, d: asdf, i = "asdf", ...rest},
           "left": {
             "name": "ops",
             "kind": "identifier",
-            "optional": false,
+            "optional": true,
             "tsType": {
               "repr": "AssignOpts",
               "kind": "typeRef",
@@ -2902,6 +4847,37 @@ export interface Thing {
         "callSignatures": [],
         "indexSignatures": [],
         "typeParams": [],
+        "accessors": [
+          {
+            "name": "size",
+            "location": {
+              "filename": "file:///test.ts",
+              "line": 3,
+              "col": 2,
+            },
+            "tsType": {
+              "repr": "",
+              "kind": "union",
+              "union": [
+                {
+                  "repr": "number",
+                  "kind": "keyword",
+                  "keyword": "number",
+                },
+                {
+                  "repr": "string",
+                  "kind": "keyword",
+                  "keyword": "string",
+                }
+              ]
+            },
+            "readonly": false,
+            "writeOnly": false,
+            "optional": false,
+            "accessibility": null,
+            "declaredAs": ["getter", "setter"]
+          }
+        ],
       }
     }]
   );
@@ -2942,6 +4918,155 @@ export type NumberArray = Array<number>;
     }
   }]);
 
+  json_test!(export_type_alias_labeled_tuple,
+    r#"
+export type Range = [start: number, end?: number, ...rest: string[]];
+    "#;
+    [{
+    "kind": "typeAlias",
+    "name": "Range",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "typeAliasDef": {
+      "typeParams": [],
+      "tsType": {
+        "repr": "",
+        "kind": "tuple",
+        "tuple": [
+          {
+            "label": "start",
+            "tsType": {
+              "repr": "number",
+              "kind": "keyword",
+              "keyword": "number"
+            }
+          },
+          {
+            "label": "end",
+            "tsType": {
+              "repr": "",
+              "kind": "optional",
+              "optional": {
+                "repr": "number",
+                "kind": "keyword",
+                "keyword": "number"
+              }
+            }
+          },
+          {
+            "label": "rest",
+            "tsType": {
+              "repr": "",
+              "kind": "rest",
+              "rest": {
+                "repr": "",
+                "kind": "array",
+                "array": {
+                  "repr": "string",
+                  "kind": "keyword",
+                  "keyword": "string"
+                }
+              }
+            }
+          }
+        ]
+      }
+    }
+  }]);
+
+  json_test!(export_type_alias_documented_members,
+    r#"
+export type Mode =
+  /** Read only. */
+  | "r"
+  /** Read and write. */
+  | "rw";
+
+export type Pair = [/** The key. */ key: string, value: number];
+    "#;
+    [{
+    "kind": "typeAlias",
+    "name": "Mode",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "typeAliasDef": {
+      "typeParams": [],
+      "tsType": {
+        "repr": "",
+        "kind": "union",
+        "union": [
+          {
+            "repr": "r",
+            "kind": "literal",
+            "literal": {
+              "kind": "string",
+              "string": "r"
+            },
+            "jsDoc": {
+              "doc": "Read only."
+            }
+          },
+          {
+            "repr": "rw",
+            "kind": "literal",
+            "literal": {
+              "kind": "string",
+              "string": "rw"
+            },
+            "jsDoc": {
+              "doc": "Read and write."
+            }
+          }
+        ]
+      }
+    }
+  }, {
+    "kind": "typeAlias",
+    "name": "Pair",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 8,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "typeAliasDef": {
+      "typeParams": [],
+      "tsType": {
+        "repr": "",
+        "kind": "tuple",
+        "tuple": [
+          {
+            "label": "key",
+            "tsType": {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string",
+              "jsDoc": {
+                "doc": "The key."
+              }
+            }
+          },
+          {
+            "label": "value",
+            "tsType": {
+              "repr": "number",
+              "kind": "keyword",
+              "keyword": "number"
+            }
+          }
+        ]
+      }
+    }
+  }]);
+
   json_test!(export_type_alias_literal,
   r#"
 export type A = {
@@ -3309,7 +5434,12 @@ export function bar() {};
         "declarationKind": "export",
         "functionDef": {
           "params": [],
-          "returnType": null,
+          "returnType": {
+            "repr": "void",
+            "kind": "keyword",
+            "keyword": "void",
+            "inferred": true
+          },
           "isAsync": false,
           "isGenerator": false,
           "typeParams": []
@@ -4075,7 +6205,12 @@ export function adopt<T>(p: import("./module.ts").Pet<T>) {
               }
             }
           ],
-          "returnType": null,
+          "returnType": {
+            "repr": "void",
+            "kind": "keyword",
+            "keyword": "void",
+            "inferred": true
+          },
           "isAsync": false,
           "isGenerator": false,
           "typeParams": [
@@ -4499,6 +6634,26 @@ export function f(): Generic<[string, number]> { return {}; }
     "Generic<[string, number]>"
   );
 
+  contains_test!(labeled_tuple_type,
+    r#"
+export type Range = [start: number, end?: number, ...rest: string[]];
+export type Plain = [number, string?, ...boolean[]];
+    "#;
+    "type Range = [start: number, end?: number, ...rest: string[]]",
+    "type Plain = [number, string?, ...boolean[]]"
+  );
+
+  contains_test!(type_alias_documented_members,
+    r#"
+export type Shape =
+  /** A circle. */
+  | { kind: "circle"; radius: number }
+  | { kind: "square"; size: number };
+    "#;
+    "{ kind: \"circle\"; radius: number; }",
+    "A circle."
+  );
+
   contains_test!(type_literal_declaration,
     "export type T = {}";
     "{ }"
@@ -4680,6 +6835,37 @@ export function a(b, c) {}
     "returning doc"
   );
 
+  contains_test!(jsdoc_value_tags,
+    r#"
+/**
+ * a is a function
+ *
+ * @since 1.2.0
+ * @category Networking
+ * @default 42
+ * @throws {TypeError} when the input is invalid
+ * @see https://deno.land
+ * @experimental Subject to change
+ * @async
+ * @override
+ * @publicKey
+ */
+export function a() {}
+    "#;
+    "@since 1.2.0\n",
+    "@category Networking\n",
+    "@default 42\n",
+    "@throws {TypeError}",
+    "when the input is invalid",
+    "@see https://deno.land",
+    "@experimental",
+    "Subject to change",
+    "@async",
+    "@override",
+    "@publicKey";
+    "@public\n"
+  );
+
   contains_test!(namespace_declaration,
   "export namespace Namespace {}";
     "namespace Namespace"
@@ -4790,6 +6976,159 @@ export const fn2 = function (a:string): void {};
     "const g: (\"a\" | 1 | true)[]"
   );
 
+  contains_test!(infer_ts_types_from_exprs,
+    r#"
+export const config = { port: 8080, host: "localhost", start(): void {} };
+export const frozen = { port: 8080 } as const;
+export const foo = bar as Foo;
+export const foo2 = <Foo>bar;
+export const not = !flag;
+export const kind = typeof flag;
+export const sum = 1 + 2;
+export const concat = "a" + 1;
+export const product = a * 2;
+export const neg = -1n;
+export const cmp = a > 1;
+export const either = a ? 1 : "b";
+export const value = await new Promise<string>(() => {});
+    "#;
+    "const config: { start(): void; port: number; host: string; }",
+    "const frozen: { readonly port: 8080; }",
+    "const foo: Foo",
+    "const foo2: Foo",
+    "const not: boolean",
+    "const kind: string",
+    "const sum: number",
+    "const concat: string",
+    "const product: number",
+    "const neg: bigint",
+    "const cmp: boolean",
+    "const either: number | string",
+    "const value: string"
+  );
+
+  contains_test!(infer_return_types,
+    r#"
+export function noReturn() {}
+export function returnsNumber(a: boolean) {
+  if (a) {
+    return 1;
+  }
+  return 2;
+}
+export function mixed(a: boolean) {
+  if (a) {
+    return "a";
+  }
+  return;
+}
+export async function asyncFn() {
+  return true;
+}
+export function* gen() {
+  yield 1;
+  return "done";
+}
+export function unknownReturn() {
+  return foo();
+}
+export const arrow = () => 1;
+export function maybe(a: boolean) {
+  if (a) return 1;
+}
+export function* assigned() {
+  const x = yield 1;
+  f(yield "a");
+  if (yield) {}
+}
+export function* returnsYield() {
+  return yield 1;
+}
+export function* nested() {
+  const inner = function* () {
+    yield "inner";
+  };
+  yield 1;
+}
+export function throws(a: boolean) {
+  if (a) {
+    return 1;
+  } else {
+    throw new Error();
+  }
+}
+    "#;
+    "function noReturn(): void",
+    "function returnsNumber(a: boolean): number",
+    "function mixed(a: boolean): string | undefined",
+    "async function asyncFn(): Promise<boolean>",
+    "function* gen(): Generator<number, string, unknown>",
+    "function unknownReturn()\n",
+    "const arrow: () => number",
+    "function maybe(a: boolean): number | undefined",
+    "function* assigned(): Generator<number | string | undefined, void, unknown>",
+    "function* returnsYield()\n",
+    "function* nested(): Generator<number, void, unknown>",
+    "function throws(a: boolean): number"
+  );
+
+  contains_test!(infer_param_types_from_defaults,
+    r#"
+export function f(retries = 3, name = "x", { a = 1 } = {}, opts: Opts = {}) {}
+    "#;
+    "function f(retries?: number, name?: string, {a}?, opts?: Opts): void"
+  );
+
+  contains_test!(infer_ts_types_from_symbol_refs,
+    r#"
+export function createHandler(req: Request): Response {}
+export class Server {}
+const pkg: { version: string } = { version: "1.0.0" };
+export const handler = createHandler;
+export const ServerClass = Server;
+export const VERSION = pkg.version;
+export const map = new collections.Map<string, number>();
+    "#;
+    "const handler: (req: Request) => Response",
+    "const ServerClass: typeof Server",
+    "const VERSION: string",
+    "const map: collections.Map<string, number>"
+  );
+
+  contains_test!(infer_ts_types_from_scoped_symbol_refs,
+    r#"
+const value: string = "outer";
+export function overloaded(a: string): string;
+export function overloaded(a: number): number;
+export function overloaded(a: any) {}
+export namespace ns {
+  const value: number = 1;
+  export const inner = value;
+}
+export const outer = value;
+export const ambiguous = overloaded;
+    "#;
+    "const inner: number",
+    "const outer: string",
+    "const ambiguous\n"
+  );
+
+  contains_test!(infer_ts_types_from_default_export_refs,
+    r#"
+export default function serve(port: number): void {}
+export const listen = serve;
+    "#;
+    "const listen: (port: number) => void"
+  );
+
+  contains_test!(infer_ts_types_from_default_export_class_refs,
+    r#"
+export default class Server {}
+export const ServerClass = Server;
+    "#;
+    "const ServerClass: typeof Server"
+  );
+
   contains_test!(
     ts_template_with_args,
     r#"
@@ -6,17 +6,32 @@ use crate::display::display_optional;
 use crate::display::display_readonly;
 use crate::display::SliceDisplayer;
 use crate::interface::expr_to_name;
+use crate::js_doc::JsDoc;
 use crate::params::pat_to_param_def;
+use crate::params::prop_name_to_string;
 use crate::params::ts_fn_param_to_param_def;
 use crate::swc_util::is_false;
+use crate::swc_util::js_doc_for_pos;
+use crate::swc_util::JsDocOptions;
+use crate::tagged_json::TS_TYPE_DEF_NAME;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use crate::ParamDef;
 
 use deno_ast::swc::ast::*;
+use deno_ast::swc::common::BytePos;
 use deno_ast::swc::common::Span;
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use deno_ast::ParsedSource;
+use serde::de;
+use serde::de::DeserializeOwned;
+use serde::ser::SerializeStruct;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -37,29 +52,55 @@ impl From<&TsArrayType> for TsTypeDef {
   fn from(other: &TsArrayType) -> TsTypeDef {
     let ts_type_def: TsTypeDef = (&*other.elem_type).into();
 
-    TsTypeDef {
-      array: Some(Box::new(ts_type_def)),
-      kind: Some(TsTypeDefKind::Array),
-      ..Default::default()
-    }
+    TsTypeDefKind::Array(Box::new(ts_type_def)).into()
   }
 }
 
 impl From<&TsTupleType> for TsTypeDef {
   fn from(other: &TsTupleType) -> TsTypeDef {
-    let mut type_defs = vec![];
+    let mut elem_defs = vec![];
 
-    for type_box in &other.elem_types {
-      let ts_type: &TsType = &type_box.ty;
-      let def: TsTypeDef = ts_type.into();
-      type_defs.push(def)
+    for elem in &other.elem_types {
+      let def: TsTupleElementDef = elem.into();
+      elem_defs.push(def)
     }
 
-    TsTypeDef {
-      tuple: Some(type_defs),
-      kind: Some(TsTypeDefKind::Tuple),
-      ..Default::default()
-    }
+    TsTypeDefKind::Tuple(elem_defs).into()
+  }
+}
+
+impl From<&TsTupleElement> for TsTupleElementDef {
+  fn from(other: &TsTupleElement) -> TsTupleElementDef {
+    let ts_type: &TsType = &other.ty;
+    let ts_type: TsTypeDef = ts_type.into();
+
+    let (label, is_optional, is_rest) = match &other.label {
+      Some(Pat::Ident(ident)) => {
+        (Some(ident.id.sym.to_string()), ident.id.optional, false)
+      }
+      Some(Pat::Rest(rest_pat)) => match &*rest_pat.arg {
+        Pat::Ident(ident) => (Some(ident.id.sym.to_string()), false, true),
+        _ => (None, false, true),
+      },
+      _ => (None, false, false),
+    };
+
+    // depending on how the element was written, the optional or rest marker
+    // can end up on the label instead of the type, so make sure the type
+    // always carries it
+    let is_marked = matches!(
+      ts_type.kind,
+      TsTypeDefKind::Optional(_) | TsTypeDefKind::Rest(_)
+    );
+    let ts_type = if is_optional && !is_marked {
+      TsTypeDefKind::Optional(Box::new(ts_type)).into()
+    } else if is_rest && !is_marked {
+      TsTypeDefKind::Rest(Box::new(ts_type)).into()
+    } else {
+      ts_type
+    };
+
+    TsTupleElementDef { label, ts_type }
   }
 }
 
@@ -77,11 +118,7 @@ impl From<&TsUnionOrIntersectionType> for TsTypeDef {
           types_union.push(def);
         }
 
-        TsTypeDef {
-          union: Some(types_union),
-          kind: Some(TsTypeDefKind::Union),
-          ..Default::default()
-        }
+        TsTypeDefKind::Union(types_union).into()
       }
       TsIntersectionType(intersection_type) => {
         let mut types_intersection = vec![];
@@ -92,11 +129,7 @@ impl From<&TsUnionOrIntersectionType> for TsTypeDef {
           types_intersection.push(def);
         }
 
-        TsTypeDef {
-          intersection: Some(types_intersection),
-          kind: Some(TsTypeDefKind::Intersection),
-          ..Default::default()
-        }
+        TsTypeDefKind::Intersection(types_intersection).into()
       }
     }
   }
@@ -134,11 +167,7 @@ impl From<&TsTypeOperator> for TsTypeDef {
       ts_type,
     };
 
-    TsTypeDef {
-      type_operator: Some(Box::new(type_operator_def)),
-      kind: Some(TsTypeDefKind::TypeOperator),
-      ..Default::default()
-    }
+    TsTypeDefKind::TypeOperator(Box::new(type_operator_def)).into()
   }
 }
 
@@ -146,11 +175,7 @@ impl From<&TsParenthesizedType> for TsTypeDef {
   fn from(other: &TsParenthesizedType) -> TsTypeDef {
     let ts_type = (&*other.type_ann).into();
 
-    TsTypeDef {
-      parenthesized: Some(Box::new(ts_type)),
-      kind: Some(TsTypeDefKind::Parenthesized),
-      ..Default::default()
-    }
+    TsTypeDefKind::Parenthesized(Box::new(ts_type)).into()
   }
 }
 
@@ -158,11 +183,7 @@ impl From<&TsRestType> for TsTypeDef {
   fn from(other: &TsRestType) -> TsTypeDef {
     let ts_type = (&*other.type_ann).into();
 
-    TsTypeDef {
-      rest: Some(Box::new(ts_type)),
-      kind: Some(TsTypeDefKind::Rest),
-      ..Default::default()
-    }
+    TsTypeDefKind::Rest(Box::new(ts_type)).into()
   }
 }
 
@@ -170,22 +191,13 @@ impl From<&TsOptionalType> for TsTypeDef {
   fn from(other: &TsOptionalType) -> TsTypeDef {
     let ts_type = (&*other.type_ann).into();
 
-    TsTypeDef {
-      optional: Some(Box::new(ts_type)),
-      kind: Some(TsTypeDefKind::Optional),
-      ..Default::default()
-    }
+    TsTypeDefKind::Optional(Box::new(ts_type)).into()
   }
 }
 
 impl From<&TsThisType> for TsTypeDef {
   fn from(_: &TsThisType) -> TsTypeDef {
-    TsTypeDef {
-      repr: "this".to_string(),
-      this: Some(true),
-      kind: Some(TsTypeDefKind::This),
-      ..Default::default()
-    }
+    TsTypeDef::with_repr("this", TsTypeDefKind::This)
   }
 }
 
@@ -199,12 +211,10 @@ impl From<&TsTypePredicate> for TsTypeDef {
         .as_ref()
         .map(|t| Box::new(ts_type_ann_to_def(t))),
     };
-    TsTypeDef {
-      repr: pred.to_string(),
-      kind: Some(TsTypeDefKind::TypePredicate),
-      type_predicate: Some(pred),
-      ..Default::default()
-    }
+    TsTypeDef::with_repr(
+      &pred.to_string(),
+      TsTypeDefKind::TypePredicate(Box::new(pred)),
+    )
   }
 }
 
@@ -243,12 +253,10 @@ impl From<&TsTypeQuery> for TsTypeDef {
       Import(import_type) => import_type.arg.value.to_string(),
     };
 
-    TsTypeDef {
-      repr: type_name.to_string(),
-      type_query: Some(type_name),
-      kind: Some(TsTypeDefKind::TypeQuery),
-      ..Default::default()
-    }
+    TsTypeDef::with_repr(
+      &type_name.clone(),
+      TsTypeDefKind::TypeQuery(type_name),
+    )
   }
 }
 
@@ -270,15 +278,7 @@ impl From<&TsTypeRef> for TsTypeDef {
       None
     };
 
-    TsTypeDef {
-      repr: type_name.clone(),
-      type_ref: Some(TsTypeRefDef {
-        type_params,
-        type_name,
-      }),
-      kind: Some(TsTypeDefKind::TypeRef),
-      ..Default::default()
-    }
+    TsTypeDef::type_ref(type_name, type_params)
   }
 }
 
@@ -300,15 +300,7 @@ impl From<&TsExprWithTypeArgs> for TsTypeDef {
       None
     };
 
-    TsTypeDef {
-      repr: type_name.clone(),
-      type_ref: Some(TsTypeRefDef {
-        type_params,
-        type_name,
-      }),
-      kind: Some(TsTypeDefKind::TypeRef),
-      ..Default::default()
-    }
+    TsTypeDef::type_ref(type_name, type_params)
   }
 }
 
@@ -320,11 +312,7 @@ impl From<&TsIndexedAccessType> for TsTypeDef {
       index_type: Box::new((&*other.index_type).into()),
     };
 
-    TsTypeDef {
-      indexed_access: Some(indexed_access_def),
-      kind: Some(TsTypeDefKind::IndexedAccess),
-      ..Default::default()
-    }
+    TsTypeDefKind::IndexedAccess(Box::new(indexed_access_def)).into()
   }
 }
 
@@ -344,11 +332,7 @@ impl From<&TsMappedType> for TsTypeDef {
         .map(|a| Box::new(TsTypeDef::from(&**a))),
     };
 
-    TsTypeDef {
-      mapped_type: Some(mapped_type_def),
-      kind: Some(TsTypeDefKind::Mapped),
-      ..Default::default()
-    }
+    TsTypeDefKind::Mapped(Box::new(mapped_type_def)).into()
   }
 }
 
@@ -452,6 +436,7 @@ impl From<&TsTypeLit> for TsTypeDef {
             computed: ts_prop_sig.computed,
             optional: ts_prop_sig.optional,
             type_params,
+            doc: None,
           };
           properties.push(prop_def);
         }
@@ -535,11 +520,7 @@ impl From<&TsTypeLit> for TsTypeDef {
       index_signatures,
     };
 
-    TsTypeDef {
-      kind: Some(TsTypeDefKind::TypeLiteral),
-      type_literal: Some(type_literal),
-      ..Default::default()
-    }
+    TsTypeDefKind::TypeLiteral(Box::new(type_literal)).into()
   }
 }
 
@@ -552,11 +533,7 @@ impl From<&TsConditionalType> for TsTypeDef {
       false_type: Box::new((&*other.false_type).into()),
     };
 
-    TsTypeDef {
-      kind: Some(TsTypeDefKind::Conditional),
-      conditional_type: Some(conditional_type_def),
-      ..Default::default()
-    }
+    TsTypeDefKind::Conditional(Box::new(conditional_type_def)).into()
   }
 }
 
@@ -566,11 +543,7 @@ impl From<&TsInferType> for TsTypeDef {
       type_param: Box::new((&other.type_param).into()),
     };
 
-    Self {
-      kind: Some(TsTypeDefKind::Infer),
-      infer: Some(infer),
-      ..Default::default()
-    }
+    TsTypeDefKind::Infer(infer).into()
   }
 }
 
@@ -596,11 +569,7 @@ impl From<&TsImportType> for TsTypeDef {
       type_params,
     };
 
-    Self {
-      kind: Some(TsTypeDefKind::ImportType),
-      import_type: Some(import_type_def),
-      ..Default::default()
-    }
+    TsTypeDefKind::ImportType(Box::new(import_type_def)).into()
   }
 }
 
@@ -626,6 +595,7 @@ impl From<&TsFnOrConstructorType> for TsTypeDef {
           ts_type: ts_type_ann_to_def(&ts_fn_type.type_ann),
           params,
           type_params,
+          return_doc: None,
         }
       }
       TsConstructorType(ctor_type) => {
@@ -644,15 +614,12 @@ impl From<&TsFnOrConstructorType> for TsTypeDef {
           ts_type: ts_type_ann_to_def(&ctor_type.type_ann),
           params,
           type_params,
+          return_doc: None,
         }
       }
     };
 
-    TsTypeDef {
-      kind: Some(TsTypeDefKind::FnOrConstructor),
-      fn_or_constructor: Some(Box::new(fn_def)),
-      ..Default::default()
-    }
+    TsTypeDefKind::FnOrConstructor(Box::new(fn_def)).into()
   }
 }
 
@@ -690,6 +657,33 @@ impl From<&TsType> for TsTypeDef {
 pub struct TsTypeRefDef {
   pub type_params: Option<Vec<TsTypeDef>>,
   pub type_name: String,
+  /// What the type name refers to, once resolved by
+  /// `DocParser::resolve_type_refs`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub resolution: Option<TypeRefResolution>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum TypeRefResolution {
+  /// A symbol declared by the module at `specifier`. The symbol path starts
+  /// with the name of the symbol in that module, followed by the names of
+  /// namespace members, e.g. `["ns", "Bar"]` for `ns.Bar`.
+  #[serde(rename_all = "camelCase")]
+  Symbol {
+    specifier: String,
+    symbol_path: Vec<String>,
+    /// Whether the symbol, or a namespace enclosing it, is hidden from the
+    /// docs by `@ignore`, or by `@internal` if `DocParser::strip_internal` is
+    /// set.
+    #[serde(default, skip_serializing_if = "is_false")]
+    hidden: bool,
+  },
+  /// A type parameter which is in scope where the type is referenced.
+  TypeParam,
+  /// Neither declared by nor imported into the module, which is the case for
+  /// the types of the global scope.
+  Unresolved,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -734,6 +728,10 @@ pub struct TsFnOrConstructorDef {
   pub ts_type: TsTypeDef,
   pub params: Vec<ParamDef>,
   pub type_params: Vec<TsTypeParamDef>,
+  /// The description of the `@returns` tag of the function, as attached by
+  /// `merge_param_docs`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub return_doc: Option<String>,
 }
 
 impl From<&deno_ast::swc::ast::ArrowExpr> for TsFnOrConstructorDef {
@@ -747,6 +745,7 @@ impl From<&deno_ast::swc::ast::ArrowExpr> for TsFnOrConstructorDef {
       .return_type
       .as_ref()
       .map(ts_type_ann_to_def)
+      .or_else(|| infer_ts_type_from_arrow_body(expr))
       .unwrap_or_else(|| TsTypeDef::keyword("unknown"));
     let type_params =
       maybe_type_param_decl_to_type_param_defs(expr.type_params.as_ref());
@@ -756,6 +755,7 @@ impl From<&deno_ast::swc::ast::ArrowExpr> for TsFnOrConstructorDef {
       ts_type,
       params,
       type_params,
+      return_doc: None,
     }
   }
 }
@@ -773,6 +773,7 @@ impl From<&deno_ast::swc::ast::FnExpr> for TsFnOrConstructorDef {
       .return_type
       .as_ref()
       .map(ts_type_ann_to_def)
+      .or_else(|| infer_return_ts_type(&expr.function))
       .unwrap_or_else(|| TsTypeDef::keyword("unknown"));
     let type_params = maybe_type_param_decl_to_type_param_defs(
       expr.function.type_params.as_ref(),
@@ -783,6 +784,39 @@ impl From<&deno_ast::swc::ast::FnExpr> for TsFnOrConstructorDef {
       ts_type,
       params,
       type_params,
+      return_doc: None,
+    }
+  }
+}
+
+/// An element of a tuple type. Optional and rest elements are represented by
+/// an `Optional` or `Rest` type.
+///
+/// ```ts
+/// [start: number, end?: number, ...rest: string[]]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TsTupleElementDef {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  pub ts_type: TsTypeDef,
+}
+
+impl Display for TsTupleElementDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if let Some(label) = &self.label {
+      match &self.ts_type.kind {
+        TsTypeDefKind::Optional(ts_type) => {
+          write!(f, "{}?: {}", label, &**ts_type)
+        }
+        TsTypeDefKind::Rest(ts_type) => {
+          write!(f, "...{}: {}", label, &**ts_type)
+        }
+        _ => write!(f, "{}: {}", label, self.ts_type),
+      }
+    } else {
+      write!(f, "{}", self.ts_type)
     }
   }
 }
@@ -886,11 +920,15 @@ pub struct LiteralPropertyDef {
   pub optional: bool,
   pub ts_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
+  /// The description of the property from the `@param` tag of the parameter
+  /// it belongs to.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub doc: Option<String>,
 }
 
 impl Display for LiteralPropertyDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-    write!(f, "{}", self.name)?;
+    write!(f, "{}{}", display_readonly(self.readonly), self.name)?;
     if let Some(ts_type) = &self.ts_type {
       write!(f, ": {}", ts_type)?;
     }
@@ -947,103 +985,297 @@ pub struct TsTypeLiteralDef {
   pub index_signatures: Vec<LiteralIndexSignatureDef>,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
+/// The kind of a type, carrying the payload of that kind.
+#[derive(Debug, Clone, PartialEq)]
 pub enum TsTypeDefKind {
-  Keyword,
-  Literal,
-  TypeRef,
-  Union,
-  Intersection,
-  Array,
-  Tuple,
-  TypeOperator,
-  Parenthesized,
-  Rest,
-  Optional,
-  TypeQuery,
+  Keyword(String),
+  Literal(Box<LiteralDef>),
+  TypeRef(Box<TsTypeRefDef>),
+  Union(Vec<TsTypeDef>),
+  Intersection(Vec<TsTypeDef>),
+  Array(Box<TsTypeDef>),
+  Tuple(Vec<TsTupleElementDef>),
+  TypeOperator(Box<TsTypeOperatorDef>),
+  Parenthesized(Box<TsTypeDef>),
+  Rest(Box<TsTypeDef>),
+  Optional(Box<TsTypeDef>),
+  TypeQuery(String),
   This,
-  FnOrConstructor,
-  Conditional,
-  Infer,
-  IndexedAccess,
-  Mapped,
-  TypeLiteral,
-  TypePredicate,
-  ImportType,
+  FnOrConstructor(Box<TsFnOrConstructorDef>),
+  Conditional(Box<TsConditionalDef>),
+  Infer(TsInferDef),
+  IndexedAccess(Box<TsIndexedAccessDef>),
+  Mapped(Box<TsMappedTypeDef>),
+  TypeLiteral(Box<TsTypeLiteralDef>),
+  TypePredicate(Box<TsTypePredicateDef>),
+  ImportType(Box<TsImportTypeDef>),
+}
+
+impl TsTypeDefKind {
+  /// The name of the kind, as found in the `kind` property of the JSON.
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::Keyword(_) => "keyword",
+      Self::Literal(_) => "literal",
+      Self::TypeRef(_) => "typeRef",
+      Self::Union(_) => "union",
+      Self::Intersection(_) => "intersection",
+      Self::Array(_) => "array",
+      Self::Tuple(_) => "tuple",
+      Self::TypeOperator(_) => "typeOperator",
+      Self::Parenthesized(_) => "parenthesized",
+      Self::Rest(_) => "rest",
+      Self::Optional(_) => "optional",
+      Self::TypeQuery(_) => "typeQuery",
+      Self::This => "this",
+      Self::FnOrConstructor(_) => "fnOrConstructor",
+      Self::Conditional(_) => "conditional",
+      Self::Infer(_) => "infer",
+      Self::IndexedAccess(_) => "indexedAccess",
+      Self::Mapped(_) => "mapped",
+      Self::TypeLiteral(_) => "typeLiteral",
+      Self::TypePredicate(_) => "typePredicate",
+      Self::ImportType(_) => "importType",
+    }
+  }
+
+  /// Serializes the payload of the kind as a field of `state`, keyed as in
+  /// the flat JSON shape.
+  fn serialize_payload<S: SerializeStruct>(
+    &self,
+    state: &mut S,
+  ) -> Result<(), S::Error> {
+    match self {
+      Self::Keyword(keyword) => state.serialize_field("keyword", keyword),
+      Self::Literal(literal) => state.serialize_field("literal", literal),
+      Self::TypeRef(type_ref) => state.serialize_field("typeRef", type_ref),
+      Self::Union(union) => state.serialize_field("union", union),
+      Self::Intersection(intersection) => {
+        state.serialize_field("intersection", intersection)
+      }
+      Self::Array(array) => state.serialize_field("array", array),
+      Self::Tuple(tuple) => state.serialize_field("tuple", tuple),
+      Self::TypeOperator(type_operator) => {
+        state.serialize_field("typeOperator", type_operator)
+      }
+      Self::Parenthesized(parenthesized) => {
+        state.serialize_field("parenthesized", parenthesized)
+      }
+      Self::Rest(rest) => state.serialize_field("rest", rest),
+      Self::Optional(optional) => state.serialize_field("optional", optional),
+      Self::TypeQuery(type_query) => {
+        state.serialize_field("typeQuery", type_query)
+      }
+      Self::This => state.serialize_field("this", &true),
+      Self::FnOrConstructor(fn_or_constructor) => {
+        state.serialize_field("fnOrConstructor", fn_or_constructor)
+      }
+      Self::Conditional(conditional_type) => {
+        state.serialize_field("conditionalType", conditional_type)
+      }
+      Self::Infer(infer) => state.serialize_field("infer", infer),
+      Self::IndexedAccess(indexed_access) => {
+        state.serialize_field("indexedAccess", indexed_access)
+      }
+      Self::Mapped(mapped_type) => {
+        state.serialize_field("mappedType", mapped_type)
+      }
+      Self::TypeLiteral(type_literal) => {
+        state.serialize_field("typeLiteral", type_literal)
+      }
+      Self::TypePredicate(type_predicate) => {
+        state.serialize_field("typePredicate", type_predicate)
+      }
+      Self::ImportType(import_type) => {
+        state.serialize_field("importType", import_type)
+      }
+    }
+  }
 }
 
-#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TsTypeDef {
   pub repr: String,
 
-  pub kind: Option<TsTypeDefKind>,
+  pub kind: TsTypeDefKind,
 
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub keyword: Option<String>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub literal: Option<LiteralDef>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub type_ref: Option<TsTypeRefDef>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub union: Option<Vec<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub intersection: Option<Vec<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub array: Option<Box<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub tuple: Option<Vec<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub type_operator: Option<Box<TsTypeOperatorDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub parenthesized: Option<Box<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub rest: Option<Box<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub optional: Option<Box<TsTypeDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub type_query: Option<String>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub this: Option<bool>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub fn_or_constructor: Option<Box<TsFnOrConstructorDef>>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub conditional_type: Option<TsConditionalDef>,
-
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub infer: Option<TsInferDef>,
+  /// The JSDoc written in front of this type when it is a member of a union
+  /// or intersection, or an element of a tuple. It is boxed, as most types
+  /// have none.
+  pub js_doc: Option<Box<JsDoc>>,
 
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub indexed_access: Option<TsIndexedAccessDef>,
+  /// Whether this type was inferred rather than declared, e.g. the return
+  /// type of a function without a return type annotation.
+  pub inferred: bool,
+}
 
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub mapped_type: Option<TsMappedTypeDef>,
+impl From<TsTypeDefKind> for TsTypeDef {
+  fn from(kind: TsTypeDefKind) -> Self {
+    Self::with_repr("", kind)
+  }
+}
 
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub type_literal: Option<TsTypeLiteralDef>,
+impl Serialize for TsTypeDef {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    // the name is how `TaggedJson` tells type definitions apart
+    let len = 3 + self.js_doc.is_some() as usize + self.inferred as usize;
+    let mut state = serializer.serialize_struct(TS_TYPE_DEF_NAME, len)?;
+    state.serialize_field("repr", &self.repr)?;
+    state.serialize_field("kind", self.kind.name())?;
+    self.kind.serialize_payload(&mut state)?;
+    if let Some(js_doc) = &self.js_doc {
+      state.serialize_field("jsDoc", js_doc)?;
+    }
+    if self.inferred {
+      state.serialize_field("inferred", &self.inferred)?;
+    }
+    state.end()
+  }
+}
 
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub type_predicate: Option<TsTypePredicateDef>,
+/// The union of the flat and the tagged JSON shapes of a type definition.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsTypeDefJson {
+  repr: String,
+  kind: String,
+  value: Option<serde_json::Value>,
+  keyword: Option<String>,
+  literal: Option<Box<LiteralDef>>,
+  type_ref: Option<Box<TsTypeRefDef>>,
+  union: Option<Vec<TsTypeDef>>,
+  intersection: Option<Vec<TsTypeDef>>,
+  array: Option<Box<TsTypeDef>>,
+  tuple: Option<Vec<TsTupleElementDef>>,
+  type_operator: Option<Box<TsTypeOperatorDef>>,
+  parenthesized: Option<Box<TsTypeDef>>,
+  rest: Option<Box<TsTypeDef>>,
+  optional: Option<Box<TsTypeDef>>,
+  type_query: Option<String>,
+  fn_or_constructor: Option<Box<TsFnOrConstructorDef>>,
+  conditional_type: Option<Box<TsConditionalDef>>,
+  infer: Option<TsInferDef>,
+  indexed_access: Option<Box<TsIndexedAccessDef>>,
+  mapped_type: Option<Box<TsMappedTypeDef>>,
+  type_literal: Option<Box<TsTypeLiteralDef>>,
+  type_predicate: Option<Box<TsTypePredicateDef>>,
+  import_type: Option<Box<TsImportTypeDef>>,
+  #[serde(default)]
+  js_doc: Option<Box<JsDoc>>,
+  #[serde(default)]
+  inferred: bool,
+}
+
+/// Takes the payload of a kind from its flat property, or otherwise from the
+/// `value` property of the tagged shape.
+fn take_payload<T: DeserializeOwned, E: de::Error>(
+  flat: Option<T>,
+  value: &mut Option<serde_json::Value>,
+  field: &'static str,
+) -> Result<T, E> {
+  match (flat, value.take()) {
+    (Some(payload), _) => Ok(payload),
+    (None, Some(value)) => serde_json::from_value(value).map_err(E::custom),
+    (None, None) => Err(E::missing_field(field)),
+  }
+}
 
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub import_type: Option<TsImportTypeDef>,
+impl<'de> Deserialize<'de> for TsTypeDef {
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    use TsTypeDefKind::*;
+
+    let json = TsTypeDefJson::deserialize(deserializer)?;
+    let mut value = json.value;
+    let value = &mut value;
+    let kind = match json.kind.as_str() {
+      "keyword" => Keyword(take_payload(json.keyword, value, "keyword")?),
+      "literal" => Literal(take_payload(json.literal, value, "literal")?),
+      "typeRef" => TypeRef(take_payload(json.type_ref, value, "typeRef")?),
+      "union" => Union(take_payload(json.union, value, "union")?),
+      "intersection" => {
+        Intersection(take_payload(json.intersection, value, "intersection")?)
+      }
+      "array" => Array(take_payload(json.array, value, "array")?),
+      "tuple" => Tuple(take_payload(json.tuple, value, "tuple")?),
+      "typeOperator" => {
+        TypeOperator(take_payload(json.type_operator, value, "typeOperator")?)
+      }
+      "parenthesized" => {
+        Parenthesized(take_payload(json.parenthesized, value, "parenthesized")?)
+      }
+      "rest" => Rest(take_payload(json.rest, value, "rest")?),
+      "optional" => Optional(take_payload(json.optional, value, "optional")?),
+      "typeQuery" => {
+        TypeQuery(take_payload(json.type_query, value, "typeQuery")?)
+      }
+      "this" => This,
+      "fnOrConstructor" => FnOrConstructor(take_payload(
+        json.fn_or_constructor,
+        value,
+        "fnOrConstructor",
+      )?),
+      "conditional" => Conditional(take_payload(
+        json.conditional_type,
+        value,
+        "conditionalType",
+      )?),
+      "infer" => Infer(take_payload(json.infer, value, "infer")?),
+      "indexedAccess" => IndexedAccess(take_payload(
+        json.indexed_access,
+        value,
+        "indexedAccess",
+      )?),
+      "mapped" => Mapped(take_payload(json.mapped_type, value, "mappedType")?),
+      "typeLiteral" => {
+        TypeLiteral(take_payload(json.type_literal, value, "typeLiteral")?)
+      }
+      "typePredicate" => TypePredicate(take_payload(
+        json.type_predicate,
+        value,
+        "typePredicate",
+      )?),
+      "importType" => {
+        ImportType(take_payload(json.import_type, value, "importType")?)
+      }
+      kind => {
+        return Err(de::Error::unknown_variant(kind, TS_TYPE_DEF_KINDS));
+      }
+    };
+    Ok(Self {
+      repr: json.repr,
+      kind,
+      js_doc: json.js_doc.filter(|js_doc| !js_doc.is_empty()),
+      inferred: json.inferred,
+    })
+  }
 }
 
+const TS_TYPE_DEF_KINDS: &[&str] = &[
+  "keyword",
+  "literal",
+  "typeRef",
+  "union",
+  "intersection",
+  "array",
+  "tuple",
+  "typeOperator",
+  "parenthesized",
+  "rest",
+  "optional",
+  "typeQuery",
+  "this",
+  "fnOrConstructor",
+  "conditional",
+  "infer",
+  "indexedAccess",
+  "mapped",
+  "typeLiteral",
+  "typePredicate",
+  "importType",
+];
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum ThisOrIdent {
@@ -1229,15 +1461,24 @@ impl TsTypeDef {
   pub fn regexp(repr: String) -> Self {
     Self {
       repr,
-      kind: Some(TsTypeDefKind::TypeRef),
-      type_ref: Some(TsTypeRefDef {
-        type_params: None,
-        type_name: "RegExp".to_string(),
-      }),
-      ..Default::default()
+      ..Self::type_ref("RegExp".to_string(), None)
     }
   }
 
+  pub fn type_ref(
+    type_name: String,
+    type_params: Option<Vec<TsTypeDef>>,
+  ) -> Self {
+    Self::with_repr(
+      &type_name.clone(),
+      TsTypeDefKind::TypeRef(Box::new(TsTypeRefDef {
+        type_params,
+        type_name,
+        resolution: None,
+      })),
+    )
+  }
+
   pub fn keyword(keyword_str: &str) -> Self {
     Self::keyword_with_repr(keyword_str, keyword_str)
   }
@@ -1259,21 +1500,20 @@ impl TsTypeDef {
   }
 
   pub fn keyword_with_repr(keyword_str: &str, repr: &str) -> Self {
+    Self::with_repr(repr, TsTypeDefKind::Keyword(keyword_str.to_string()))
+  }
+
+  pub fn with_repr(repr: &str, kind: TsTypeDefKind) -> Self {
     Self {
       repr: repr.to_string(),
-      kind: Some(TsTypeDefKind::Keyword),
-      keyword: Some(keyword_str.to_string()),
-      ..Default::default()
+      kind,
+      js_doc: None,
+      inferred: false,
     }
   }
 
   fn literal(repr: String, lit: LiteralDef) -> Self {
-    Self {
-      repr,
-      kind: Some(TsTypeDefKind::Literal),
-      literal: Some(lit),
-      ..Default::default()
-    }
+    Self::with_repr(&repr, TsTypeDefKind::Literal(Box::new(lit)))
   }
 }
 
@@ -1304,6 +1544,180 @@ pub fn ts_type_ann_to_def(type_ann: &TsTypeAnn) -> TsTypeDef {
   }
 }
 
+/// Attaches the JSDoc comments written in front of union and intersection
+/// members and tuple elements of `ts_type` to the matching parts of `def`,
+/// which must have been converted from `ts_type`.
+///
+/// ```ts
+/// type Mode =
+///   /** Read only. */
+///   | "r"
+///   /** Read and write. */
+///   | "rw";
+/// ```
+pub fn attach_member_js_docs(
+  parsed_source: &ParsedSource,
+  ts_type: &TsType,
+  def: &mut TsTypeDef,
+  js_doc_options: JsDocOptions,
+) {
+  match ts_type {
+    TsType::TsUnionOrIntersectionType(union_or_inter) => {
+      let (span, types) = match union_or_inter {
+        TsUnionOrIntersectionType::TsUnionType(union_type) => {
+          (union_type.span, &union_type.types)
+        }
+        TsUnionOrIntersectionType::TsIntersectionType(intersection_type) => {
+          (intersection_type.span, &intersection_type.types)
+        }
+      };
+      let member_defs = match &mut def.kind {
+        TsTypeDefKind::Union(member_defs)
+        | TsTypeDefKind::Intersection(member_defs) => member_defs,
+        _ => return,
+      };
+      let mut search_start = span.lo;
+      for (member, member_def) in types.iter().zip(member_defs.iter_mut()) {
+        let member_span = member.span();
+        member_def.js_doc = boxed_js_doc(union_member_js_doc(
+          parsed_source,
+          search_start,
+          member_span.lo,
+          js_doc_options,
+        ));
+        attach_member_js_docs(
+          parsed_source,
+          member,
+          member_def,
+          js_doc_options,
+        );
+        search_start = member_span.hi;
+      }
+    }
+    TsType::TsTupleType(tuple_type) => {
+      if let TsTypeDefKind::Tuple(elem_defs) = &mut def.kind {
+        for (elem, elem_def) in
+          tuple_type.elem_types.iter().zip(elem_defs.iter_mut())
+        {
+          elem_def.ts_type.js_doc = boxed_js_doc(js_doc_for_pos(
+            parsed_source,
+            elem.span.lo,
+            js_doc_options,
+          ));
+          // the element type is wrapped when the optional or rest marker is
+          // written on the label, in which case the members are found on the
+          // inner type
+          let is_wrapped = !matches!(
+            &*elem.ty,
+            TsType::TsOptionalType(_) | TsType::TsRestType(_)
+          );
+          if is_wrapped {
+            if let TsTypeDefKind::Optional(inner_def)
+            | TsTypeDefKind::Rest(inner_def) = &mut elem_def.ts_type.kind
+            {
+              attach_member_js_docs(
+                parsed_source,
+                &elem.ty,
+                inner_def,
+                js_doc_options,
+              );
+              continue;
+            }
+          }
+          attach_member_js_docs(
+            parsed_source,
+            &elem.ty,
+            &mut elem_def.ts_type,
+            js_doc_options,
+          );
+        }
+      }
+    }
+    TsType::TsParenthesizedType(paren_type) => {
+      if let TsTypeDefKind::Parenthesized(inner_def) = &mut def.kind {
+        attach_member_js_docs(
+          parsed_source,
+          &paren_type.type_ann,
+          inner_def,
+          js_doc_options,
+        );
+      }
+    }
+    TsType::TsArrayType(array_type) => {
+      if let TsTypeDefKind::Array(elem_def) = &mut def.kind {
+        attach_member_js_docs(
+          parsed_source,
+          &array_type.elem_type,
+          elem_def,
+          js_doc_options,
+        );
+      }
+    }
+    TsType::TsOptionalType(optional_type) => {
+      if let TsTypeDefKind::Optional(inner_def) = &mut def.kind {
+        attach_member_js_docs(
+          parsed_source,
+          &optional_type.type_ann,
+          inner_def,
+          js_doc_options,
+        );
+      }
+    }
+    TsType::TsRestType(rest_type) => {
+      if let TsTypeDefKind::Rest(inner_def) = &mut def.kind {
+        attach_member_js_docs(
+          parsed_source,
+          &rest_type.type_ann,
+          inner_def,
+          js_doc_options,
+        );
+      }
+    }
+    _ => {}
+  }
+}
+
+/// A JSDoc comment for a union member can either be written directly in front
+/// of the member or in front of the `|` (or `&`) that precedes it, in which
+/// case it is attached to that operator token.
+/// The JSDoc of a type, which is left out if empty.
+fn boxed_js_doc(js_doc: JsDoc) -> Option<Box<JsDoc>> {
+  if js_doc.is_empty() {
+    None
+  } else {
+    Some(Box::new(js_doc))
+  }
+}
+
+fn union_member_js_doc(
+  parsed_source: &ParsedSource,
+  search_start: BytePos,
+  member_start: BytePos,
+  js_doc_options: JsDocOptions,
+) -> JsDoc {
+  let js_doc = js_doc_for_pos(parsed_source, member_start, js_doc_options);
+  if !js_doc.is_empty() || search_start >= member_start {
+    return js_doc;
+  }
+  let between = parsed_source.source().span_text(&Span::new(
+    search_start,
+    member_start,
+    Default::default(),
+  ));
+  // operators within comments have no comments attached, so they are skipped
+  for (index, _) in between.match_indices(|c| c == '|' || c == '&').rev() {
+    let js_doc = js_doc_for_pos(
+      parsed_source,
+      search_start + BytePos(index as u32),
+      js_doc_options,
+    );
+    if !js_doc.is_empty() {
+      return js_doc;
+    }
+  }
+  JsDoc::default()
+}
+
 pub fn infer_ts_type_from_expr(
   expr: &Expr,
   is_const: bool,
@@ -1341,6 +1755,38 @@ pub fn infer_ts_type_from_expr(
       // e.g.) const value = Number(123);
       infer_ts_type_from_call_expr(expr)
     }
+    Expr::Object(obj_lit) => {
+      // e.g.) const config = { port: 8080 };
+      infer_ts_type_from_obj_lit(obj_lit, false)
+    }
+    Expr::TsAs(expr) => {
+      // e.g.) const foo = bar as Foo;
+      Some(expr.type_ann.as_ref().into())
+    }
+    Expr::TsTypeAssertion(expr) => {
+      // e.g.) const foo = <Foo>bar;
+      Some(expr.type_ann.as_ref().into())
+    }
+    Expr::Paren(expr) => {
+      // e.g.) const n = (1);
+      infer_ts_type_from_expr(&expr.expr, is_const)
+    }
+    Expr::Unary(expr) => {
+      // e.g.) const b = !a;
+      infer_ts_type_from_unary_expr(expr)
+    }
+    Expr::Bin(expr) => {
+      // e.g.) const n = a + 1;
+      infer_ts_type_from_bin_expr(expr)
+    }
+    Expr::Cond(expr) => {
+      // e.g.) const n = a ? 1 : 2;
+      infer_ts_type_from_cond_expr(expr)
+    }
+    Expr::Await(expr) => {
+      // e.g.) const s = await new Promise<string>(resolve);
+      infer_ts_type_from_await_expr(expr)
+    }
     _ => None,
   }
 }
@@ -1370,59 +1816,424 @@ fn infer_ts_type_from_arr_lit(
       } else {
         // it is not a trivial type that can be inferred an so will infer an
         // an any array.
-        return Some(TsTypeDef {
-          repr: "any[]".to_string(),
-          kind: Some(TsTypeDefKind::Array),
-          array: Some(Box::new(TsTypeDef::keyword("any"))),
-          ..Default::default()
-        });
+        return Some(TsTypeDef::with_repr(
+          "any[]",
+          TsTypeDefKind::Array(Box::new(TsTypeDef::keyword("any"))),
+        ));
       }
     } else {
       // TODO(@kitsonk) we should recursively unwrap the spread here
-      return Some(TsTypeDef {
-        repr: "any[]".to_string(),
-        kind: Some(TsTypeDefKind::Array),
-        array: Some(Box::new(TsTypeDef::keyword("any"))),
-        ..Default::default()
-      });
+      return Some(TsTypeDef::with_repr(
+        "any[]",
+        TsTypeDefKind::Array(Box::new(TsTypeDef::keyword("any"))),
+      ));
     }
   }
   match defs.len() {
-    1 => Some(TsTypeDef {
-      kind: Some(TsTypeDefKind::Array),
-      array: Some(Box::new(defs[0].clone())),
-      ..Default::default()
-    }),
+    1 => Some(TsTypeDefKind::Array(Box::new(defs[0].clone())).into()),
     2.. => {
-      let union = TsTypeDef {
-        kind: Some(TsTypeDefKind::Union),
-        union: Some(defs),
-        ..Default::default()
+      let union = TsTypeDefKind::Union(defs).into();
+      Some(TsTypeDefKind::Array(Box::new(union)).into())
+    }
+    _ => None,
+  }
+}
+
+fn infer_ts_type_from_obj_lit(
+  obj_lit: &ObjectLit,
+  is_const: bool,
+) -> Option<TsTypeDef> {
+  let mut methods = Vec::new();
+  let mut properties = Vec::new();
+  for prop in &obj_lit.props {
+    let prop = match prop {
+      PropOrSpread::Prop(prop) => prop,
+      // TODO(@kitsonk) we should recursively unwrap the spread here
+      PropOrSpread::Spread(_) => return None,
+    };
+    match prop.as_ref() {
+      Prop::KeyValue(key_value) => properties.push(LiteralPropertyDef {
+        name: obj_prop_name_to_name(&key_value.key),
+        params: vec![],
+        readonly: is_const,
+        computed: matches!(key_value.key, PropName::Computed(_)),
+        optional: false,
+        ts_type: infer_ts_type_from_expr(&key_value.value, is_const),
+        type_params: vec![],
+        doc: None,
+      }),
+      Prop::Shorthand(ident) => properties.push(LiteralPropertyDef {
+        name: ident.sym.to_string(),
+        params: vec![],
+        readonly: is_const,
+        computed: false,
+        optional: false,
+        ts_type: None,
+        type_params: vec![],
+        doc: None,
+      }),
+      Prop::Method(method) => methods.push(LiteralMethodDef {
+        name: obj_prop_name_to_name(&method.key),
+        kind: MethodKind::Method,
+        params: method
+          .function
+          .params
+          .iter()
+          .map(|param| pat_to_param_def(None, &param.pat))
+          .collect(),
+        computed: matches!(method.key, PropName::Computed(_)),
+        optional: false,
+        return_type: method
+          .function
+          .return_type
+          .as_ref()
+          .map(ts_type_ann_to_def)
+          .or_else(|| infer_return_ts_type(&method.function)),
+        type_params: maybe_type_param_decl_to_type_param_defs(
+          method.function.type_params.as_ref(),
+        ),
+      }),
+      Prop::Getter(getter) => methods.push(LiteralMethodDef {
+        name: obj_prop_name_to_name(&getter.key),
+        kind: MethodKind::Getter,
+        params: vec![],
+        computed: matches!(getter.key, PropName::Computed(_)),
+        optional: false,
+        return_type: getter.type_ann.as_ref().map(ts_type_ann_to_def).or_else(
+          || {
+            let body = getter.body.as_ref()?;
+            infer_return_ts_type_from_stmts(&body.stmts, false, false)
+          },
+        ),
+        type_params: vec![],
+      }),
+      Prop::Setter(setter) => methods.push(LiteralMethodDef {
+        name: obj_prop_name_to_name(&setter.key),
+        kind: MethodKind::Setter,
+        params: vec![pat_to_param_def(None, &setter.param)],
+        computed: matches!(setter.key, PropName::Computed(_)),
+        optional: false,
+        return_type: None,
+        type_params: vec![],
+      }),
+      // only valid in object patterns
+      Prop::Assign(_) => {}
+    }
+  }
+
+  Some(
+    TsTypeDefKind::TypeLiteral(Box::new(TsTypeLiteralDef {
+      methods,
+      properties,
+      call_signatures: vec![],
+      index_signatures: vec![],
+    }))
+    .into(),
+  )
+}
+
+fn obj_prop_name_to_name(prop_name: &PropName) -> String {
+  match prop_name {
+    PropName::Computed(computed) => expr_to_name(&computed.expr),
+    _ => prop_name_to_string(None, prop_name),
+  }
+}
+
+/// Returns the primitive keyword of the widened type of `expr`, e.g.
+/// `"number"` for `1`.
+fn infer_primitive_keyword(expr: &Expr) -> Option<String> {
+  match infer_ts_type_from_expr(expr, false)?.kind {
+    TsTypeDefKind::Keyword(keyword) => Some(keyword),
+    _ => None,
+  }
+}
+
+fn infer_ts_type_from_unary_expr(expr: &UnaryExpr) -> Option<TsTypeDef> {
+  match expr.op {
+    UnaryOp::Bang | UnaryOp::Delete => Some(TsTypeDef::keyword("boolean")),
+    UnaryOp::TypeOf => Some(TsTypeDef::keyword("string")),
+    UnaryOp::Void => Some(TsTypeDef::keyword("undefined")),
+    UnaryOp::Plus => Some(TsTypeDef::keyword("number")),
+    UnaryOp::Minus | UnaryOp::Tilde => {
+      match infer_primitive_keyword(&expr.arg).as_deref() {
+        Some("bigint") => Some(TsTypeDef::keyword("bigint")),
+        _ => Some(TsTypeDef::keyword("number")),
+      }
+    }
+  }
+}
+
+fn infer_ts_type_from_bin_expr(expr: &BinExpr) -> Option<TsTypeDef> {
+  match expr.op {
+    BinaryOp::EqEq
+    | BinaryOp::NotEq
+    | BinaryOp::EqEqEq
+    | BinaryOp::NotEqEq
+    | BinaryOp::Lt
+    | BinaryOp::LtEq
+    | BinaryOp::Gt
+    | BinaryOp::GtEq
+    | BinaryOp::In
+    | BinaryOp::InstanceOf => Some(TsTypeDef::keyword("boolean")),
+    BinaryOp::Add => {
+      let left = infer_primitive_keyword(&expr.left)?;
+      let right = infer_primitive_keyword(&expr.right)?;
+      if left == "string" || right == "string" {
+        Some(TsTypeDef::keyword("string"))
+      } else if left == right && (left == "number" || left == "bigint") {
+        Some(TsTypeDef::keyword(&left))
+      } else {
+        None
+      }
+    }
+    BinaryOp::ZeroFillRShift => Some(TsTypeDef::keyword("number")),
+    BinaryOp::Sub
+    | BinaryOp::Mul
+    | BinaryOp::Div
+    | BinaryOp::Mod
+    | BinaryOp::Exp
+    | BinaryOp::LShift
+    | BinaryOp::RShift
+    | BinaryOp::BitOr
+    | BinaryOp::BitXor
+    | BinaryOp::BitAnd => {
+      match infer_primitive_keyword(&expr.left).as_deref() {
+        Some("bigint") => Some(TsTypeDef::keyword("bigint")),
+        _ => Some(TsTypeDef::keyword("number")),
+      }
+    }
+    BinaryOp::LogicalOr
+    | BinaryOp::LogicalAnd
+    | BinaryOp::NullishCoalescing => {
+      infer_ts_type_from_alternatives(&expr.left, &expr.right)
+    }
+  }
+}
+
+fn infer_ts_type_from_cond_expr(expr: &CondExpr) -> Option<TsTypeDef> {
+  infer_ts_type_from_alternatives(&expr.cons, &expr.alt)
+}
+
+/// Infers the type of an expression evaluating to either `a` or `b`.
+fn infer_ts_type_from_alternatives(a: &Expr, b: &Expr) -> Option<TsTypeDef> {
+  let a = infer_ts_type_from_expr(a, false)?;
+  let b = infer_ts_type_from_expr(b, false)?;
+  if a == b {
+    Some(a)
+  } else {
+    Some(TsTypeDefKind::Union(vec![a, b]).into())
+  }
+}
+
+fn infer_ts_type_from_await_expr(expr: &AwaitExpr) -> Option<TsTypeDef> {
+  let ts_type = infer_ts_type_from_expr(&expr.arg, false)?;
+  if let TsTypeDefKind::TypeRef(type_ref) = &ts_type.kind {
+    if type_ref.type_name == "Promise" {
+      return match type_ref.type_params.as_deref() {
+        Some([ts_type]) => Some(ts_type.clone()),
+        _ => None,
       };
-      Some(TsTypeDef {
-        kind: Some(TsTypeDefKind::Array),
-        array: Some(Box::new(union)),
+    }
+  }
+  Some(ts_type)
+}
+
+/// Infers the return type of a function without a return type annotation
+/// from the `return` statements of its body. The result is marked as
+/// inferred.
+pub fn infer_return_ts_type(function: &Function) -> Option<TsTypeDef> {
+  let body = function.body.as_ref()?;
+  infer_return_ts_type_from_stmts(
+    &body.stmts,
+    function.is_async,
+    function.is_generator,
+  )
+}
+
+fn infer_ts_type_from_arrow_body(expr: &ArrowExpr) -> Option<TsTypeDef> {
+  match &expr.body {
+    BlockStmtOrExpr::BlockStmt(block) => infer_return_ts_type_from_stmts(
+      &block.stmts,
+      expr.is_async,
+      expr.is_generator,
+    ),
+    BlockStmtOrExpr::Expr(body) => {
+      let returns_and_yields = ReturnsAndYields {
+        returns: vec![infer_ts_type_from_expr(body, false)?],
+        returns_value: true,
         ..Default::default()
-      })
+      };
+      wrap_return_ts_type(returns_and_yields, expr.is_async, false)
     }
-    _ => None,
   }
 }
 
+fn infer_return_ts_type_from_stmts(
+  stmts: &[Stmt],
+  is_async: bool,
+  is_generator: bool,
+) -> Option<TsTypeDef> {
+  let mut returns_and_yields = ReturnsAndYields::default();
+  for stmt in stmts {
+    stmt.visit_with(&mut returns_and_yields);
+  }
+  // a body which can complete without a `return` statement returns
+  // `undefined`
+  if !stmts.iter().any(always_exits) {
+    returns_and_yields
+      .returns
+      .push(TsTypeDef::keyword("undefined"));
+  }
+  wrap_return_ts_type(returns_and_yields, is_async, is_generator)
+}
+
+/// Whether completing `stmt` always returns from the function or throws.
+fn always_exits(stmt: &Stmt) -> bool {
+  match stmt {
+    Stmt::Return(_) | Stmt::Throw(_) => true,
+    Stmt::Block(block) => block.stmts.iter().any(always_exits),
+    Stmt::If(if_stmt) => {
+      always_exits(&if_stmt.cons)
+        && if_stmt.alt.as_deref().map_or(false, always_exits)
+    }
+    Stmt::Labeled(labeled) => always_exits(&labeled.body),
+    Stmt::Try(try_stmt) => {
+      let finalizer_exits = try_stmt
+        .finalizer
+        .as_ref()
+        .map_or(false, |finalizer| finalizer.stmts.iter().any(always_exits));
+      let handler_exits = try_stmt
+        .handler
+        .as_ref()
+        .map_or(true, |handler| handler.body.stmts.iter().any(always_exits));
+      finalizer_exits
+        || (try_stmt.block.stmts.iter().any(always_exits) && handler_exits)
+    }
+    _ => false,
+  }
+}
+
+/// The types of the values which a function body returns and yields, as
+/// collected from its `return` statements and `yield` expressions, without
+/// descending into nested functions and classes.
+#[derive(Default)]
+struct ReturnsAndYields {
+  returns: Vec<TsTypeDef>,
+  /// Whether any `return` statement returns a value.
+  returns_value: bool,
+  yields: Vec<TsTypeDef>,
+  /// Whether the type of a returned or yielded value is not inferred.
+  unknown: bool,
+}
+
+impl ReturnsAndYields {
+  /// The type of the argument of a `return` statement or `yield` expression,
+  /// which is `undefined` without an argument.
+  fn arg_ts_type(&mut self, arg: Option<&Expr>) -> Option<TsTypeDef> {
+    let ts_type = match arg {
+      Some(arg) => infer_ts_type_from_expr(arg, false),
+      None => Some(TsTypeDef::keyword("undefined")),
+    };
+    if ts_type.is_none() {
+      self.unknown = true;
+    }
+    ts_type
+  }
+}
+
+impl Visit for ReturnsAndYields {
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+    self.returns_value |= return_stmt.arg.is_some();
+    if let Some(ts_type) = self.arg_ts_type(return_stmt.arg.as_deref()) {
+      self.returns.push(ts_type);
+    }
+    return_stmt.visit_children_with(self);
+  }
+
+  fn visit_yield_expr(&mut self, yield_expr: &YieldExpr) {
+    if yield_expr.delegate {
+      // the yielded type of a delegated iterable is not inferred
+      self.unknown = true;
+    } else if let Some(ts_type) = self.arg_ts_type(yield_expr.arg.as_deref()) {
+      self.yields.push(ts_type);
+    }
+    yield_expr.visit_children_with(self);
+  }
+
+  // the `return` statements and `yield` expressions of nested functions and
+  // classes are their own
+  fn visit_function(&mut self, _function: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr) {}
+
+  fn visit_getter_prop(&mut self, _getter_prop: &GetterProp) {}
+
+  fn visit_setter_prop(&mut self, _setter_prop: &SetterProp) {}
+
+  fn visit_class(&mut self, _class: &Class) {}
+}
+
+/// Returns the union of `types`, or the only type in it.
+fn union_ts_type(types: Vec<TsTypeDef>) -> TsTypeDef {
+  let mut members = Vec::new();
+  for ts_type in types {
+    if !members.contains(&ts_type) {
+      members.push(ts_type);
+    }
+  }
+  if members.len() == 1 {
+    members.remove(0)
+  } else {
+    TsTypeDefKind::Union(members).into()
+  }
+}
+
+/// Infers the return type of a function from the values it returns and
+/// yields, wrapping it in a `Promise` or generator type as needed.
+fn wrap_return_ts_type(
+  returns_and_yields: ReturnsAndYields,
+  is_async: bool,
+  is_generator: bool,
+) -> Option<TsTypeDef> {
+  if returns_and_yields.unknown {
+    return None;
+  }
+  let return_type = if returns_and_yields.returns_value {
+    union_ts_type(returns_and_yields.returns)
+  } else {
+    TsTypeDef::keyword("void")
+  };
+
+  let mut ts_type = if is_generator {
+    let yield_type = if returns_and_yields.yields.is_empty() {
+      TsTypeDef::keyword("never")
+    } else {
+      union_ts_type(returns_and_yields.yields)
+    };
+    let name = if is_async {
+      "AsyncGenerator"
+    } else {
+      "Generator"
+    };
+    TsTypeDef::type_ref(
+      name.to_string(),
+      Some(vec![yield_type, return_type, TsTypeDef::keyword("unknown")]),
+    )
+  } else if is_async {
+    TsTypeDef::type_ref("Promise".to_string(), Some(vec![return_type]))
+  } else {
+    return_type
+  };
+  ts_type.inferred = true;
+  Some(ts_type)
+}
+
 fn infer_ts_type_from_arrow_expr(expr: &ArrowExpr) -> Option<TsTypeDef> {
-  Some(TsTypeDef {
-    kind: Some(TsTypeDefKind::FnOrConstructor),
-    fn_or_constructor: Some(Box::new(expr.into())),
-    ..Default::default()
-  })
+  Some(TsTypeDefKind::FnOrConstructor(Box::new(expr.into())).into())
 }
 
 fn infer_ts_type_from_fn_expr(expr: &FnExpr) -> Option<TsTypeDef> {
-  Some(TsTypeDef {
-    kind: Some(TsTypeDefKind::FnOrConstructor),
-    fn_or_constructor: Some(Box::new(expr.into())),
-    ..Default::default()
-  })
+  Some(TsTypeDefKind::FnOrConstructor(Box::new(expr.into())).into())
 }
 
 fn infer_ts_type_from_const_assertion(
@@ -1433,6 +2244,10 @@ fn infer_ts_type_from_const_assertion(
       // e.g.) const n = ["a", 1] as const;
       infer_ts_type_from_arr_lit(arr_lit, true)
     }
+    Expr::Object(obj_lit) => {
+      // e.g.) const o = { a: 1 } as const;
+      infer_ts_type_from_obj_lit(obj_lit, true)
+    }
     _ => infer_ts_type_from_expr(&*assertion.expr, true),
   }
 }
@@ -1473,19 +2288,28 @@ fn infer_ts_type_from_lit(lit: &Lit, is_const: bool) -> Option<TsTypeDef> {
 }
 
 fn infer_ts_type_from_new_expr(new_expr: &NewExpr) -> Option<TsTypeDef> {
-  match new_expr.callee.as_ref() {
-    Expr::Ident(ident) => Some(TsTypeDef {
-      repr: ident.sym.to_string(),
-      kind: Some(TsTypeDefKind::TypeRef),
-      type_ref: Some(TsTypeRefDef {
-        type_params: new_expr
-          .type_args
-          .as_ref()
-          .map(|init| maybe_type_param_instantiation_to_type_defs(Some(init))),
-        type_name: ident.sym.to_string(),
-      }),
-      ..Default::default()
-    }),
+  Some(TsTypeDef::type_ref(
+    expr_to_type_name(&new_expr.callee)?,
+    new_expr
+      .type_args
+      .as_ref()
+      .map(|init| maybe_type_param_instantiation_to_type_defs(Some(init))),
+  ))
+}
+
+/// Returns the type name an identifier or a qualified name like `ns.Foo`
+/// refers to.
+fn expr_to_type_name(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(member_expr) => match &member_expr.prop {
+      MemberProp::Ident(ident) => Some(format!(
+        "{}.{}",
+        expr_to_type_name(&member_expr.obj)?,
+        ident.sym
+      )),
+      _ => None,
+    },
     _ => None,
   }
 }
@@ -1527,25 +2351,18 @@ fn infer_ts_type_from_tpl(tpl: &Tpl, is_const: bool) -> TsTypeDef {
 
 impl Display for TsTypeDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-    if self.kind.is_none() {
-      return write!(f, "{}", colors::red("[UNSUPPORTED]"));
-    }
-
-    let kind = self.kind.as_ref().unwrap();
-    match kind {
-      TsTypeDefKind::Array => {
-        let array = self.array.as_ref().unwrap();
+    match &self.kind {
+      TsTypeDefKind::Array(array) => {
         if matches!(
           array.kind,
-          Some(TsTypeDefKind::Union) | Some(TsTypeDefKind::Intersection)
+          TsTypeDefKind::Union(_) | TsTypeDefKind::Intersection(_)
         ) {
-          write!(f, "({})[]", &*self.array.as_ref().unwrap())
+          write!(f, "({})[]", &**array)
         } else {
-          write!(f, "{}[]", &*self.array.as_ref().unwrap())
+          write!(f, "{}[]", &**array)
         }
       }
-      TsTypeDefKind::Conditional => {
-        let conditional = self.conditional_type.as_ref().unwrap();
+      TsTypeDefKind::Conditional(conditional) => {
         write!(
           f,
           "{} {} {} ? {} : {}",
@@ -1556,12 +2373,10 @@ impl Display for TsTypeDef {
           &*conditional.false_type
         )
       }
-      TsTypeDefKind::Infer => {
-        let infer = self.infer.as_ref().unwrap();
+      TsTypeDefKind::Infer(infer) => {
         write!(f, "{} {}", colors::magenta("infer"), infer.type_param)
       }
-      TsTypeDefKind::ImportType => {
-        let import_type = self.import_type.as_ref().unwrap();
+      TsTypeDefKind::ImportType(import_type) => {
         write!(f, "import(\"{}\")", import_type.specifier)?;
         if let Some(qualifier) = &import_type.qualifier {
           write!(f, ".{}", qualifier)?;
@@ -1571,8 +2386,7 @@ impl Display for TsTypeDef {
         }
         Ok(())
       }
-      TsTypeDefKind::FnOrConstructor => {
-        let fn_or_constructor = self.fn_or_constructor.as_ref().unwrap();
+      TsTypeDefKind::FnOrConstructor(fn_or_constructor) => {
         write!(
           f,
           "{}({}) => {}",
@@ -1585,20 +2399,17 @@ impl Display for TsTypeDef {
           &fn_or_constructor.ts_type,
         )
       }
-      TsTypeDefKind::IndexedAccess => {
-        let indexed_access = self.indexed_access.as_ref().unwrap();
+      TsTypeDefKind::IndexedAccess(indexed_access) => {
         write!(
           f,
           "{}[{}]",
           &*indexed_access.obj_type, &*indexed_access.index_type
         )
       }
-      TsTypeDefKind::Intersection => {
-        let intersection = self.intersection.as_ref().unwrap();
+      TsTypeDefKind::Intersection(intersection) => {
         write!(f, "{}", SliceDisplayer::new(intersection, " & ", false))
       }
-      TsTypeDefKind::Mapped => {
-        let mapped_type = self.mapped_type.as_ref().unwrap();
+      TsTypeDefKind::Mapped(mapped_type) => {
         let readonly = match mapped_type.readonly {
           Some(TruePlusMinus::True) => {
             format!("{} ", colors::magenta("readonly"))
@@ -1639,71 +2450,62 @@ impl Display for TsTypeDef {
           readonly, type_param, name_type, optional, ts_type
         )
       }
-      TsTypeDefKind::Keyword => {
-        write!(f, "{}", colors::cyan(self.keyword.as_ref().unwrap()))
+      TsTypeDefKind::Keyword(keyword) => {
+        write!(f, "{}", colors::cyan(keyword))
       }
-      TsTypeDefKind::Literal => {
-        let literal = self.literal.as_ref().unwrap();
-        match literal.kind {
-          LiteralDefKind::Boolean => write!(
-            f,
-            "{}",
-            colors::yellow(&literal.boolean.unwrap().to_string())
-          ),
-          LiteralDefKind::String => write!(
-            f,
-            "{}",
-            colors::green(&format!("\"{}\"", literal.string.as_ref().unwrap()))
-          ),
-          LiteralDefKind::Template => {
-            write!(f, "{}", colors::green("`"))?;
-            for ts_type in literal.ts_types.as_ref().unwrap() {
-              let kind = ts_type.kind.as_ref().unwrap();
-              if *kind == TsTypeDefKind::Literal {
-                let literal = ts_type.literal.as_ref().unwrap();
-                if literal.kind == LiteralDefKind::String {
-                  write!(
-                    f,
-                    "{}",
-                    colors::green(literal.string.as_ref().unwrap())
-                  )?;
-                  continue;
-                }
+      TsTypeDefKind::Literal(literal) => match literal.kind {
+        LiteralDefKind::Boolean => write!(
+          f,
+          "{}",
+          colors::yellow(&literal.boolean.unwrap().to_string())
+        ),
+        LiteralDefKind::String => write!(
+          f,
+          "{}",
+          colors::green(&format!("\"{}\"", literal.string.as_ref().unwrap()))
+        ),
+        LiteralDefKind::Template => {
+          write!(f, "{}", colors::green("`"))?;
+          for ts_type in literal.ts_types.as_ref().unwrap() {
+            if let TsTypeDefKind::Literal(literal) = &ts_type.kind {
+              if literal.kind == LiteralDefKind::String {
+                write!(
+                  f,
+                  "{}",
+                  colors::green(literal.string.as_ref().unwrap())
+                )?;
+                continue;
               }
-              write!(
-                f,
-                "{}{}{}",
-                colors::magenta("${"),
-                ts_type,
-                colors::magenta("}")
-              )?;
             }
-            write!(f, "{}", colors::green("`"))
-          }
-          LiteralDefKind::Number => write!(
-            f,
-            "{}",
-            colors::yellow(&literal.number.unwrap().to_string())
-          ),
-          LiteralDefKind::BigInt => {
-            write!(f, "{}", colors::yellow(&literal.string.as_ref().unwrap()))
+            write!(
+              f,
+              "{}{}{}",
+              colors::magenta("${"),
+              ts_type,
+              colors::magenta("}")
+            )?;
           }
+          write!(f, "{}", colors::green("`"))
         }
+        LiteralDefKind::Number => write!(
+          f,
+          "{}",
+          colors::yellow(&literal.number.unwrap().to_string())
+        ),
+        LiteralDefKind::BigInt => {
+          write!(f, "{}", colors::yellow(&literal.string.as_ref().unwrap()))
+        }
+      },
+      TsTypeDefKind::Optional(optional) => write!(f, "{}?", &**optional),
+      TsTypeDefKind::Parenthesized(parenthesized) => {
+        write!(f, "({})", &**parenthesized)
       }
-      TsTypeDefKind::Optional => {
-        write!(f, "{}?", &*self.optional.as_ref().unwrap())
-      }
-      TsTypeDefKind::Parenthesized => {
-        write!(f, "({})", &*self.parenthesized.as_ref().unwrap())
-      }
-      TsTypeDefKind::Rest => write!(f, "...{}", &*self.rest.as_ref().unwrap()),
+      TsTypeDefKind::Rest(rest) => write!(f, "...{}", &**rest),
       TsTypeDefKind::This => write!(f, "this"),
-      TsTypeDefKind::Tuple => {
-        let tuple = self.tuple.as_ref().unwrap();
+      TsTypeDefKind::Tuple(tuple) => {
         write!(f, "[{}]", SliceDisplayer::new(tuple, ", ", false))
       }
-      TsTypeDefKind::TypeLiteral => {
-        let type_literal = self.type_literal.as_ref().unwrap();
+      TsTypeDefKind::TypeLiteral(type_literal) => {
         write!(
           f,
           "{{ {}{}{}{}}}",
@@ -1713,29 +2515,23 @@ impl Display for TsTypeDef {
           SliceDisplayer::new(&type_literal.index_signatures, "; ", true),
         )
       }
-      TsTypeDefKind::TypeOperator => {
-        let operator = self.type_operator.as_ref().unwrap();
+      TsTypeDefKind::TypeOperator(operator) => {
         write!(f, "{} {}", operator.operator, &operator.ts_type)
       }
-      TsTypeDefKind::TypeQuery => {
-        write!(f, "typeof {}", self.type_query.as_ref().unwrap())
+      TsTypeDefKind::TypeQuery(type_query) => {
+        write!(f, "typeof {}", type_query)
       }
-      TsTypeDefKind::TypeRef => {
-        let type_ref = self.type_ref.as_ref().unwrap();
+      TsTypeDefKind::TypeRef(type_ref) => {
         write!(f, "{}", colors::intense_blue(&type_ref.type_name))?;
         if let Some(type_params) = &type_ref.type_params {
           write!(f, "<{}>", SliceDisplayer::new(type_params, ", ", false))?;
         }
         Ok(())
       }
-      TsTypeDefKind::Union => {
-        let union = self.union.as_ref().unwrap();
+      TsTypeDefKind::Union(union) => {
         write!(f, "{}", SliceDisplayer::new(union, " | ", false))
       }
-      TsTypeDefKind::TypePredicate => {
-        let pred = self.type_predicate.as_ref().unwrap();
-        write!(f, "{}", pred)
-      }
+      TsTypeDefKind::TypePredicate(pred) => write!(f, "{}", pred),
     }
   }
 }
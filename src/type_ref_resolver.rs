@@ -0,0 +1,709 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassAncestorDef;
+use crate::class::ClassDef;
+use crate::function::FunctionDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceCallSignatureDef;
+use crate::interface::InterfaceDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocSegment;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::parser::DocParser;
+use crate::parser::Import;
+use crate::parser::ImportKind;
+use crate::swc_util::module_export_name_value;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TypeRefResolution;
+use crate::ts_type_param::TsTypeParamDef;
+use crate::type_alias::TypeAliasDef;
+use crate::visibility::is_hidden;
+use crate::visit::walk_class_ancestor_def_mut;
+use crate::visit::walk_class_def_mut;
+use crate::visit::walk_doc_node_mut;
+use crate::visit::walk_function_def_mut;
+use crate::visit::walk_interface_ancestor_def_mut;
+use crate::visit::walk_interface_call_signature_def_mut;
+use crate::visit::walk_interface_def_mut;
+use crate::visit::walk_interface_method_def_mut;
+use crate::visit::walk_interface_property_def_mut;
+use crate::visit::walk_ts_type_def;
+use crate::visit::walk_ts_type_def_mut;
+use crate::visit::walk_type_alias_def_mut;
+use crate::visit::DocVisitor;
+use crate::visit::DocVisitorMut;
+
+use deno_ast::swc::ast::Decl;
+use deno_ast::swc::ast::ExportSpecifier;
+use deno_ast::swc::ast::Expr;
+use deno_ast::swc::ast::ModuleDecl;
+use deno_ast::swc::ast::ModuleItem;
+use deno_graph::ModuleSpecifier;
+use deno_graph::Resolved;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// How deep chains of reexports are followed.
+const MAX_REEXPORT_DEPTH: usize = 16;
+
+/// How a module exports one of its names.
+enum Export {
+  /// A symbol declared by the module, by its local name.
+  Local(String),
+  /// A symbol of another module, reexported by the module.
+  Import(Import),
+}
+
+/// The enclosing namespaces of a doc node, outermost first, by name and with
+/// the names of their elements.
+pub(crate) type Namespaces = Vec<(String, HashSet<String>)>;
+
+/// The names a module declares, imports and exports.
+struct ModuleScope {
+  /// The doc nodes of the symbols declared by the module, by name. The doc
+  /// node of a default export declaration is found under `default`.
+  symbols: HashMap<String, DocNode>,
+  imports: HashMap<String, Import>,
+  exports: HashMap<String, Export>,
+  /// The sources of `export * from "..."` declarations.
+  star_reexports: Vec<String>,
+}
+
+/// Resolves the type references of doc nodes to the symbols they refer to, by
+/// looking up the type parameters in scope, the symbols of the enclosing
+/// namespaces and the symbols declared by or imported into the module of each
+/// doc node. Imports are followed through reexports to the module declaring
+/// the symbol.
+pub(crate) struct TypeRefResolver<'p, 'a> {
+  parser: &'p DocParser<'a>,
+  scopes: HashMap<ModuleSpecifier, Option<Rc<ModuleScope>>>,
+  specifier: Option<ModuleSpecifier>,
+  scope: Option<Rc<ModuleScope>>,
+  namespaces: Namespaces,
+  type_params: Vec<String>,
+}
+
+impl<'p, 'a> TypeRefResolver<'p, 'a> {
+  pub fn new(parser: &'p DocParser<'a>) -> Self {
+    Self {
+      parser,
+      scopes: HashMap::new(),
+      specifier: None,
+      scope: None,
+      namespaces: Vec::new(),
+      type_params: Vec::new(),
+    }
+  }
+
+  fn with_type_params(
+    &mut self,
+    type_params: Vec<String>,
+    f: impl FnOnce(&mut Self),
+  ) {
+    let len = self.type_params.len();
+    self.type_params.extend(type_params);
+    f(self);
+    self.type_params.truncate(len);
+  }
+
+  /// Resolves within `f` in the scope of the module `specifier`, outside of
+  /// any namespace.
+  fn in_module(
+    &mut self,
+    specifier: Option<ModuleSpecifier>,
+    f: impl FnOnce(&mut Self),
+  ) {
+    let scope = specifier.as_ref().and_then(|s| self.module_scope(s));
+    let specifier = std::mem::replace(&mut self.specifier, specifier);
+    let scope = std::mem::replace(&mut self.scope, scope);
+    let namespaces = std::mem::take(&mut self.namespaces);
+    f(self);
+    self.specifier = specifier;
+    self.scope = scope;
+    self.namespaces = namespaces;
+  }
+
+  /// Resolves `type_name` as referenced by a doc node of the module at
+  /// `specifier`, which is enclosed by `namespaces`.
+  pub fn resolve_in(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    namespaces: Namespaces,
+    type_name: &str,
+  ) -> TypeRefResolution {
+    let scope = self.module_scope(specifier);
+    let specifier =
+      std::mem::replace(&mut self.specifier, Some(specifier.clone()));
+    let scope = std::mem::replace(&mut self.scope, scope);
+    let namespaces = std::mem::replace(&mut self.namespaces, namespaces);
+    let type_params = std::mem::take(&mut self.type_params);
+    let resolution = self.resolve(type_name);
+    self.specifier = specifier;
+    self.scope = scope;
+    self.namespaces = namespaces;
+    self.type_params = type_params;
+    resolution
+  }
+
+  /// Finds the doc node of the symbol at `symbol_path` declared by the module
+  /// at `specifier`, along with the namespaces enclosing it.
+  pub fn find_symbol(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    symbol_path: &[String],
+  ) -> Option<(DocNode, Namespaces)> {
+    let scope = self.module_scope(specifier)?;
+    let mut doc_node = scope.symbols.get(symbol_path.first()?)?;
+    let mut namespaces = Vec::new();
+    for name in &symbol_path[1..] {
+      namespaces.push(namespace_scope(doc_node)?);
+      doc_node = doc_node
+        .namespace_def
+        .as_ref()?
+        .elements
+        .iter()
+        .find(|element| &element.name == name)?;
+    }
+    Some((doc_node.clone(), namespaces))
+  }
+
+  fn resolve(&mut self, type_name: &str) -> TypeRefResolution {
+    let path = type_name.split('.').map(String::from).collect::<Vec<_>>();
+    if path.len() == 1 && self.type_params.contains(&path[0]) {
+      return TypeRefResolution::TypeParam;
+    }
+
+    let specifier = match &self.specifier {
+      Some(specifier) => specifier.clone(),
+      None => return TypeRefResolution::Unresolved,
+    };
+    if let Some(i) = self
+      .namespaces
+      .iter()
+      .rposition(|(_, elements)| elements.contains(&path[0]))
+    {
+      let mut symbol_path = self.namespaces[..=i]
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+      symbol_path.extend(path);
+      return self.symbol(&specifier, symbol_path);
+    }
+
+    let scope = match &self.scope {
+      Some(scope) => scope.clone(),
+      None => return TypeRefResolution::Unresolved,
+    };
+    if scope.symbols.contains_key(&path[0]) {
+      return self.symbol(&specifier, path);
+    }
+    if let Some(import) = scope.imports.get(&path[0]) {
+      if let Some((specifier, symbol_path)) =
+        self.resolve_import(&specifier, import, &path[1..], 0)
+      {
+        return self.symbol(&specifier, symbol_path);
+      }
+    }
+
+    TypeRefResolution::Unresolved
+  }
+
+  fn symbol(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    symbol_path: Vec<String>,
+  ) -> TypeRefResolution {
+    let hidden = self.is_hidden_symbol(specifier, &symbol_path);
+    TypeRefResolution::Symbol {
+      specifier: specifier.to_string(),
+      symbol_path,
+      hidden,
+    }
+  }
+
+  /// Whether the symbol at `symbol_path` declared by the module at
+  /// `specifier`, or one of the namespaces enclosing it, is hidden from the
+  /// docs.
+  fn is_hidden_symbol(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    symbol_path: &[String],
+  ) -> bool {
+    let strip_internal = self.parser.strip_internal;
+    let scope = match self.module_scope(specifier) {
+      Some(scope) => scope,
+      None => return false,
+    };
+    let mut doc_node = match scope.symbols.get(&symbol_path[0]) {
+      Some(doc_node) => doc_node,
+      None => return false,
+    };
+    for name in &symbol_path[1..] {
+      if is_hidden(&doc_node.js_doc, strip_internal) {
+        return true;
+      }
+      // a member of an enum, e.g. `Mode.Debug`
+      if let Some(enum_def) = &doc_node.enum_def {
+        return enum_def
+          .members
+          .iter()
+          .find(|member| &member.name == name)
+          .map_or(false, |member| is_hidden(&member.js_doc, strip_internal));
+      }
+      let element = doc_node.namespace_def.as_ref().and_then(|namespace_def| {
+        namespace_def
+          .elements
+          .iter()
+          .find(|element| &element.name == name)
+      });
+      doc_node = match element {
+        Some(element) => element,
+        None => return false,
+      };
+    }
+    is_hidden(&doc_node.js_doc, strip_internal)
+  }
+
+  /// Resolves `path` within what `import` of the module at `referrer` refers
+  /// to. When the imported module cannot be looked into, the imported name is
+  /// used as is.
+  fn resolve_import(
+    &mut self,
+    referrer: &ModuleSpecifier,
+    import: &Import,
+    path: &[String],
+    depth: usize,
+  ) -> Option<(ModuleSpecifier, Vec<String>)> {
+    let specifier = self.resolve_dependency(&import.src, referrer)?;
+    let path = match &import.kind {
+      ImportKind::Named(local, imported) => {
+        let mut imported_path =
+          vec![imported.clone().unwrap_or_else(|| local.clone())];
+        imported_path.extend(path.iter().cloned());
+        imported_path
+      }
+      ImportKind::Namespace(_) => path.to_vec(),
+    };
+    if path.is_empty() {
+      return None;
+    }
+
+    match self.resolve_export(&specifier, &path, depth + 1) {
+      Some(resolved) => Some(resolved),
+      None => Some((specifier, path)),
+    }
+  }
+
+  /// Resolves `path` within the exports of the module at `specifier`.
+  fn resolve_export(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    path: &[String],
+    depth: usize,
+  ) -> Option<(ModuleSpecifier, Vec<String>)> {
+    if depth > MAX_REEXPORT_DEPTH {
+      return None;
+    }
+
+    let scope = self.module_scope(specifier)?;
+    match scope.exports.get(&path[0]) {
+      Some(Export::Local(local)) => {
+        let mut symbol_path = vec![local.clone()];
+        symbol_path.extend(path[1..].iter().cloned());
+        Some((specifier.clone(), symbol_path))
+      }
+      Some(Export::Import(import)) => {
+        self.resolve_import(specifier, import, &path[1..], depth)
+      }
+      // `export *` does not reexport the default export
+      None if path[0] == "default" => None,
+      None => scope.star_reexports.iter().find_map(|src| {
+        let star_specifier = self.resolve_dependency(src, specifier)?;
+        self.resolve_export(&star_specifier, path, depth + 1)
+      }),
+    }
+  }
+
+  /// Resolves a dependency of a module, preferring the module which provides
+  /// its types.
+  fn resolve_dependency(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<ModuleSpecifier> {
+    let specifier = self
+      .parser
+      .graph
+      .resolve_dependency(specifier, referrer, true)?;
+    let module = self.parser.graph.try_get(specifier).ok().flatten();
+    if let Some(module) = module {
+      if let Some((_, Resolved::Ok { specifier, .. })) =
+        &module.maybe_types_dependency
+      {
+        return Some(specifier.clone());
+      }
+    }
+    Some(specifier.clone())
+  }
+
+  fn module_scope(
+    &mut self,
+    specifier: &ModuleSpecifier,
+  ) -> Option<Rc<ModuleScope>> {
+    if let Some(scope) = self.scopes.get(specifier) {
+      return scope.clone();
+    }
+    let scope = self.load_module_scope(specifier).map(Rc::new);
+    self.scopes.insert(specifier.clone(), scope.clone());
+    scope
+  }
+
+  fn load_module_scope(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Option<ModuleScope> {
+    let module = self.parser.graph.try_get(specifier).ok().flatten()?;
+    let source_code = module.maybe_source.clone()?;
+    let parsed_source = self
+      .parser
+      .ast_parser
+      .parse_module(&module.specifier, source_code, module.media_type)
+      .ok()?;
+    let module_body = &parsed_source.module().body;
+
+    let mut symbols = self
+      .parser
+      .get_symbols_for_module_body(&parsed_source, module_body);
+    let imports = self.parser.get_imports_for_module_body(module_body);
+    let mut exports = HashMap::new();
+    let mut star_reexports = Vec::new();
+
+    for node in module_body {
+      let module_decl = match node {
+        ModuleItem::ModuleDecl(module_decl) => module_decl,
+        ModuleItem::Stmt(_) => continue,
+      };
+      match module_decl {
+        ModuleDecl::ExportDecl(export_decl) => {
+          if let Some(name) = decl_name(&export_decl.decl) {
+            exports.insert(name.clone(), Export::Local(name));
+          }
+        }
+        ModuleDecl::ExportDefaultDecl(_) => {
+          for doc_node in self
+            .parser
+            .get_doc_nodes_for_module_exports(&parsed_source, module_decl)
+          {
+            symbols.insert("default".to_string(), doc_node);
+          }
+          exports.insert(
+            "default".to_string(),
+            Export::Local("default".to_string()),
+          );
+        }
+        ModuleDecl::ExportDefaultExpr(export_expr) => {
+          if let Expr::Ident(ident) = export_expr.expr.as_ref() {
+            let name = ident.sym.to_string();
+            let export = match imports.get(&name) {
+              Some(import) => Export::Import(import.clone()),
+              None => Export::Local(name),
+            };
+            exports.insert("default".to_string(), export);
+          }
+        }
+        ModuleDecl::ExportNamed(named_export) => {
+          for specifier in &named_export.specifiers {
+            let (exported, export) = match (specifier, &named_export.src) {
+              (ExportSpecifier::Named(named), Some(src)) => {
+                let orig = module_export_name_value(&named.orig);
+                let exported = named
+                  .exported
+                  .as_ref()
+                  .map(module_export_name_value)
+                  .unwrap_or_else(|| orig.clone());
+                let import = Import {
+                  src: src.value.to_string(),
+                  kind: ImportKind::Named(exported.clone(), Some(orig)),
+                };
+                (exported, Export::Import(import))
+              }
+              (ExportSpecifier::Named(named), None) => {
+                let orig = module_export_name_value(&named.orig);
+                let exported = named
+                  .exported
+                  .as_ref()
+                  .map(module_export_name_value)
+                  .unwrap_or_else(|| orig.clone());
+                let export = match imports.get(&orig) {
+                  Some(import) => Export::Import(import.clone()),
+                  None => Export::Local(orig),
+                };
+                (exported, export)
+              }
+              (ExportSpecifier::Namespace(namespace), Some(src)) => {
+                let exported = module_export_name_value(&namespace.name);
+                let import = Import {
+                  src: src.value.to_string(),
+                  kind: ImportKind::Namespace(exported.clone()),
+                };
+                (exported, Export::Import(import))
+              }
+              (ExportSpecifier::Default(default), Some(src)) => {
+                let exported = default.exported.sym.to_string();
+                let import = Import {
+                  src: src.value.to_string(),
+                  kind: ImportKind::Named(
+                    exported.clone(),
+                    Some("default".to_string()),
+                  ),
+                };
+                (exported, Export::Import(import))
+              }
+              _ => continue,
+            };
+            exports.insert(exported, export);
+          }
+        }
+        ModuleDecl::ExportAll(export_all) => {
+          star_reexports.push(export_all.src.value.to_string());
+        }
+        _ => {}
+      }
+    }
+
+    self.parser.parse_custom_tags(symbols.values_mut());
+    Some(ModuleScope {
+      symbols,
+      imports,
+      exports,
+      star_reexports,
+    })
+  }
+}
+
+/// The name of a namespace doc node, with the names of its elements.
+pub(crate) fn namespace_scope(
+  doc_node: &DocNode,
+) -> Option<(String, HashSet<String>)> {
+  let elements = doc_node
+    .namespace_def
+    .as_ref()?
+    .elements
+    .iter()
+    .map(|element| element.name.clone())
+    .collect();
+  Some((doc_node.name.clone(), elements))
+}
+
+fn decl_name(decl: &Decl) -> Option<String> {
+  match decl {
+    Decl::Class(class_decl) => Some(class_decl.ident.sym.to_string()),
+    Decl::Fn(fn_decl) => Some(fn_decl.ident.sym.to_string()),
+    Decl::TsInterface(interface_decl) => {
+      Some(interface_decl.id.sym.to_string())
+    }
+    Decl::TsTypeAlias(type_alias_decl) => {
+      Some(type_alias_decl.id.sym.to_string())
+    }
+    Decl::TsEnum(enum_decl) => Some(enum_decl.id.sym.to_string()),
+    Decl::TsModule(module_decl) => match &module_decl.id {
+      deno_ast::swc::ast::TsModuleName::Ident(ident) => {
+        Some(ident.sym.to_string())
+      }
+      deno_ast::swc::ast::TsModuleName::Str(_) => None,
+    },
+    // variables are not types
+    Decl::Var(_) => None,
+  }
+}
+
+fn type_param_names<'a>(
+  type_params: impl IntoIterator<Item = &'a TsTypeParamDef>,
+) -> Vec<String> {
+  type_params
+    .into_iter()
+    .map(|type_param| type_param.name.clone())
+    .collect()
+}
+
+/// Collects the names of the type parameters declared with `infer`.
+struct InferNames(Vec<String>);
+
+impl DocVisitor for InferNames {
+  fn visit_ts_type_def(&mut self, ts_type_def: &TsTypeDef) {
+    if let TsTypeDefKind::Infer(infer) = &ts_type_def.kind {
+      self.0.push(infer.type_param.name.clone());
+    }
+    walk_ts_type_def(self, ts_type_def);
+  }
+}
+
+impl DocVisitorMut for TypeRefResolver<'_, '_> {
+  fn visit_doc_node_mut(&mut self, doc_node: &mut DocNode) {
+    // doc nodes of reexports are declared in other modules than the doc nodes
+    // around them
+    let specifier = ModuleSpecifier::parse(&doc_node.location.filename).ok();
+    if specifier != self.specifier {
+      self
+        .in_module(specifier, |resolver| resolver.visit_doc_node_mut(doc_node));
+      return;
+    }
+
+    if let Some(namespace) = namespace_scope(doc_node) {
+      self.namespaces.push(namespace);
+      walk_doc_node_mut(self, doc_node);
+      self.namespaces.pop();
+    } else {
+      walk_doc_node_mut(self, doc_node);
+    }
+  }
+
+  fn visit_js_doc_mut(&mut self, js_doc: &mut JsDoc) {
+    let tag_segments =
+      js_doc.tags.iter_mut().filter_map(JsDocTag::segments_mut);
+    for segments in std::iter::once(&mut js_doc.segments).chain(tag_segments) {
+      for segment in segments {
+        if let JsDocSegment::Link {
+          target, resolution, ..
+        } = segment
+        {
+          // URLs are not symbols
+          if !target.contains("://") {
+            let type_name = target.trim_end_matches("()").replace('#', ".");
+            *resolution = Some(self.resolve(&type_name));
+          }
+        }
+      }
+    }
+  }
+
+  fn visit_function_def_mut(&mut self, function_def: &mut FunctionDef) {
+    self.with_type_params(type_param_names(&function_def.type_params), |v| {
+      walk_function_def_mut(v, function_def)
+    });
+  }
+
+  fn visit_class_def_mut(&mut self, class_def: &mut ClassDef) {
+    self.with_type_params(type_param_names(&class_def.type_params), |v| {
+      walk_class_def_mut(v, class_def)
+    });
+  }
+
+  fn visit_type_alias_def_mut(&mut self, type_alias_def: &mut TypeAliasDef) {
+    self.with_type_params(type_param_names(&type_alias_def.type_params), |v| {
+      walk_type_alias_def_mut(v, type_alias_def)
+    });
+  }
+
+  fn visit_interface_def_mut(&mut self, interface_def: &mut InterfaceDef) {
+    self.with_type_params(type_param_names(&interface_def.type_params), |v| {
+      walk_interface_def_mut(v, interface_def)
+    });
+  }
+
+  // the members inherited from an ancestor are declared in its module
+  fn visit_class_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut ClassAncestorDef,
+  ) {
+    let specifier =
+      ModuleSpecifier::parse(&ancestor_def.location.filename).ok();
+    if specifier == self.specifier {
+      walk_class_ancestor_def_mut(self, ancestor_def);
+    } else {
+      self
+        .in_module(specifier, |v| walk_class_ancestor_def_mut(v, ancestor_def));
+    }
+  }
+
+  fn visit_interface_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut InterfaceAncestorDef,
+  ) {
+    let specifier =
+      ModuleSpecifier::parse(&ancestor_def.location.filename).ok();
+    if specifier == self.specifier {
+      walk_interface_ancestor_def_mut(self, ancestor_def);
+    } else {
+      self.in_module(specifier, |v| {
+        walk_interface_ancestor_def_mut(v, ancestor_def)
+      });
+    }
+  }
+
+  fn visit_interface_method_def_mut(
+    &mut self,
+    method_def: &mut InterfaceMethodDef,
+  ) {
+    self.with_type_params(type_param_names(&method_def.type_params), |v| {
+      walk_interface_method_def_mut(v, method_def)
+    });
+  }
+
+  fn visit_interface_property_def_mut(
+    &mut self,
+    property_def: &mut InterfacePropertyDef,
+  ) {
+    self.with_type_params(type_param_names(&property_def.type_params), |v| {
+      walk_interface_property_def_mut(v, property_def)
+    });
+  }
+
+  fn visit_interface_call_signature_def_mut(
+    &mut self,
+    call_signature_def: &mut InterfaceCallSignatureDef,
+  ) {
+    self.with_type_params(
+      type_param_names(&call_signature_def.type_params),
+      |v| walk_interface_call_signature_def_mut(v, call_signature_def),
+    );
+  }
+
+  fn visit_ts_type_def_mut(&mut self, ts_type_def: &mut TsTypeDef) {
+    let type_params = match &ts_type_def.kind {
+      TsTypeDefKind::FnOrConstructor(fn_or_constructor) => {
+        type_param_names(&fn_or_constructor.type_params)
+      }
+      TsTypeDefKind::Mapped(mapped_type) => {
+        vec![mapped_type.type_param.name.clone()]
+      }
+      TsTypeDefKind::Conditional(conditional) => {
+        let mut infer_names = InferNames(Vec::new());
+        infer_names.visit_ts_type_def(&conditional.extends_type);
+        infer_names.0
+      }
+      // the type parameters of the members of a type literal are in scope
+      // for the whole type literal
+      TsTypeDefKind::TypeLiteral(type_literal) => type_param_names(
+        type_literal
+          .methods
+          .iter()
+          .flat_map(|method| &method.type_params)
+          .chain(
+            type_literal
+              .properties
+              .iter()
+              .flat_map(|property| &property.type_params),
+          )
+          .chain(
+            type_literal
+              .call_signatures
+              .iter()
+              .flat_map(|call_signature| &call_signature.type_params),
+          ),
+      ),
+      _ => Vec::new(),
+    };
+    if let TsTypeDefKind::TypeRef(type_ref) = &mut ts_type_def.kind {
+      type_ref.resolution = Some(self.resolve(&type_ref.type_name));
+    }
+    self
+      .with_type_params(type_params, |v| walk_ts_type_def_mut(v, ts_type_def));
+  }
+}
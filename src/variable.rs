@@ -1,10 +1,34 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+use deno_ast::swc::ast::Class;
+use deno_ast::swc::ast::ClassExpr;
+use deno_ast::swc::ast::Decl;
+use deno_ast::swc::ast::DefaultDecl;
+use deno_ast::swc::ast::Expr;
+use deno_ast::swc::ast::FnExpr;
+use deno_ast::swc::ast::Function;
+use deno_ast::swc::ast::MemberProp;
+use deno_ast::swc::ast::ModuleDecl;
+use deno_ast::swc::ast::ModuleItem;
+use deno_ast::swc::ast::Pat;
+use deno_ast::swc::ast::Stmt;
+use deno_ast::swc::ast::TsNamespaceBody;
+use deno_ast::swc::ast::VarDeclKind;
+use deno_ast::swc::ast::VarDeclarator;
+use deno_ast::swc::common::Span;
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use deno_ast::ParsedSource;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 
+use crate::function::function_to_function_def;
 use crate::ts_type::infer_simple_ts_type_from_var_decl;
 use crate::ts_type::ts_type_ann_to_def;
+use crate::ts_type::TsFnOrConstructorDef;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -16,31 +40,281 @@ pub struct VariableDef {
 // TODO: change this function to return Vec<(String, VariableDef)> as single
 // var declaration can have multiple declarators
 pub fn get_doc_for_var_decl(
+  parsed_source: &ParsedSource,
+  symbols: &ModuleSymbols,
   var_decl: &deno_ast::swc::ast::VarDecl,
 ) -> (String, VariableDef) {
   assert!(!var_decl.decls.is_empty());
   let var_declarator = var_decl.decls.get(0).unwrap();
   let var_name = match &var_declarator.name {
-    deno_ast::swc::ast::Pat::Ident(ident) => ident.id.sym.to_string(),
+    Pat::Ident(ident) => ident.id.sym.to_string(),
     _ => "[UNSUPPORTED]".to_string(),
   };
 
   let maybe_ts_type = match &var_declarator.name {
-    deno_ast::swc::ast::Pat::Ident(ident) => {
-      ident.type_ann.as_ref().map(ts_type_ann_to_def)
-    }
+    Pat::Ident(ident) => ident.type_ann.as_ref().map(ts_type_ann_to_def),
     _ => None,
   };
 
   let variable_def = VariableDef {
-    ts_type: maybe_ts_type.or_else(|| {
-      infer_simple_ts_type_from_var_decl(
-        var_declarator,
-        var_decl.kind == deno_ast::swc::ast::VarDeclKind::Const,
-      )
-    }),
+    ts_type: maybe_ts_type
+      .or_else(|| {
+        infer_simple_ts_type_from_var_decl(
+          var_declarator,
+          var_decl.kind == deno_ast::swc::ast::VarDeclKind::Const,
+        )
+      })
+      .or_else(|| {
+        let init = var_declarator.init.as_ref()?;
+        let mut ts_type = infer_ts_type_from_symbol_ref(
+          parsed_source,
+          symbols,
+          var_decl.span,
+          init,
+        )?;
+        ts_type.inferred = true;
+        Some(ts_type)
+      }),
     kind: var_decl.kind,
   };
 
   (var_name, variable_def)
 }
+
+/// Infers the type of an expression referring to a declaration of the
+/// module, e.g. `createHandler` or `pkg.version`, looking up the declaration
+/// from `scope`, the span of the declaration referring to it.
+fn infer_ts_type_from_symbol_ref(
+  parsed_source: &ParsedSource,
+  symbols: &ModuleSymbols,
+  scope: Span,
+  expr: &Expr,
+) -> Option<TsTypeDef> {
+  match expr {
+    Expr::Ident(ident) => {
+      get_ts_type_for_symbol(parsed_source, symbols.get(scope, &ident.sym)?)
+    }
+    Expr::Member(member_expr) => {
+      let name = match &member_expr.prop {
+        MemberProp::Ident(ident) => ident.sym.to_string(),
+        _ => return None,
+      };
+      let obj_type = infer_ts_type_from_symbol_ref(
+        parsed_source,
+        symbols,
+        scope,
+        &member_expr.obj,
+      )?;
+      match obj_type.kind {
+        TsTypeDefKind::TypeLiteral(type_literal) => {
+          type_literal
+            .properties
+            .into_iter()
+            .find(|property| property.name == name)?
+            .ts_type
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// A declaration of a function, class or variable, as referred to by the
+/// initializers of variables. Functions and variables are kept by their span
+/// and looked up in the module again when referred to.
+enum SymbolDecl {
+  Fn(Span),
+  Class(String),
+  Var(VarDeclKind, Span),
+}
+
+/// The functions, classes and variables declared in the body of a module and
+/// in the bodies of the namespaces within it, by name.
+#[derive(Default)]
+pub(crate) struct ModuleSymbols {
+  /// The span of each body with the declarations in it, where the bodies of
+  /// namespaces follow the bodies they are declared in.
+  scopes: Vec<(Span, HashMap<String, Vec<SymbolDecl>>)>,
+}
+
+impl ModuleSymbols {
+  pub fn new(parsed_source: &ParsedSource) -> Self {
+    let module = parsed_source.module();
+    let mut symbols = Self::default();
+    symbols.add_scope(module.span, &module.body);
+    symbols
+  }
+
+  fn add_scope(&mut self, span: Span, body: &[ModuleItem]) {
+    let index = self.scopes.len();
+    self.scopes.push((span, HashMap::new()));
+    for item in body {
+      let decl = match item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+          &export_decl.decl
+        }
+        // a named default export is declared in the module as well
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+          export_default_decl,
+        )) => {
+          match &export_default_decl.decl {
+            DefaultDecl::Fn(FnExpr {
+              ident: Some(ident),
+              function,
+            }) => self.insert(
+              index,
+              ident.sym.to_string(),
+              SymbolDecl::Fn(function.span),
+            ),
+            DefaultDecl::Class(ClassExpr {
+              ident: Some(ident), ..
+            }) => {
+              let name = ident.sym.to_string();
+              self.insert(index, name.clone(), SymbolDecl::Class(name));
+            }
+            _ => {}
+          }
+          continue;
+        }
+        _ => continue,
+      };
+      match decl {
+        Decl::Fn(fn_decl) => self.insert(
+          index,
+          fn_decl.ident.sym.to_string(),
+          SymbolDecl::Fn(fn_decl.function.span),
+        ),
+        Decl::Class(class_decl) => {
+          let name = class_decl.ident.sym.to_string();
+          self.insert(index, name.clone(), SymbolDecl::Class(name));
+        }
+        Decl::Var(var_decl) => {
+          for var_declarator in &var_decl.decls {
+            if let Pat::Ident(ident) = &var_declarator.name {
+              self.insert(
+                index,
+                ident.id.sym.to_string(),
+                SymbolDecl::Var(var_decl.kind, var_declarator.span),
+              );
+            }
+          }
+        }
+        Decl::TsModule(ts_module_decl) => {
+          let mut body = ts_module_decl.body.as_ref();
+          // `namespace a.b {}` declares the namespace `b` within `a`
+          while let Some(TsNamespaceBody::TsNamespaceDecl(namespace_decl)) =
+            body
+          {
+            body = Some(&*namespace_decl.body);
+          }
+          if let Some(TsNamespaceBody::TsModuleBlock(block)) = body {
+            self.add_scope(block.span, &block.body);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn insert(&mut self, index: usize, name: String, decl: SymbolDecl) {
+    self.scopes[index].1.entry(name).or_default().push(decl);
+  }
+
+  /// The declaration named `name` in the innermost body around `scope` which
+  /// declares the name, unless that body declares it more than once, e.g.
+  /// as a function overload, or as a class merged with a namespace.
+  fn get(&self, scope: Span, name: &str) -> Option<&SymbolDecl> {
+    let decls = self
+      .scopes
+      .iter()
+      .rev()
+      .filter(|(span, _)| span.lo <= scope.lo && scope.hi <= span.hi)
+      .find_map(|(_, decls)| decls.get(name))?;
+    match decls.as_slice() {
+      [decl] => Some(decl),
+      _ => None,
+    }
+  }
+}
+
+/// Returns the type of a function, class or variable declaration.
+fn get_ts_type_for_symbol(
+  parsed_source: &ParsedSource,
+  decl: &SymbolDecl,
+) -> Option<TsTypeDef> {
+  match decl {
+    SymbolDecl::Fn(span) | SymbolDecl::Var(_, span) => {
+      let mut finder = SymbolDeclFinder {
+        parsed_source,
+        decl,
+        span: *span,
+        ts_type: None,
+      };
+      parsed_source.module().visit_with(&mut finder);
+      finder.ts_type
+    }
+    SymbolDecl::Class(name) => Some(TsTypeDef::with_repr(
+      name,
+      TsTypeDefKind::TypeQuery(name.to_string()),
+    )),
+  }
+}
+
+/// Finds the function or variable declarator of a declaration by its span,
+/// only descending into the items of the bodies around it, and returns its
+/// type.
+struct SymbolDeclFinder<'a> {
+  parsed_source: &'a ParsedSource,
+  decl: &'a SymbolDecl,
+  span: Span,
+  ts_type: Option<TsTypeDef>,
+}
+
+impl Visit for SymbolDeclFinder<'_> {
+  fn visit_module_item(&mut self, item: &ModuleItem) {
+    let span = item.span();
+    if span.lo <= self.span.lo && self.span.hi <= span.hi {
+      item.visit_children_with(self);
+    }
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    if matches!(self.decl, SymbolDecl::Fn(_)) && function.span == self.span {
+      let function_def = function_to_function_def(self.parsed_source, function);
+      let fn_def = TsFnOrConstructorDef {
+        constructor: false,
+        ts_type: function_def
+          .return_type
+          .unwrap_or_else(|| TsTypeDef::keyword("unknown")),
+        params: function_def.params,
+        type_params: function_def.type_params,
+        return_doc: None,
+      };
+      self.ts_type =
+        Some(TsTypeDefKind::FnOrConstructor(Box::new(fn_def)).into());
+    }
+  }
+
+  fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+    let kind = match self.decl {
+      SymbolDecl::Var(kind, _) if var_declarator.span == self.span => *kind,
+      _ => return,
+    };
+    // variables without an annotation are only inferred from their
+    // initializers, so references between variables can't cycle
+    if let Pat::Ident(ident) = &var_declarator.name {
+      self.ts_type =
+        ident.type_ann.as_ref().map(ts_type_ann_to_def).or_else(|| {
+          infer_simple_ts_type_from_var_decl(
+            var_declarator,
+            kind == VarDeclKind::Const,
+          )
+        });
+    }
+  }
+
+  // the declarations are found in the bodies of the module and namespaces
+  fn visit_class(&mut self, _class: &Class) {}
+}
@@ -0,0 +1,101 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::class_accessors_to_defs;
+use crate::class::ClassDef;
+use crate::interface::interface_accessors_to_defs;
+use crate::interface::InterfaceDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::namespace::NamespaceDef;
+use crate::node::DocNode;
+use crate::r#enum::EnumDef;
+use crate::ts_type::TsTypeDef;
+use crate::visit::walk_namespace_def_mut;
+use crate::visit::DocVisitorMut;
+
+/// Whether the symbol or member documented by `js_doc` is hidden from the
+/// docs, which is the case when it is tagged with `@ignore`, or with
+/// `@internal` if internal symbols are stripped.
+pub(crate) fn is_hidden(js_doc: &JsDoc, strip_internal: bool) -> bool {
+  js_doc.tags.iter().any(|tag| match tag {
+    JsDocTag::Ignore => true,
+    JsDocTag::Internal => strip_internal,
+    _ => false,
+  })
+}
+
+/// Drops the doc nodes, namespace elements and members of classes,
+/// interfaces and enums which are hidden from the docs.
+pub(crate) struct VisibilityFilter {
+  strip_internal: bool,
+}
+
+impl VisibilityFilter {
+  pub fn new(strip_internal: bool) -> Self {
+    Self { strip_internal }
+  }
+
+  fn is_hidden(&self, js_doc: &JsDoc) -> bool {
+    is_hidden(js_doc, self.strip_internal)
+  }
+
+  pub fn filter(&mut self, doc_nodes: &mut Vec<DocNode>) {
+    doc_nodes.retain(|doc_node| !self.is_hidden(&doc_node.js_doc));
+    for doc_node in doc_nodes {
+      self.visit_doc_node_mut(doc_node);
+    }
+  }
+}
+
+impl DocVisitorMut for VisibilityFilter {
+  fn visit_namespace_def_mut(&mut self, namespace_def: &mut NamespaceDef) {
+    namespace_def
+      .elements
+      .retain(|element| !self.is_hidden(&element.js_doc));
+    walk_namespace_def_mut(self, namespace_def);
+  }
+
+  fn visit_class_def_mut(&mut self, class_def: &mut ClassDef) {
+    class_def
+      .constructors
+      .retain(|constructor| !self.is_hidden(&constructor.js_doc));
+    class_def
+      .properties
+      .retain(|property| !self.is_hidden(&property.js_doc));
+    let methods = class_def.methods.len();
+    class_def
+      .methods
+      .retain(|method| !self.is_hidden(&method.js_doc));
+    // the getter and the setter of an accessor are hidden separately, so the
+    // accessors are merged again from the ones left
+    if class_def.methods.len() != methods {
+      class_def.accessors = class_accessors_to_defs(&class_def.methods);
+    }
+  }
+
+  fn visit_interface_def_mut(&mut self, interface_def: &mut InterfaceDef) {
+    interface_def
+      .properties
+      .retain(|property| !self.is_hidden(&property.js_doc));
+    let methods = interface_def.methods.len();
+    interface_def
+      .methods
+      .retain(|method| !self.is_hidden(&method.js_doc));
+    if interface_def.methods.len() != methods {
+      interface_def.accessors =
+        interface_accessors_to_defs(&interface_def.methods);
+    }
+    interface_def
+      .call_signatures
+      .retain(|call_signature| !self.is_hidden(&call_signature.js_doc));
+  }
+
+  fn visit_enum_def_mut(&mut self, enum_def: &mut EnumDef) {
+    enum_def
+      .members
+      .retain(|member| !self.is_hidden(&member.js_doc));
+  }
+
+  // types don't contain doc nodes or members which could be hidden
+  fn visit_ts_type_def_mut(&mut self, _ts_type_def: &mut TsTypeDef) {}
+}
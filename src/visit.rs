@@ -0,0 +1,1255 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+//! Traversal of the doc nodes of a module and everything they contain.
+
+use crate::accessor::AccessorDef;
+use crate::class::ClassAncestorDef;
+use crate::class::ClassConstructorDef;
+use crate::class::ClassDef;
+use crate::class::ClassIndexSignatureDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::decorators::DecoratorDef;
+use crate::function::FunctionDef;
+use crate::interface::InterfaceAncestorDef;
+use crate::interface::InterfaceCallSignatureDef;
+use crate::interface::InterfaceDef;
+use crate::interface::InterfaceIndexSignatureDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::js_doc::JsDoc;
+use crate::namespace::NamespaceDef;
+use crate::node::DocNode;
+use crate::node::ImportDef;
+use crate::params::ObjectPatPropDef;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
+use crate::r#enum::EnumDef;
+use crate::r#enum::EnumMemberDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type_param::TsTypeParamDef;
+use crate::type_alias::TypeAliasDef;
+use crate::variable::VariableDef;
+
+/// A visitor over a tree of doc nodes, with a method for every kind of node,
+/// member, parameter, type parameter, decorator and type in the tree.
+///
+/// Every method defaults to walking the children of what it visits, so an
+/// implementation only overrides the methods it is interested in. To keep
+/// walking the children from an overridden method, call the matching `walk_`
+/// function of this module.
+///
+/// Getters and setters are visited both as methods and, merged, as accessors.
+/// The members classes and interfaces inherit from their ancestors are
+/// visited after their own members, through `visit_class_ancestor_def` and
+/// `visit_interface_ancestor_def`, including the overridden ones.
+pub trait DocVisitor {
+  fn visit_doc_node(&mut self, doc_node: &DocNode) {
+    walk_doc_node(self, doc_node)
+  }
+
+  fn visit_js_doc(&mut self, _js_doc: &JsDoc) {}
+
+  fn visit_function_def(&mut self, function_def: &FunctionDef) {
+    walk_function_def(self, function_def)
+  }
+
+  fn visit_variable_def(&mut self, variable_def: &VariableDef) {
+    walk_variable_def(self, variable_def)
+  }
+
+  fn visit_enum_def(&mut self, enum_def: &EnumDef) {
+    walk_enum_def(self, enum_def)
+  }
+
+  fn visit_enum_member_def(&mut self, member_def: &EnumMemberDef) {
+    walk_enum_member_def(self, member_def)
+  }
+
+  fn visit_class_def(&mut self, class_def: &ClassDef) {
+    walk_class_def(self, class_def)
+  }
+
+  fn visit_class_constructor_def(
+    &mut self,
+    constructor_def: &ClassConstructorDef,
+  ) {
+    walk_class_constructor_def(self, constructor_def)
+  }
+
+  fn visit_class_property_def(&mut self, property_def: &ClassPropertyDef) {
+    walk_class_property_def(self, property_def)
+  }
+
+  fn visit_class_index_signature_def(
+    &mut self,
+    index_signature_def: &ClassIndexSignatureDef,
+  ) {
+    walk_class_index_signature_def(self, index_signature_def)
+  }
+
+  fn visit_class_method_def(&mut self, method_def: &ClassMethodDef) {
+    walk_class_method_def(self, method_def)
+  }
+
+  fn visit_accessor_def(&mut self, accessor_def: &AccessorDef) {
+    walk_accessor_def(self, accessor_def)
+  }
+
+  fn visit_class_ancestor_def(&mut self, ancestor_def: &ClassAncestorDef) {
+    walk_class_ancestor_def(self, ancestor_def)
+  }
+
+  fn visit_type_alias_def(&mut self, type_alias_def: &TypeAliasDef) {
+    walk_type_alias_def(self, type_alias_def)
+  }
+
+  fn visit_namespace_def(&mut self, namespace_def: &NamespaceDef) {
+    walk_namespace_def(self, namespace_def)
+  }
+
+  fn visit_interface_def(&mut self, interface_def: &InterfaceDef) {
+    walk_interface_def(self, interface_def)
+  }
+
+  fn visit_interface_method_def(&mut self, method_def: &InterfaceMethodDef) {
+    walk_interface_method_def(self, method_def)
+  }
+
+  fn visit_interface_property_def(
+    &mut self,
+    property_def: &InterfacePropertyDef,
+  ) {
+    walk_interface_property_def(self, property_def)
+  }
+
+  fn visit_interface_call_signature_def(
+    &mut self,
+    call_signature_def: &InterfaceCallSignatureDef,
+  ) {
+    walk_interface_call_signature_def(self, call_signature_def)
+  }
+
+  fn visit_interface_index_signature_def(
+    &mut self,
+    index_signature_def: &InterfaceIndexSignatureDef,
+  ) {
+    walk_interface_index_signature_def(self, index_signature_def)
+  }
+
+  fn visit_interface_ancestor_def(
+    &mut self,
+    ancestor_def: &InterfaceAncestorDef,
+  ) {
+    walk_interface_ancestor_def(self, ancestor_def)
+  }
+
+  fn visit_import_def(&mut self, _import_def: &ImportDef) {}
+
+  fn visit_param_def(&mut self, param_def: &ParamDef) {
+    walk_param_def(self, param_def)
+  }
+
+  fn visit_ts_type_param_def(&mut self, type_param_def: &TsTypeParamDef) {
+    walk_ts_type_param_def(self, type_param_def)
+  }
+
+  fn visit_decorator_def(&mut self, _decorator_def: &DecoratorDef) {}
+
+  fn visit_ts_type_def(&mut self, ts_type_def: &TsTypeDef) {
+    walk_ts_type_def(self, ts_type_def)
+  }
+}
+
+pub fn walk_doc_node<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  doc_node: &DocNode,
+) {
+  visitor.visit_js_doc(&doc_node.js_doc);
+  if let Some(function_def) = &doc_node.function_def {
+    visitor.visit_function_def(function_def);
+  }
+  if let Some(variable_def) = &doc_node.variable_def {
+    visitor.visit_variable_def(variable_def);
+  }
+  if let Some(enum_def) = &doc_node.enum_def {
+    visitor.visit_enum_def(enum_def);
+  }
+  if let Some(class_def) = &doc_node.class_def {
+    visitor.visit_class_def(class_def);
+  }
+  if let Some(type_alias_def) = &doc_node.type_alias_def {
+    visitor.visit_type_alias_def(type_alias_def);
+  }
+  if let Some(namespace_def) = &doc_node.namespace_def {
+    visitor.visit_namespace_def(namespace_def);
+  }
+  if let Some(interface_def) = &doc_node.interface_def {
+    visitor.visit_interface_def(interface_def);
+  }
+  if let Some(import_def) = &doc_node.import_def {
+    visitor.visit_import_def(import_def);
+  }
+}
+
+pub fn walk_function_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  function_def: &FunctionDef,
+) {
+  for decorator_def in &function_def.decorators {
+    visitor.visit_decorator_def(decorator_def);
+  }
+  for type_param_def in &function_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  for param_def in &function_def.params {
+    visitor.visit_param_def(param_def);
+  }
+  if let Some(return_type) = &function_def.return_type {
+    visitor.visit_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_variable_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  variable_def: &VariableDef,
+) {
+  if let Some(ts_type) = &variable_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_enum_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  enum_def: &EnumDef,
+) {
+  for member_def in &enum_def.members {
+    visitor.visit_enum_member_def(member_def);
+  }
+}
+
+pub fn walk_enum_member_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  member_def: &EnumMemberDef,
+) {
+  visitor.visit_js_doc(&member_def.js_doc);
+  if let Some(init) = &member_def.init {
+    visitor.visit_ts_type_def(init);
+  }
+}
+
+pub fn walk_class_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  class_def: &ClassDef,
+) {
+  for decorator_def in &class_def.decorators {
+    visitor.visit_decorator_def(decorator_def);
+  }
+  for type_param_def in &class_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  for ts_type in &class_def.super_type_params {
+    visitor.visit_ts_type_def(ts_type);
+  }
+  for ts_type in &class_def.implements {
+    visitor.visit_ts_type_def(ts_type);
+  }
+  for constructor_def in &class_def.constructors {
+    visitor.visit_class_constructor_def(constructor_def);
+  }
+  for property_def in &class_def.properties {
+    visitor.visit_class_property_def(property_def);
+  }
+  for accessor_def in &class_def.accessors {
+    visitor.visit_accessor_def(accessor_def);
+  }
+  for index_signature_def in &class_def.index_signatures {
+    visitor.visit_class_index_signature_def(index_signature_def);
+  }
+  for method_def in &class_def.methods {
+    visitor.visit_class_method_def(method_def);
+  }
+  for ancestor_def in &class_def.inherited {
+    visitor.visit_class_ancestor_def(ancestor_def);
+  }
+}
+
+pub fn walk_class_ancestor_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  ancestor_def: &ClassAncestorDef,
+) {
+  for property in &ancestor_def.properties {
+    visitor.visit_class_property_def(&property.member);
+  }
+  for accessor in &ancestor_def.accessors {
+    visitor.visit_accessor_def(&accessor.member);
+  }
+  for method in &ancestor_def.methods {
+    visitor.visit_class_method_def(&method.member);
+  }
+}
+
+pub fn walk_class_constructor_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  constructor_def: &ClassConstructorDef,
+) {
+  visitor.visit_js_doc(&constructor_def.js_doc);
+  for param_def in &constructor_def.params {
+    visitor.visit_param_def(param_def);
+  }
+}
+
+pub fn walk_class_property_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  property_def: &ClassPropertyDef,
+) {
+  visitor.visit_js_doc(&property_def.js_doc);
+  for decorator_def in &property_def.decorators {
+    visitor.visit_decorator_def(decorator_def);
+  }
+  if let Some(ts_type) = &property_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_class_index_signature_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  index_signature_def: &ClassIndexSignatureDef,
+) {
+  for param_def in &index_signature_def.params {
+    visitor.visit_param_def(param_def);
+  }
+  if let Some(ts_type) = &index_signature_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_class_method_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  method_def: &ClassMethodDef,
+) {
+  visitor.visit_js_doc(&method_def.js_doc);
+  visitor.visit_function_def(&method_def.function_def);
+}
+
+pub fn walk_accessor_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  accessor_def: &AccessorDef,
+) {
+  visitor.visit_js_doc(&accessor_def.js_doc);
+  if let Some(ts_type) = &accessor_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_type_alias_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  type_alias_def: &TypeAliasDef,
+) {
+  for type_param_def in &type_alias_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  visitor.visit_ts_type_def(&type_alias_def.ts_type);
+}
+
+pub fn walk_namespace_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  namespace_def: &NamespaceDef,
+) {
+  for doc_node in &namespace_def.elements {
+    visitor.visit_doc_node(doc_node);
+  }
+}
+
+pub fn walk_interface_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  interface_def: &InterfaceDef,
+) {
+  for type_param_def in &interface_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  for ts_type in &interface_def.extends {
+    visitor.visit_ts_type_def(ts_type);
+  }
+  for property_def in &interface_def.properties {
+    visitor.visit_interface_property_def(property_def);
+  }
+  for accessor_def in &interface_def.accessors {
+    visitor.visit_accessor_def(accessor_def);
+  }
+  for method_def in &interface_def.methods {
+    visitor.visit_interface_method_def(method_def);
+  }
+  for call_signature_def in &interface_def.call_signatures {
+    visitor.visit_interface_call_signature_def(call_signature_def);
+  }
+  for index_signature_def in &interface_def.index_signatures {
+    visitor.visit_interface_index_signature_def(index_signature_def);
+  }
+  for ancestor_def in &interface_def.inherited {
+    visitor.visit_interface_ancestor_def(ancestor_def);
+  }
+}
+
+pub fn walk_interface_ancestor_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  ancestor_def: &InterfaceAncestorDef,
+) {
+  for property in &ancestor_def.properties {
+    visitor.visit_interface_property_def(&property.member);
+  }
+  for accessor in &ancestor_def.accessors {
+    visitor.visit_accessor_def(&accessor.member);
+  }
+  for method in &ancestor_def.methods {
+    visitor.visit_interface_method_def(&method.member);
+  }
+}
+
+pub fn walk_interface_method_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  method_def: &InterfaceMethodDef,
+) {
+  visitor.visit_js_doc(&method_def.js_doc);
+  for type_param_def in &method_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  for param_def in &method_def.params {
+    visitor.visit_param_def(param_def);
+  }
+  if let Some(return_type) = &method_def.return_type {
+    visitor.visit_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_interface_property_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  property_def: &InterfacePropertyDef,
+) {
+  visitor.visit_js_doc(&property_def.js_doc);
+  for type_param_def in &property_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  for param_def in &property_def.params {
+    visitor.visit_param_def(param_def);
+  }
+  if let Some(ts_type) = &property_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_interface_call_signature_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  call_signature_def: &InterfaceCallSignatureDef,
+) {
+  visitor.visit_js_doc(&call_signature_def.js_doc);
+  for type_param_def in &call_signature_def.type_params {
+    visitor.visit_ts_type_param_def(type_param_def);
+  }
+  for param_def in &call_signature_def.params {
+    visitor.visit_param_def(param_def);
+  }
+  if let Some(ts_type) = &call_signature_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_interface_index_signature_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  index_signature_def: &InterfaceIndexSignatureDef,
+) {
+  for param_def in &index_signature_def.params {
+    visitor.visit_param_def(param_def);
+  }
+  if let Some(ts_type) = &index_signature_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_param_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  param_def: &ParamDef,
+) {
+  for decorator_def in &param_def.decorators {
+    visitor.visit_decorator_def(decorator_def);
+  }
+  match &param_def.pattern {
+    ParamPatternDef::Array { elements, .. } => {
+      for element in elements.iter().flatten() {
+        visitor.visit_param_def(element);
+      }
+    }
+    ParamPatternDef::Assign { left, .. } => visitor.visit_param_def(left),
+    ParamPatternDef::Identifier { .. } => {}
+    ParamPatternDef::Object { props, .. } => {
+      for prop in props {
+        match prop {
+          ObjectPatPropDef::Assign { ts_type, .. } => {
+            if let Some(ts_type) = ts_type {
+              visitor.visit_ts_type_def(ts_type);
+            }
+          }
+          ObjectPatPropDef::KeyValue { value, .. } => {
+            visitor.visit_param_def(value)
+          }
+          ObjectPatPropDef::Rest { arg } => visitor.visit_param_def(arg),
+        }
+      }
+    }
+    ParamPatternDef::Rest { arg } => visitor.visit_param_def(arg),
+  }
+  if let Some(ts_type) = &param_def.ts_type {
+    visitor.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_ts_type_param_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  type_param_def: &TsTypeParamDef,
+) {
+  if let Some(constraint) = &type_param_def.constraint {
+    visitor.visit_ts_type_def(constraint);
+  }
+  if let Some(default) = &type_param_def.default {
+    visitor.visit_ts_type_def(default);
+  }
+}
+
+pub fn walk_ts_type_def<V: DocVisitor + ?Sized>(
+  visitor: &mut V,
+  ts_type_def: &TsTypeDef,
+) {
+  if let Some(js_doc) = &ts_type_def.js_doc {
+    visitor.visit_js_doc(js_doc);
+  }
+  match &ts_type_def.kind {
+    TsTypeDefKind::Keyword(_)
+    | TsTypeDefKind::TypeQuery(_)
+    | TsTypeDefKind::This => {}
+    TsTypeDefKind::Literal(literal) => {
+      for ts_type in literal.ts_types.iter().flatten() {
+        visitor.visit_ts_type_def(ts_type);
+      }
+    }
+    TsTypeDefKind::TypeRef(type_ref) => {
+      for ts_type in type_ref.type_params.iter().flatten() {
+        visitor.visit_ts_type_def(ts_type);
+      }
+    }
+    TsTypeDefKind::Union(ts_types) | TsTypeDefKind::Intersection(ts_types) => {
+      for ts_type in ts_types {
+        visitor.visit_ts_type_def(ts_type);
+      }
+    }
+    TsTypeDefKind::Array(ts_type)
+    | TsTypeDefKind::Parenthesized(ts_type)
+    | TsTypeDefKind::Rest(ts_type)
+    | TsTypeDefKind::Optional(ts_type) => visitor.visit_ts_type_def(ts_type),
+    TsTypeDefKind::Tuple(elements) => {
+      for element in elements {
+        visitor.visit_ts_type_def(&element.ts_type);
+      }
+    }
+    TsTypeDefKind::TypeOperator(type_operator) => {
+      visitor.visit_ts_type_def(&type_operator.ts_type)
+    }
+    TsTypeDefKind::FnOrConstructor(fn_or_constructor) => {
+      for type_param_def in &fn_or_constructor.type_params {
+        visitor.visit_ts_type_param_def(type_param_def);
+      }
+      for param_def in &fn_or_constructor.params {
+        visitor.visit_param_def(param_def);
+      }
+      visitor.visit_ts_type_def(&fn_or_constructor.ts_type);
+    }
+    TsTypeDefKind::Conditional(conditional) => {
+      visitor.visit_ts_type_def(&conditional.check_type);
+      visitor.visit_ts_type_def(&conditional.extends_type);
+      visitor.visit_ts_type_def(&conditional.true_type);
+      visitor.visit_ts_type_def(&conditional.false_type);
+    }
+    TsTypeDefKind::Infer(infer) => {
+      visitor.visit_ts_type_param_def(&infer.type_param)
+    }
+    TsTypeDefKind::IndexedAccess(indexed_access) => {
+      visitor.visit_ts_type_def(&indexed_access.obj_type);
+      visitor.visit_ts_type_def(&indexed_access.index_type);
+    }
+    TsTypeDefKind::Mapped(mapped_type) => {
+      visitor.visit_ts_type_param_def(&mapped_type.type_param);
+      if let Some(name_type) = &mapped_type.name_type {
+        visitor.visit_ts_type_def(name_type);
+      }
+      if let Some(ts_type) = &mapped_type.ts_type {
+        visitor.visit_ts_type_def(ts_type);
+      }
+    }
+    TsTypeDefKind::TypeLiteral(type_literal) => {
+      for method in &type_literal.methods {
+        for type_param_def in &method.type_params {
+          visitor.visit_ts_type_param_def(type_param_def);
+        }
+        for param_def in &method.params {
+          visitor.visit_param_def(param_def);
+        }
+        if let Some(return_type) = &method.return_type {
+          visitor.visit_ts_type_def(return_type);
+        }
+      }
+      for property in &type_literal.properties {
+        for type_param_def in &property.type_params {
+          visitor.visit_ts_type_param_def(type_param_def);
+        }
+        for param_def in &property.params {
+          visitor.visit_param_def(param_def);
+        }
+        if let Some(ts_type) = &property.ts_type {
+          visitor.visit_ts_type_def(ts_type);
+        }
+      }
+      for call_signature in &type_literal.call_signatures {
+        for type_param_def in &call_signature.type_params {
+          visitor.visit_ts_type_param_def(type_param_def);
+        }
+        for param_def in &call_signature.params {
+          visitor.visit_param_def(param_def);
+        }
+        if let Some(ts_type) = &call_signature.ts_type {
+          visitor.visit_ts_type_def(ts_type);
+        }
+      }
+      for index_signature in &type_literal.index_signatures {
+        for param_def in &index_signature.params {
+          visitor.visit_param_def(param_def);
+        }
+        if let Some(ts_type) = &index_signature.ts_type {
+          visitor.visit_ts_type_def(ts_type);
+        }
+      }
+    }
+    TsTypeDefKind::TypePredicate(type_predicate) => {
+      if let Some(ts_type) = &type_predicate.r#type {
+        visitor.visit_ts_type_def(ts_type);
+      }
+    }
+    TsTypeDefKind::ImportType(import_type) => {
+      for ts_type in import_type.type_params.iter().flatten() {
+        visitor.visit_ts_type_def(ts_type);
+      }
+    }
+  }
+}
+
+/// Like [`DocVisitor`], but visiting the tree mutably, so that an
+/// implementation can rewrite what it visits in place.
+pub trait DocVisitorMut {
+  fn visit_doc_node_mut(&mut self, doc_node: &mut DocNode) {
+    walk_doc_node_mut(self, doc_node)
+  }
+
+  fn visit_js_doc_mut(&mut self, _js_doc: &mut JsDoc) {}
+
+  fn visit_function_def_mut(&mut self, function_def: &mut FunctionDef) {
+    walk_function_def_mut(self, function_def)
+  }
+
+  fn visit_variable_def_mut(&mut self, variable_def: &mut VariableDef) {
+    walk_variable_def_mut(self, variable_def)
+  }
+
+  fn visit_enum_def_mut(&mut self, enum_def: &mut EnumDef) {
+    walk_enum_def_mut(self, enum_def)
+  }
+
+  fn visit_enum_member_def_mut(&mut self, member_def: &mut EnumMemberDef) {
+    walk_enum_member_def_mut(self, member_def)
+  }
+
+  fn visit_class_def_mut(&mut self, class_def: &mut ClassDef) {
+    walk_class_def_mut(self, class_def)
+  }
+
+  fn visit_class_constructor_def_mut(
+    &mut self,
+    constructor_def: &mut ClassConstructorDef,
+  ) {
+    walk_class_constructor_def_mut(self, constructor_def)
+  }
+
+  fn visit_class_property_def_mut(
+    &mut self,
+    property_def: &mut ClassPropertyDef,
+  ) {
+    walk_class_property_def_mut(self, property_def)
+  }
+
+  fn visit_class_index_signature_def_mut(
+    &mut self,
+    index_signature_def: &mut ClassIndexSignatureDef,
+  ) {
+    walk_class_index_signature_def_mut(self, index_signature_def)
+  }
+
+  fn visit_class_method_def_mut(&mut self, method_def: &mut ClassMethodDef) {
+    walk_class_method_def_mut(self, method_def)
+  }
+
+  fn visit_accessor_def_mut(&mut self, accessor_def: &mut AccessorDef) {
+    walk_accessor_def_mut(self, accessor_def)
+  }
+
+  fn visit_class_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut ClassAncestorDef,
+  ) {
+    walk_class_ancestor_def_mut(self, ancestor_def)
+  }
+
+  fn visit_type_alias_def_mut(&mut self, type_alias_def: &mut TypeAliasDef) {
+    walk_type_alias_def_mut(self, type_alias_def)
+  }
+
+  fn visit_namespace_def_mut(&mut self, namespace_def: &mut NamespaceDef) {
+    walk_namespace_def_mut(self, namespace_def)
+  }
+
+  fn visit_interface_def_mut(&mut self, interface_def: &mut InterfaceDef) {
+    walk_interface_def_mut(self, interface_def)
+  }
+
+  fn visit_interface_method_def_mut(
+    &mut self,
+    method_def: &mut InterfaceMethodDef,
+  ) {
+    walk_interface_method_def_mut(self, method_def)
+  }
+
+  fn visit_interface_property_def_mut(
+    &mut self,
+    property_def: &mut InterfacePropertyDef,
+  ) {
+    walk_interface_property_def_mut(self, property_def)
+  }
+
+  fn visit_interface_call_signature_def_mut(
+    &mut self,
+    call_signature_def: &mut InterfaceCallSignatureDef,
+  ) {
+    walk_interface_call_signature_def_mut(self, call_signature_def)
+  }
+
+  fn visit_interface_index_signature_def_mut(
+    &mut self,
+    index_signature_def: &mut InterfaceIndexSignatureDef,
+  ) {
+    walk_interface_index_signature_def_mut(self, index_signature_def)
+  }
+
+  fn visit_interface_ancestor_def_mut(
+    &mut self,
+    ancestor_def: &mut InterfaceAncestorDef,
+  ) {
+    walk_interface_ancestor_def_mut(self, ancestor_def)
+  }
+
+  fn visit_import_def_mut(&mut self, _import_def: &mut ImportDef) {}
+
+  fn visit_param_def_mut(&mut self, param_def: &mut ParamDef) {
+    walk_param_def_mut(self, param_def)
+  }
+
+  fn visit_ts_type_param_def_mut(
+    &mut self,
+    type_param_def: &mut TsTypeParamDef,
+  ) {
+    walk_ts_type_param_def_mut(self, type_param_def)
+  }
+
+  fn visit_decorator_def_mut(&mut self, _decorator_def: &mut DecoratorDef) {}
+
+  fn visit_ts_type_def_mut(&mut self, ts_type_def: &mut TsTypeDef) {
+    walk_ts_type_def_mut(self, ts_type_def)
+  }
+}
+
+pub fn walk_doc_node_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  doc_node: &mut DocNode,
+) {
+  visitor.visit_js_doc_mut(&mut doc_node.js_doc);
+  if let Some(function_def) = &mut doc_node.function_def {
+    visitor.visit_function_def_mut(function_def);
+  }
+  if let Some(variable_def) = &mut doc_node.variable_def {
+    visitor.visit_variable_def_mut(variable_def);
+  }
+  if let Some(enum_def) = &mut doc_node.enum_def {
+    visitor.visit_enum_def_mut(enum_def);
+  }
+  if let Some(class_def) = &mut doc_node.class_def {
+    visitor.visit_class_def_mut(class_def);
+  }
+  if let Some(type_alias_def) = &mut doc_node.type_alias_def {
+    visitor.visit_type_alias_def_mut(type_alias_def);
+  }
+  if let Some(namespace_def) = &mut doc_node.namespace_def {
+    visitor.visit_namespace_def_mut(namespace_def);
+  }
+  if let Some(interface_def) = &mut doc_node.interface_def {
+    visitor.visit_interface_def_mut(interface_def);
+  }
+  if let Some(import_def) = &mut doc_node.import_def {
+    visitor.visit_import_def_mut(import_def);
+  }
+}
+
+pub fn walk_function_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  function_def: &mut FunctionDef,
+) {
+  for decorator_def in &mut function_def.decorators {
+    visitor.visit_decorator_def_mut(decorator_def);
+  }
+  for type_param_def in &mut function_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  for param_def in &mut function_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+  if let Some(return_type) = &mut function_def.return_type {
+    visitor.visit_ts_type_def_mut(return_type);
+  }
+}
+
+pub fn walk_variable_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  variable_def: &mut VariableDef,
+) {
+  if let Some(ts_type) = &mut variable_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_enum_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  enum_def: &mut EnumDef,
+) {
+  for member_def in &mut enum_def.members {
+    visitor.visit_enum_member_def_mut(member_def);
+  }
+}
+
+pub fn walk_enum_member_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  member_def: &mut EnumMemberDef,
+) {
+  visitor.visit_js_doc_mut(&mut member_def.js_doc);
+  if let Some(init) = &mut member_def.init {
+    visitor.visit_ts_type_def_mut(init);
+  }
+}
+
+pub fn walk_class_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  class_def: &mut ClassDef,
+) {
+  for decorator_def in &mut class_def.decorators {
+    visitor.visit_decorator_def_mut(decorator_def);
+  }
+  for type_param_def in &mut class_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  for ts_type in &mut class_def.super_type_params {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+  for ts_type in &mut class_def.implements {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+  for constructor_def in &mut class_def.constructors {
+    visitor.visit_class_constructor_def_mut(constructor_def);
+  }
+  for property_def in &mut class_def.properties {
+    visitor.visit_class_property_def_mut(property_def);
+  }
+  for accessor_def in &mut class_def.accessors {
+    visitor.visit_accessor_def_mut(accessor_def);
+  }
+  for index_signature_def in &mut class_def.index_signatures {
+    visitor.visit_class_index_signature_def_mut(index_signature_def);
+  }
+  for method_def in &mut class_def.methods {
+    visitor.visit_class_method_def_mut(method_def);
+  }
+  for ancestor_def in &mut class_def.inherited {
+    visitor.visit_class_ancestor_def_mut(ancestor_def);
+  }
+}
+
+pub fn walk_class_ancestor_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  ancestor_def: &mut ClassAncestorDef,
+) {
+  for property in &mut ancestor_def.properties {
+    visitor.visit_class_property_def_mut(&mut property.member);
+  }
+  for accessor in &mut ancestor_def.accessors {
+    visitor.visit_accessor_def_mut(&mut accessor.member);
+  }
+  for method in &mut ancestor_def.methods {
+    visitor.visit_class_method_def_mut(&mut method.member);
+  }
+}
+
+pub fn walk_class_constructor_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  constructor_def: &mut ClassConstructorDef,
+) {
+  visitor.visit_js_doc_mut(&mut constructor_def.js_doc);
+  for param_def in &mut constructor_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+}
+
+pub fn walk_class_property_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  property_def: &mut ClassPropertyDef,
+) {
+  visitor.visit_js_doc_mut(&mut property_def.js_doc);
+  for decorator_def in &mut property_def.decorators {
+    visitor.visit_decorator_def_mut(decorator_def);
+  }
+  if let Some(ts_type) = &mut property_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_class_index_signature_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  index_signature_def: &mut ClassIndexSignatureDef,
+) {
+  for param_def in &mut index_signature_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+  if let Some(ts_type) = &mut index_signature_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_class_method_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  method_def: &mut ClassMethodDef,
+) {
+  visitor.visit_js_doc_mut(&mut method_def.js_doc);
+  visitor.visit_function_def_mut(&mut method_def.function_def);
+}
+
+pub fn walk_accessor_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  accessor_def: &mut AccessorDef,
+) {
+  visitor.visit_js_doc_mut(&mut accessor_def.js_doc);
+  if let Some(ts_type) = &mut accessor_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_type_alias_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  type_alias_def: &mut TypeAliasDef,
+) {
+  for type_param_def in &mut type_alias_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  visitor.visit_ts_type_def_mut(&mut type_alias_def.ts_type);
+}
+
+pub fn walk_namespace_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  namespace_def: &mut NamespaceDef,
+) {
+  for doc_node in &mut namespace_def.elements {
+    visitor.visit_doc_node_mut(doc_node);
+  }
+}
+
+pub fn walk_interface_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  interface_def: &mut InterfaceDef,
+) {
+  for type_param_def in &mut interface_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  for ts_type in &mut interface_def.extends {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+  for property_def in &mut interface_def.properties {
+    visitor.visit_interface_property_def_mut(property_def);
+  }
+  for accessor_def in &mut interface_def.accessors {
+    visitor.visit_accessor_def_mut(accessor_def);
+  }
+  for method_def in &mut interface_def.methods {
+    visitor.visit_interface_method_def_mut(method_def);
+  }
+  for call_signature_def in &mut interface_def.call_signatures {
+    visitor.visit_interface_call_signature_def_mut(call_signature_def);
+  }
+  for index_signature_def in &mut interface_def.index_signatures {
+    visitor.visit_interface_index_signature_def_mut(index_signature_def);
+  }
+  for ancestor_def in &mut interface_def.inherited {
+    visitor.visit_interface_ancestor_def_mut(ancestor_def);
+  }
+}
+
+pub fn walk_interface_ancestor_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  ancestor_def: &mut InterfaceAncestorDef,
+) {
+  for property in &mut ancestor_def.properties {
+    visitor.visit_interface_property_def_mut(&mut property.member);
+  }
+  for accessor in &mut ancestor_def.accessors {
+    visitor.visit_accessor_def_mut(&mut accessor.member);
+  }
+  for method in &mut ancestor_def.methods {
+    visitor.visit_interface_method_def_mut(&mut method.member);
+  }
+}
+
+pub fn walk_interface_method_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  method_def: &mut InterfaceMethodDef,
+) {
+  visitor.visit_js_doc_mut(&mut method_def.js_doc);
+  for type_param_def in &mut method_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  for param_def in &mut method_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+  if let Some(return_type) = &mut method_def.return_type {
+    visitor.visit_ts_type_def_mut(return_type);
+  }
+}
+
+pub fn walk_interface_property_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  property_def: &mut InterfacePropertyDef,
+) {
+  visitor.visit_js_doc_mut(&mut property_def.js_doc);
+  for type_param_def in &mut property_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  for param_def in &mut property_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+  if let Some(ts_type) = &mut property_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_interface_call_signature_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  call_signature_def: &mut InterfaceCallSignatureDef,
+) {
+  visitor.visit_js_doc_mut(&mut call_signature_def.js_doc);
+  for type_param_def in &mut call_signature_def.type_params {
+    visitor.visit_ts_type_param_def_mut(type_param_def);
+  }
+  for param_def in &mut call_signature_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+  if let Some(ts_type) = &mut call_signature_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_interface_index_signature_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  index_signature_def: &mut InterfaceIndexSignatureDef,
+) {
+  for param_def in &mut index_signature_def.params {
+    visitor.visit_param_def_mut(param_def);
+  }
+  if let Some(ts_type) = &mut index_signature_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_param_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  param_def: &mut ParamDef,
+) {
+  for decorator_def in &mut param_def.decorators {
+    visitor.visit_decorator_def_mut(decorator_def);
+  }
+  match &mut param_def.pattern {
+    ParamPatternDef::Array { elements, .. } => {
+      for element in elements.iter_mut().flatten() {
+        visitor.visit_param_def_mut(element);
+      }
+    }
+    ParamPatternDef::Assign { left, .. } => visitor.visit_param_def_mut(left),
+    ParamPatternDef::Identifier { .. } => {}
+    ParamPatternDef::Object { props, .. } => {
+      for prop in props {
+        match prop {
+          ObjectPatPropDef::Assign { ts_type, .. } => {
+            if let Some(ts_type) = ts_type {
+              visitor.visit_ts_type_def_mut(ts_type);
+            }
+          }
+          ObjectPatPropDef::KeyValue { value, .. } => {
+            visitor.visit_param_def_mut(value)
+          }
+          ObjectPatPropDef::Rest { arg } => visitor.visit_param_def_mut(arg),
+        }
+      }
+    }
+    ParamPatternDef::Rest { arg } => visitor.visit_param_def_mut(arg),
+  }
+  if let Some(ts_type) = &mut param_def.ts_type {
+    visitor.visit_ts_type_def_mut(ts_type);
+  }
+}
+
+pub fn walk_ts_type_param_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  type_param_def: &mut TsTypeParamDef,
+) {
+  if let Some(constraint) = &mut type_param_def.constraint {
+    visitor.visit_ts_type_def_mut(constraint);
+  }
+  if let Some(default) = &mut type_param_def.default {
+    visitor.visit_ts_type_def_mut(default);
+  }
+}
+
+pub fn walk_ts_type_def_mut<V: DocVisitorMut + ?Sized>(
+  visitor: &mut V,
+  ts_type_def: &mut TsTypeDef,
+) {
+  if let Some(js_doc) = &mut ts_type_def.js_doc {
+    visitor.visit_js_doc_mut(js_doc);
+  }
+  match &mut ts_type_def.kind {
+    TsTypeDefKind::Keyword(_)
+    | TsTypeDefKind::TypeQuery(_)
+    | TsTypeDefKind::This => {}
+    TsTypeDefKind::Literal(literal) => {
+      for ts_type in literal.ts_types.iter_mut().flatten() {
+        visitor.visit_ts_type_def_mut(ts_type);
+      }
+    }
+    TsTypeDefKind::TypeRef(type_ref) => {
+      for ts_type in type_ref.type_params.iter_mut().flatten() {
+        visitor.visit_ts_type_def_mut(ts_type);
+      }
+    }
+    TsTypeDefKind::Union(ts_types) | TsTypeDefKind::Intersection(ts_types) => {
+      for ts_type in ts_types {
+        visitor.visit_ts_type_def_mut(ts_type);
+      }
+    }
+    TsTypeDefKind::Array(ts_type)
+    | TsTypeDefKind::Parenthesized(ts_type)
+    | TsTypeDefKind::Rest(ts_type)
+    | TsTypeDefKind::Optional(ts_type) => {
+      visitor.visit_ts_type_def_mut(ts_type)
+    }
+    TsTypeDefKind::Tuple(elements) => {
+      for element in elements {
+        visitor.visit_ts_type_def_mut(&mut element.ts_type);
+      }
+    }
+    TsTypeDefKind::TypeOperator(type_operator) => {
+      visitor.visit_ts_type_def_mut(&mut type_operator.ts_type)
+    }
+    TsTypeDefKind::FnOrConstructor(fn_or_constructor) => {
+      for type_param_def in &mut fn_or_constructor.type_params {
+        visitor.visit_ts_type_param_def_mut(type_param_def);
+      }
+      for param_def in &mut fn_or_constructor.params {
+        visitor.visit_param_def_mut(param_def);
+      }
+      visitor.visit_ts_type_def_mut(&mut fn_or_constructor.ts_type);
+    }
+    TsTypeDefKind::Conditional(conditional) => {
+      visitor.visit_ts_type_def_mut(&mut conditional.check_type);
+      visitor.visit_ts_type_def_mut(&mut conditional.extends_type);
+      visitor.visit_ts_type_def_mut(&mut conditional.true_type);
+      visitor.visit_ts_type_def_mut(&mut conditional.false_type);
+    }
+    TsTypeDefKind::Infer(infer) => {
+      visitor.visit_ts_type_param_def_mut(&mut infer.type_param)
+    }
+    TsTypeDefKind::IndexedAccess(indexed_access) => {
+      visitor.visit_ts_type_def_mut(&mut indexed_access.obj_type);
+      visitor.visit_ts_type_def_mut(&mut indexed_access.index_type);
+    }
+    TsTypeDefKind::Mapped(mapped_type) => {
+      visitor.visit_ts_type_param_def_mut(&mut mapped_type.type_param);
+      if let Some(name_type) = &mut mapped_type.name_type {
+        visitor.visit_ts_type_def_mut(name_type);
+      }
+      if let Some(ts_type) = &mut mapped_type.ts_type {
+        visitor.visit_ts_type_def_mut(ts_type);
+      }
+    }
+    TsTypeDefKind::TypeLiteral(type_literal) => {
+      for method in &mut type_literal.methods {
+        for type_param_def in &mut method.type_params {
+          visitor.visit_ts_type_param_def_mut(type_param_def);
+        }
+        for param_def in &mut method.params {
+          visitor.visit_param_def_mut(param_def);
+        }
+        if let Some(return_type) = &mut method.return_type {
+          visitor.visit_ts_type_def_mut(return_type);
+        }
+      }
+      for property in &mut type_literal.properties {
+        for type_param_def in &mut property.type_params {
+          visitor.visit_ts_type_param_def_mut(type_param_def);
+        }
+        for param_def in &mut property.params {
+          visitor.visit_param_def_mut(param_def);
+        }
+        if let Some(ts_type) = &mut property.ts_type {
+          visitor.visit_ts_type_def_mut(ts_type);
+        }
+      }
+      for call_signature in &mut type_literal.call_signatures {
+        for type_param_def in &mut call_signature.type_params {
+          visitor.visit_ts_type_param_def_mut(type_param_def);
+        }
+        for param_def in &mut call_signature.params {
+          visitor.visit_param_def_mut(param_def);
+        }
+        if let Some(ts_type) = &mut call_signature.ts_type {
+          visitor.visit_ts_type_def_mut(ts_type);
+        }
+      }
+      for index_signature in &mut type_literal.index_signatures {
+        for param_def in &mut index_signature.params {
+          visitor.visit_param_def_mut(param_def);
+        }
+        if let Some(ts_type) = &mut index_signature.ts_type {
+          visitor.visit_ts_type_def_mut(ts_type);
+        }
+      }
+    }
+    TsTypeDefKind::TypePredicate(type_predicate) => {
+      if let Some(ts_type) = &mut type_predicate.r#type {
+        visitor.visit_ts_type_def_mut(ts_type);
+      }
+    }
+    TsTypeDefKind::ImportType(import_type) => {
+      for ts_type in import_type.type_params.iter_mut().flatten() {
+        visitor.visit_ts_type_def_mut(ts_type);
+      }
+    }
+  }
+}